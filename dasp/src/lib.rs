@@ -49,27 +49,50 @@
 //!       another `dasp` graph type.
 //!     - The **node-pass** feature provides a `Pass` node that simply passes audio from its
 //!       inputs to its outputs.
+//!     - The **node-remix** feature provides a `Remix` node that mixes input channels into output
+//!       channels using a matrix of coefficients.
 //!     - The **node-signal** feature provides an implementation of `Node` for `dyn Signal`.
 //!     - The **node-sum** feature provides `Sum` and `SumBuffers` `Node` implementations.
 //! - The **interpolate** feature enables the `dasp_interpolate` crate via the
 //!   [interpolate](./interpolate/index.html) module.
+//!     - The **interpolate-catmull-rom** feature enables a Catmull-Rom interpolation
+//!       implementation.
+//!     - The **interpolate-cubic** feature enables a cubic interpolation implementation.
+//!     - The **interpolate-delay** feature enables a delay line with fractional delay reads.
 //!     - The **interpolate-floor** feature enables a floor interpolation implementation.
+//!     - The **interpolate-hermite** feature enables a Hermite interpolation implementation.
+//!     - The **interpolate-lagrange** feature enables a Lagrange interpolation implementation.
 //!     - The **interpolate-linear** feature enables a linear interpolation implementation.
+//!     - The **interpolate-polyphase** feature enables an anti-aliased polyphase sinc
+//!       interpolation implementation.
+//!     - The **interpolate-rational** feature enables a drift-free resampler for fixed rational
+//!       ratios between rates.
 //!     - The **interpolate-sinc** feature enables a sinc interpolation implementation.
 //! - The **peak** feature enables the `dasp_peak` crate via the [peak](./peak/index.html) module.
 //! - The **ring_buffer** feature enables the `dasp_ring_buffer` crate via the
 //!   [ring_buffer](./peak/index.html) module.
 //! - The **rms** feature enables the `dasp_rms` crate via the [rms](./rms/index.html) module.
+//! - The **sample-half** feature enables the half precision `f16` and `bf16` sample types.
 //! - The **signal** feature enables the `dasp_signal` crate via the [signal](./signal/index.html)
 //!   module.
 //!     - The **signal-boxed** feature enables an implementation of **Signal** for `Box<dyn
 //!       Signal>`.
 //!     - The **signal-bus** feature enables the [**SignalBus**](./signal/bus/trait.SignalBus.html)
 //!       trait.
+//!     - The **signal-dither** feature enables the
+//!       [**SignalDither**](./signal/dither/trait.SignalDither.html) trait.
 //!     - The **signal-envelope** feature enables the
 //!       [**SignalEnvelope**](./signal/envelope/trait.SignalEnvelope.html) trait.
+//!     - The **signal-layout** feature enables the
+//!       [**SignalLayout**](./signal/layout/trait.SignalLayout.html) trait.
+//!     - The **signal-rational** feature enables the
+//!       [**SignalRational**](./signal/rational/trait.SignalRational.html) trait.
 //!     - The **signal-rms** feature enables the [**SignalRms**](./signal/rms/trait.SignalRms.html)
 //!       trait.
+//!     - The **signal-sanitize** feature enables the
+//!       [**SignalSanitize**](./signal/sanitize/trait.SignalSanitize.html) trait.
+//!     - The **signal-stereo** feature enables the
+//!       [**SignalStereo**](./signal/stereo/trait.SignalStereo.html) trait.
 //!     - The **signal-window** feature enables the
 //!       [**signal::window**](./signal/window/index.html) module.
 //!     - The **signal-window-hann** enables the
@@ -78,6 +101,8 @@
 //!       [**signal::window::rectangle**](./signal/window/fn.rectangle.html) window constructor.
 //! - The **slice** feature enables the `dasp_slice` crate via the [slice](./slice/index.html)
 //!   module.
+//!     - The **slice-analysis** feature enables whole-slice analysis functions under the
+//!       [**slice::analysis**](./slice/analysis/index.html) module.
 //!     - The **slice-boxed** feature enables boxed slice conversion traits and functions.
 //! - The **window** feature enables the `dasp_window` crate via the [window](./window/index.html)
 //!   module.
@@ -0,0 +1,292 @@
+use crate::{Channels, ChannelsMut, ChannelsRef, Frame, NumChannels};
+use core::fmt;
+use dasp_sample::Sample;
+
+/// A frame whose number of channels is determined at runtime, e.g. from the header of a file.
+///
+/// Samples are stored inline within a fixed-capacity array of `N` samples, so a `DynFrame` is
+/// `Copy` and never allocates. The number of channels may be anywhere from `0` to `N`.
+///
+/// ### Channel counts
+///
+/// Channels beyond the length of a `DynFrame` are considered to be at equilibrium. As a result:
+///
+/// - `Frame::EQUILIBRIUM` is an empty frame, acting as silence for any number of channels.
+/// - `Frame::zip_map` (and in turn `add_amp` and `mul_amp`) yields a frame with as many channels
+///   as the longer of the two frames.
+/// - `Frame::map` yields a frame with the same number of channels.
+/// - `Frame::from_fn` yields a frame with `N` channels. Use `DynFrame::from_fn_with_channels` to
+///   specify the number of channels.
+/// - `Frame::from_samples` reads up to `N` samples, stopping early if the iterator is exhausted.
+///   Use `DynFrame::from_samples_with_channels` to read a specific number of samples.
+/// - `Frame::CHANNELS` is the capacity `N`, not the number of channels of any particular frame.
+/// - Frames that differ only by trailing channels at equilibrium are equal.
+///
+/// As the number of channels is not part of its type, `DynFrame` does not implement
+/// `FixedChannels` and is rejected at compile time by operations that determine the number of
+/// channels from `Frame::CHANNELS`, e.g. `dasp_signal::from_interleaved_samples_iter` and the
+/// `dasp_slice::planar` module. Use `dasp_signal::from_interleaved_samples_iter_dyn` to read frames
+/// with a given number of channels from interleaved samples.
+///
+/// # Example
+///
+/// ```rust
+/// use dasp_frame::{DynFrame, Frame};
+///
+/// fn main() {
+///     let channels = 3;
+///     let frame = DynFrame::<f32, 8>::from_fn_with_channels(channels, |i| i as f32 * 0.25);
+///     assert_eq!(frame.len(), 3);
+///     assert_eq!(frame.as_slice(), &[0.0, 0.25, 0.5]);
+///
+///     let scaled = frame.scale_amp(2.0);
+///     assert_eq!(scaled.as_slice(), &[0.0, 0.5, 1.0]);
+///     assert_eq!(frame.add_amp(DynFrame::EQUILIBRIUM), frame);
+/// }
+/// ```
+#[derive(Copy, Clone)]
+pub struct DynFrame<S, const N: usize> {
+    samples: [S; N],
+    len: usize,
+}
+
+/// The `Frame::NumChannels` of a `DynFrame` with a capacity of `N` channels.
+pub struct DynChannels<const N: usize> {}
+impl<const N: usize> NumChannels for DynChannels<N> {}
+
+impl<S, const N: usize> DynFrame<S, N>
+where
+    S: Sample,
+{
+    /// A frame with the given number of channels, each at equilibrium.
+    ///
+    /// **Panics** if `channels` is greater than `N`.
+    pub fn new(channels: usize) -> Self {
+        Self::from_fn_with_channels(channels, |_| S::EQUILIBRIUM)
+    }
+
+    /// A frame with a copy of the given samples, one per channel.
+    ///
+    /// Returns `None` if there are more than `N` samples.
+    pub fn from_slice(samples: &[S]) -> Option<Self> {
+        if samples.len() > N {
+            return None;
+        }
+        Some(Self::from_fn_with_channels(samples.len(), |i| samples[i]))
+    }
+
+    /// A frame with the given number of channels, where the sample for each channel is produced
+    /// by the given function.
+    ///
+    /// **Panics** if `channels` is greater than `N`.
+    pub fn from_fn_with_channels<F>(channels: usize, mut from: F) -> Self
+    where
+        F: FnMut(usize) -> S,
+    {
+        assert!(channels <= N, "channel count exceeds the frame capacity");
+        let mut frame = Self::EQUILIBRIUM;
+        for (i, sample) in frame.samples[..channels].iter_mut().enumerate() {
+            *sample = from(i);
+        }
+        frame.len = channels;
+        frame
+    }
+
+    /// A frame with the given number of channels, reading one sample per channel from the given
+    /// iterator.
+    ///
+    /// Returns `None` if the iterator does not yield enough samples.
+    ///
+    /// **Panics** if `channels` is greater than `N`.
+    pub fn from_samples_with_channels<I>(samples: &mut I, channels: usize) -> Option<Self>
+    where
+        I: Iterator<Item = S>,
+    {
+        assert!(channels <= N, "channel count exceeds the frame capacity");
+        let mut frame = Self::EQUILIBRIUM;
+        for sample in frame.samples[..channels].iter_mut() {
+            *sample = samples.next()?;
+        }
+        frame.len = channels;
+        Some(frame)
+    }
+}
+
+impl<S, const N: usize> DynFrame<S, N> {
+    /// The number of channels within the frame.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether or not the frame has no channels.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The sample of each channel.
+    pub fn as_slice(&self) -> &[S] {
+        &self.samples[..self.len]
+    }
+
+    /// Mutable access to the sample of each channel.
+    pub fn as_mut_slice(&mut self) -> &mut [S] {
+        &mut self.samples[..self.len]
+    }
+}
+
+impl<S, const N: usize> Frame for DynFrame<S, N>
+where
+    S: Sample,
+{
+    type Sample = S;
+    type NumChannels = DynChannels<N>;
+    type Channels = Channels<Self>;
+    type Float = DynFrame<S::Float, N>;
+    type Signed = DynFrame<S::Signed, N>;
+
+    const EQUILIBRIUM: Self = DynFrame {
+        samples: [S::EQUILIBRIUM; N],
+        len: 0,
+    };
+    const CHANNELS: usize = N;
+
+    #[inline]
+    fn channels(self) -> Self::Channels {
+        Channels {
+            next_idx: 0,
+            frame: self,
+        }
+    }
+
+    #[inline]
+    fn channels_ref(&self) -> ChannelsRef<'_, Self> {
+        ChannelsRef(self.as_slice().iter())
+    }
+
+    #[inline]
+    fn channels_mut(&mut self) -> ChannelsMut<'_, Self> {
+        ChannelsMut(self.as_mut_slice().iter_mut())
+    }
+
+    #[inline]
+    fn channel(&self, idx: usize) -> Option<&Self::Sample> {
+        self.as_slice().get(idx)
+    }
+
+    #[inline]
+    fn channel_mut(&mut self, idx: usize) -> Option<&mut Self::Sample> {
+        self.as_mut_slice().get_mut(idx)
+    }
+
+    #[inline]
+    fn from_fn<F>(from: F) -> Self
+    where
+        F: FnMut(usize) -> S,
+    {
+        Self::from_fn_with_channels(N, from)
+    }
+
+    #[inline]
+    fn from_samples<I>(samples: &mut I) -> Option<Self>
+    where
+        I: Iterator<Item = Self::Sample>,
+    {
+        let mut frame = Self::EQUILIBRIUM;
+        for sample in frame.samples.iter_mut() {
+            match samples.next() {
+                Some(s) => *sample = s,
+                None => break,
+            }
+            frame.len += 1;
+        }
+        if frame.is_empty() {
+            None
+        } else {
+            Some(frame)
+        }
+    }
+
+    // Indices up to the capacity `N` are valid, though channels beyond the length of the frame
+    // are not meaningful.
+    #[inline(always)]
+    unsafe fn channel_unchecked(&self, idx: usize) -> &Self::Sample {
+        self.samples.get_unchecked(idx)
+    }
+
+    #[inline(always)]
+    unsafe fn channel_unchecked_mut(&mut self, idx: usize) -> &mut Self::Sample {
+        self.samples.get_unchecked_mut(idx)
+    }
+
+    #[inline]
+    fn to_signed_frame(self) -> Self::Signed {
+        self.map(|s| s.to_sample())
+    }
+
+    #[inline]
+    fn to_float_frame(self) -> Self::Float {
+        self.map(|s| s.to_sample())
+    }
+
+    #[inline]
+    fn map<F, M>(self, map: M) -> F
+    where
+        F: Frame<NumChannels = Self::NumChannels>,
+        M: FnMut(Self::Sample) -> F::Sample,
+    {
+        // Only a `DynFrame` with the same capacity has `NumChannels = DynChannels<N>`, so reading
+        // from an iterator of exactly `len` samples yields a frame of the same length.
+        F::from_samples(&mut self.channels().map(map)).unwrap_or(F::EQUILIBRIUM)
+    }
+
+    #[inline]
+    fn zip_map<O, F, M>(self, other: O, mut zip_map: M) -> F
+    where
+        O: Frame<NumChannels = Self::NumChannels>,
+        F: Frame<NumChannels = Self::NumChannels>,
+        M: FnMut(Self::Sample, O::Sample) -> F::Sample,
+    {
+        let len = core::cmp::max(self.len, other.channels_ref().len());
+        let mut samples = (0..len).map(|i| {
+            let a = self.channel(i).copied().unwrap_or(S::EQUILIBRIUM);
+            let b = other.channel(i).copied().unwrap_or(O::Sample::EQUILIBRIUM);
+            zip_map(a, b)
+        });
+        F::from_samples(&mut samples).unwrap_or(F::EQUILIBRIUM)
+    }
+}
+
+impl<S, const N: usize> PartialEq for DynFrame<S, N>
+where
+    S: Sample,
+{
+    fn eq(&self, other: &Self) -> bool {
+        let len = core::cmp::max(self.len, other.len);
+        (0..len).all(|i| {
+            let a = self.channel(i).copied().unwrap_or(S::EQUILIBRIUM);
+            let b = other.channel(i).copied().unwrap_or(S::EQUILIBRIUM);
+            a == b
+        })
+    }
+}
+
+impl<S, const N: usize> fmt::Debug for DynFrame<S, N>
+where
+    S: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self.as_slice(), f)
+    }
+}
+
+impl<S, const N: usize> AsRef<[S]> for DynFrame<S, N> {
+    fn as_ref(&self) -> &[S] {
+        self.as_slice()
+    }
+}
+
+impl<S, const N: usize> AsMut<[S]> for DynFrame<S, N> {
+    fn as_mut(&mut self) -> &mut [S] {
+        self.as_mut_slice()
+    }
+}
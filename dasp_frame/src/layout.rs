@@ -0,0 +1,468 @@
+//! Describing the speaker position of each channel within a frame.
+//!
+//! A **Frame** only knows how many channels it has. A [**ChannelLayout**](./struct.ChannelLayout.html)
+//! describes the [**Speaker**](./enum.Speaker.html) position of each of those channels, allowing
+//! channels to be accessed by position and reordered between the differing channel orderings
+//! used by files, devices and applications via a [**ChannelMap**](./struct.ChannelMap.html).
+
+use crate::Frame;
+use dasp_sample::Sample;
+
+/// The maximum number of channels within a **ChannelLayout**, matching the largest fixed-size
+/// array for which **Frame** is implemented.
+pub const MAX_CHANNELS: usize = 32;
+
+/// The position of the speaker that a channel is intended for.
+///
+/// The named positions, along with their bit within a WAVE channel mask, follow those of the
+/// `WAVE_FORMAT_EXTENSIBLE` specification.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Speaker {
+    /// Front left, or the left channel of a stereo pair.
+    FrontLeft,
+    /// Front right, or the right channel of a stereo pair.
+    FrontRight,
+    /// Front center, or the single channel of a mono signal.
+    FrontCenter,
+    /// Low frequency effects.
+    LowFrequency,
+    /// Back left, or the left surround of a quadraphonic layout.
+    BackLeft,
+    /// Back right, or the right surround of a quadraphonic layout.
+    BackRight,
+    /// Front left of center.
+    FrontLeftOfCenter,
+    /// Front right of center.
+    FrontRightOfCenter,
+    /// Back center.
+    BackCenter,
+    /// Side left, or the left surround of a 5.1 layout.
+    SideLeft,
+    /// Side right, or the right surround of a 5.1 layout.
+    SideRight,
+    /// Top center.
+    TopCenter,
+    /// Top front left.
+    TopFrontLeft,
+    /// Top front center.
+    TopFrontCenter,
+    /// Top front right.
+    TopFrontRight,
+    /// Top back left.
+    TopBackLeft,
+    /// Top back center.
+    TopBackCenter,
+    /// Top back right.
+    TopBackRight,
+    /// A channel with no speaker position, e.g. one of several independent microphones. Each
+    /// discrete channel is identified by its index.
+    Discrete(u8),
+}
+
+/// The standard channel layouts.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Standard {
+    /// Front center.
+    Mono,
+    /// Front left and right.
+    Stereo,
+    /// Front left, right and center.
+    Lcr,
+    /// Front left and right, back left and right.
+    Quad,
+    /// Front left, right and center, side left and right, low frequency effects.
+    Surround51,
+    /// **Surround51** with the addition of back left and right.
+    Surround71,
+    /// **Surround71** with the addition of top front left and right, top back left and right.
+    Surround714,
+}
+
+/// The order in which the channels of a **Standard** layout are arranged.
+///
+/// The orders only differ for layouts with a center or low frequency effects channel.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ChannelOrder {
+    /// The order used by WAVE files and most audio device APIs, as standardised by SMPTE and
+    /// ITU-R, e.g. `L R C LFE Ls Rs` for 5.1.
+    Smpte,
+    /// The order used by film post-production, e.g. `L C R Ls Rs LFE` for 5.1.
+    Film,
+}
+
+/// The speaker position of each channel within a frame, in channel order.
+///
+/// # Example
+///
+/// ```rust
+/// use dasp_frame::layout::{ChannelLayout, ChannelOrder, Speaker, Standard};
+///
+/// fn main() {
+///     let smpte = ChannelLayout::standard(Standard::Surround51, ChannelOrder::Smpte);
+///     let film = ChannelLayout::standard(Standard::Surround51, ChannelOrder::Film);
+///     assert_eq!(smpte.len(), 6);
+///     assert_eq!(smpte.index_of(Speaker::FrontCenter), Some(2));
+///     assert_eq!(film.index_of(Speaker::FrontCenter), Some(1));
+///
+///     let frame = [0.1, 0.2, 0.3, 0.4, 0.5, 0.6];
+///     assert_eq!(smpte.channel(&frame, Speaker::LowFrequency), Some(&0.4));
+///     assert_eq!(smpte.channel(&frame, Speaker::TopCenter), None);
+///
+///     let to_film = smpte.map_to(&film);
+///     assert_eq!(to_film.apply::<_, [f32; 6]>(frame), [0.1, 0.3, 0.2, 0.5, 0.6, 0.4]);
+/// }
+/// ```
+#[derive(Copy, Clone)]
+pub struct ChannelLayout {
+    speakers: [Speaker; MAX_CHANNELS],
+    len: u8,
+}
+
+/// A mapping from the channels of one **ChannelLayout** to those of another.
+///
+/// Each channel of the target layout is taken from the channel of the source layout with the
+/// same **Speaker** position, or set to the equilibrium if the source has no such channel.
+#[derive(Copy, Clone)]
+pub struct ChannelMap {
+    sources: [Option<u8>; MAX_CHANNELS],
+    len: u8,
+}
+
+impl Speaker {
+    /// The WAVE channel mask bit of the speaker position, or `None` for discrete channels.
+    pub fn channel_mask_bit(self) -> Option<u32> {
+        let index = match self {
+            Speaker::FrontLeft => 0,
+            Speaker::FrontRight => 1,
+            Speaker::FrontCenter => 2,
+            Speaker::LowFrequency => 3,
+            Speaker::BackLeft => 4,
+            Speaker::BackRight => 5,
+            Speaker::FrontLeftOfCenter => 6,
+            Speaker::FrontRightOfCenter => 7,
+            Speaker::BackCenter => 8,
+            Speaker::SideLeft => 9,
+            Speaker::SideRight => 10,
+            Speaker::TopCenter => 11,
+            Speaker::TopFrontLeft => 12,
+            Speaker::TopFrontCenter => 13,
+            Speaker::TopFrontRight => 14,
+            Speaker::TopBackLeft => 15,
+            Speaker::TopBackCenter => 16,
+            Speaker::TopBackRight => 17,
+            Speaker::Discrete(_) => return None,
+        };
+        Some(1 << index)
+    }
+}
+
+impl Standard {
+    /// The speaker positions of the layout in the given order.
+    pub fn speakers(self, order: ChannelOrder) -> &'static [Speaker] {
+        use self::Speaker::*;
+        match (self, order) {
+            (Standard::Mono, _) => &[FrontCenter],
+            (Standard::Stereo, _) => &[FrontLeft, FrontRight],
+            (Standard::Quad, _) => &[FrontLeft, FrontRight, BackLeft, BackRight],
+            (Standard::Lcr, ChannelOrder::Smpte) => &[FrontLeft, FrontRight, FrontCenter],
+            (Standard::Lcr, ChannelOrder::Film) => &[FrontLeft, FrontCenter, FrontRight],
+            (Standard::Surround51, ChannelOrder::Smpte) => &[
+                FrontLeft,
+                FrontRight,
+                FrontCenter,
+                LowFrequency,
+                SideLeft,
+                SideRight,
+            ],
+            (Standard::Surround51, ChannelOrder::Film) => &[
+                FrontLeft,
+                FrontCenter,
+                FrontRight,
+                SideLeft,
+                SideRight,
+                LowFrequency,
+            ],
+            (Standard::Surround71, ChannelOrder::Smpte) => &[
+                FrontLeft,
+                FrontRight,
+                FrontCenter,
+                LowFrequency,
+                BackLeft,
+                BackRight,
+                SideLeft,
+                SideRight,
+            ],
+            (Standard::Surround71, ChannelOrder::Film) => &[
+                FrontLeft,
+                FrontCenter,
+                FrontRight,
+                SideLeft,
+                SideRight,
+                BackLeft,
+                BackRight,
+                LowFrequency,
+            ],
+            (Standard::Surround714, ChannelOrder::Smpte) => &[
+                FrontLeft,
+                FrontRight,
+                FrontCenter,
+                LowFrequency,
+                BackLeft,
+                BackRight,
+                SideLeft,
+                SideRight,
+                TopFrontLeft,
+                TopFrontRight,
+                TopBackLeft,
+                TopBackRight,
+            ],
+            (Standard::Surround714, ChannelOrder::Film) => &[
+                FrontLeft,
+                FrontCenter,
+                FrontRight,
+                SideLeft,
+                SideRight,
+                BackLeft,
+                BackRight,
+                LowFrequency,
+                TopFrontLeft,
+                TopFrontRight,
+                TopBackLeft,
+                TopBackRight,
+            ],
+        }
+    }
+}
+
+impl ChannelLayout {
+    /// Construct a layout with the given speaker position for each channel.
+    ///
+    /// Returns `None` if there are more than `MAX_CHANNELS` speakers or if any speaker position
+    /// appears more than once.
+    pub fn new(speakers: &[Speaker]) -> Option<Self> {
+        if speakers.len() > MAX_CHANNELS {
+            return None;
+        }
+        let mut layout = ChannelLayout {
+            speakers: [Speaker::Discrete(0); MAX_CHANNELS],
+            len: 0,
+        };
+        for &speaker in speakers {
+            if layout.contains(speaker) {
+                return None;
+            }
+            layout.speakers[layout.len as usize] = speaker;
+            layout.len += 1;
+        }
+        Some(layout)
+    }
+
+    /// A **Standard** layout in the given order.
+    pub fn standard(standard: Standard, order: ChannelOrder) -> Self {
+        Self::new(standard.speakers(order)).expect("standard layouts are valid")
+    }
+
+    /// A layout of `channels` discrete channels with no speaker positions.
+    ///
+    /// **Panics** if `channels` is greater than `MAX_CHANNELS`.
+    pub fn discrete(channels: usize) -> Self {
+        assert!(channels <= MAX_CHANNELS);
+        let mut speakers = [Speaker::Discrete(0); MAX_CHANNELS];
+        for (i, speaker) in speakers.iter_mut().enumerate() {
+            *speaker = Speaker::Discrete(i as u8);
+        }
+        ChannelLayout {
+            speakers,
+            len: channels as u8,
+        }
+    }
+
+    /// The layout described by the given WAVE channel mask, with the channels in the order of
+    /// their mask bits.
+    ///
+    /// Bits that do not correspond to a speaker position are ignored.
+    pub fn from_channel_mask(mask: u32) -> Self {
+        let mut layout = ChannelLayout::discrete(0);
+        for &speaker in ALL_POSITIONS.iter() {
+            if speaker.channel_mask_bit().unwrap() & mask != 0 {
+                layout.speakers[layout.len as usize] = speaker;
+                layout.len += 1;
+            }
+        }
+        layout
+    }
+
+    /// The WAVE channel mask describing the layout.
+    ///
+    /// Returns `None` if the layout contains discrete channels or if its channels are not in the
+    /// order of their mask bits, as a channel mask cannot describe such a layout.
+    pub fn channel_mask(&self) -> Option<u32> {
+        let mut mask = 0;
+        for speaker in self.speakers() {
+            let bit = speaker.channel_mask_bit()?;
+            if bit <= mask {
+                return None;
+            }
+            mask |= bit;
+        }
+        Some(mask)
+    }
+
+    /// The speaker position of each channel, in channel order.
+    pub fn speakers(&self) -> &[Speaker] {
+        &self.speakers[..self.len as usize]
+    }
+
+    /// The number of channels within the layout.
+    pub fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    /// Whether or not the layout has no channels.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The index of the channel with the given speaker position.
+    pub fn index_of(&self, speaker: Speaker) -> Option<usize> {
+        self.speakers().iter().position(|&s| s == speaker)
+    }
+
+    /// Whether or not the layout has a channel with the given speaker position.
+    pub fn contains(&self, speaker: Speaker) -> bool {
+        self.index_of(speaker).is_some()
+    }
+
+    /// Borrow the sample of the given frame at the given speaker position.
+    ///
+    /// Returns `None` if the layout has no such speaker or the frame has no such channel.
+    pub fn channel<'a, F>(&self, frame: &'a F, speaker: Speaker) -> Option<&'a F::Sample>
+    where
+        F: Frame,
+    {
+        self.index_of(speaker).and_then(|i| frame.channel(i))
+    }
+
+    /// Mutably borrow the sample of the given frame at the given speaker position.
+    ///
+    /// Returns `None` if the layout has no such speaker or the frame has no such channel.
+    pub fn channel_mut<'a, F>(
+        &self,
+        frame: &'a mut F,
+        speaker: Speaker,
+    ) -> Option<&'a mut F::Sample>
+    where
+        F: Frame,
+    {
+        self.index_of(speaker)
+            .and_then(move |i| frame.channel_mut(i))
+    }
+
+    /// The **ChannelMap** that rearranges frames of this layout into frames of the given layout.
+    pub fn map_to(&self, target: &ChannelLayout) -> ChannelMap {
+        let mut sources = [None; MAX_CHANNELS];
+        for (source, &speaker) in sources.iter_mut().zip(target.speakers()) {
+            *source = self.index_of(speaker).map(|i| i as u8);
+        }
+        ChannelMap {
+            sources,
+            len: target.len,
+        }
+    }
+}
+
+impl ChannelMap {
+    /// The index of the source channel for each channel of the target layout, or `None` for
+    /// target channels that are not present within the source layout.
+    pub fn sources(&self) -> &[Option<u8>] {
+        &self.sources[..self.len as usize]
+    }
+
+    /// Whether or not every channel of the target layout is taken from the source layout.
+    pub fn is_complete(&self) -> bool {
+        self.sources().iter().all(|s| s.is_some())
+    }
+
+    /// Rearrange the channels of the given frame.
+    ///
+    /// Channels of `G` beyond those of the target layout, along with those taken from channels
+    /// beyond those of `F`, are set to the equilibrium.
+    #[inline]
+    pub fn apply<F, G>(&self, frame: F) -> G
+    where
+        F: Frame,
+        G: Frame<Sample = F::Sample>,
+    {
+        G::from_fn(|channel| {
+            self.sources()
+                .get(channel)
+                .cloned()
+                .flatten()
+                .and_then(|source| frame.channel(source as usize).cloned())
+                .unwrap_or(F::Sample::EQUILIBRIUM)
+        })
+    }
+}
+
+impl PartialEq for ChannelLayout {
+    fn eq(&self, other: &Self) -> bool {
+        self.speakers() == other.speakers()
+    }
+}
+
+impl Eq for ChannelLayout {}
+
+impl core::hash::Hash for ChannelLayout {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.speakers().hash(state);
+    }
+}
+
+impl core::fmt::Debug for ChannelLayout {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_list().entries(self.speakers()).finish()
+    }
+}
+
+impl PartialEq for ChannelMap {
+    fn eq(&self, other: &Self) -> bool {
+        self.sources() == other.sources()
+    }
+}
+
+impl Eq for ChannelMap {}
+
+impl core::fmt::Debug for ChannelMap {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_list().entries(self.sources()).finish()
+    }
+}
+
+impl From<Standard> for ChannelLayout {
+    /// The standard layout in **ChannelOrder::Smpte**.
+    fn from(standard: Standard) -> Self {
+        ChannelLayout::standard(standard, ChannelOrder::Smpte)
+    }
+}
+
+// Every named speaker position in the order of their WAVE channel mask bits.
+const ALL_POSITIONS: [Speaker; 18] = [
+    Speaker::FrontLeft,
+    Speaker::FrontRight,
+    Speaker::FrontCenter,
+    Speaker::LowFrequency,
+    Speaker::BackLeft,
+    Speaker::BackRight,
+    Speaker::FrontLeftOfCenter,
+    Speaker::FrontRightOfCenter,
+    Speaker::BackCenter,
+    Speaker::SideLeft,
+    Speaker::SideRight,
+    Speaker::TopCenter,
+    Speaker::TopFrontLeft,
+    Speaker::TopFrontCenter,
+    Speaker::TopFrontRight,
+    Speaker::TopBackLeft,
+    Speaker::TopBackCenter,
+    Speaker::TopBackRight,
+];
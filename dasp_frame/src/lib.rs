@@ -1,13 +1,28 @@
 //! Use the [**Frame**](./trait.Frame.html) trait to remain generic over the number of channels at
 //! a single discrete moment in time.
 //!
-//! Implementations are provided for all fixed-size arrays up to 32 elements in length.
+//! Implementations are provided for all fixed-size arrays up to 32 elements in length, along with
+//! the [**DynFrame**](./struct.DynFrame.html) type for channel counts only known at runtime.
+//!
+//! ### Optional Features
+//!
+//! - The **half** feature (or **sample-half** feature if using `dasp`) provides single channel
+//!   **Frame** implementations for the half precision `f16` and `bf16` sample types.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use core::{iter::DoubleEndedIterator, mem::MaybeUninit};
 
-use dasp_sample::Sample;
+use dasp_sample::conv::NominalRange;
+use dasp_sample::{IntoAmp, Sample, ToSample};
+
+pub use dynamic::{DynChannels, DynFrame};
+pub use layout::{ChannelLayout, Speaker};
+
+mod dynamic;
+pub mod layout;
+pub mod remix;
+pub mod stereo;
 
 /// Represents one sample from each channel at a single discrete instance in time within a
 /// PCM signal.
@@ -16,6 +31,7 @@ use dasp_sample::Sample;
 ///
 /// - All fixed-size arrays up to a length of 32 elements.
 /// - All primitive types that implement `Sample`. These implementations assume `CHANNELS = 1`.
+/// - `DynFrame`, whose number of channels is determined at runtime.
 pub trait Frame: Copy + Clone + PartialEq {
     /// The type of PCM sample stored at each channel within the frame.
     type Sample: Sample;
@@ -183,6 +199,106 @@ pub trait Frame: Copy + Clone + PartialEq {
     /// ```
     fn to_float_frame(self) -> Self::Float;
 
+    /// Converts the frame to an equivalent frame of another sample type, first clamping each
+    /// sample to its nominal range.
+    ///
+    /// See `Sample::to_sample_saturating` for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use dasp_frame::Frame;
+    ///
+    /// fn main() {
+    ///     let foo: [i16; 2] = [1.3, -0.5].to_frame_saturating();
+    ///     assert_eq!(foo, [32_767, -16_384]);
+    /// }
+    /// ```
+    #[inline]
+    fn to_frame_saturating<F>(self) -> F
+    where
+        F: Frame<NumChannels = Self::NumChannels>,
+        Self::Sample: ToSample<F::Sample> + NominalRange,
+    {
+        self.map(Sample::to_sample_saturating)
+    }
+
+    /// Converts the frame to an equivalent frame of another sample type, first wrapping each
+    /// sample around its nominal range.
+    ///
+    /// See `Sample::to_sample_wrapping` for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use dasp_frame::Frame;
+    ///
+    /// fn main() {
+    ///     let foo: [f32; 2] = [1.25, -0.5].to_frame_wrapping();
+    ///     assert_eq!(foo, [-0.75, -0.5]);
+    /// }
+    /// ```
+    #[inline]
+    fn to_frame_wrapping<F>(self) -> F
+    where
+        F: Frame<NumChannels = Self::NumChannels>,
+        Self::Sample: ToSample<F::Sample> + NominalRange,
+    {
+        self.map(Sample::to_sample_wrapping)
+    }
+
+    /// Converts the frame to an equivalent frame of another sample type, returning `None` if any
+    /// of its samples lie outside of their nominal range.
+    ///
+    /// See `Sample::to_sample_checked` for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use dasp_frame::Frame;
+    ///
+    /// fn main() {
+    ///     assert_eq!([1.0, -0.5].to_frame_checked::<[i8; 2]>(), Some([127, -64]));
+    ///     assert_eq!([1.3, -0.5].to_frame_checked::<[i8; 2]>(), None);
+    /// }
+    /// ```
+    #[inline]
+    fn to_frame_checked<F>(self) -> Option<F>
+    where
+        F: Frame<NumChannels = Self::NumChannels>,
+        Self::Sample: ToSample<F::Sample> + NominalRange,
+    {
+        if self.channels().all(NominalRange::is_in_range) {
+            Some(self.map(Sample::to_sample))
+        } else {
+            None
+        }
+    }
+
+    /// Converts the frame to an equivalent frame of another sample type.
+    ///
+    /// **Panics** in debug builds if any of its samples lie outside of their nominal range. See
+    /// `Sample::to_sample_debug_checked` for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use dasp_frame::Frame;
+    ///
+    /// fn main() {
+    ///     let foo: [u8; 2] = [0.5, -0.5].to_frame_debug_checked();
+    ///     assert_eq!(foo, [192, 64]);
+    /// }
+    /// ```
+    #[inline]
+    fn to_frame_debug_checked<F>(self) -> F
+    where
+        F: Frame<NumChannels = Self::NumChannels>,
+        Self::Sample: ToSample<F::Sample> + NominalRange,
+    {
+        self.map(Sample::to_sample_debug_checked)
+    }
+
     /// Offsets the amplitude of every channel in the frame by the given `offset` and yields the
     /// resulting frame.
     ///
@@ -210,17 +326,26 @@ pub trait Frame: Copy + Clone + PartialEq {
     /// - A == 1.0 yields the same sample.
     /// - A == 0.0 yields the `Sample::equilibrium`.
     ///
+    /// The amplitude may also be given as a linear `Gain` or in `Decibels`.
+    ///
     /// # Example
     ///
     /// ```rust
     /// use dasp_frame::Frame;
+    /// use dasp_sample::{Decibels, Gain};
     ///
     /// fn main() {
     ///     assert_eq!([0.1, 0.2, -0.1, -0.2].scale_amp(2.0), [0.2, 0.4, -0.2, -0.4]);
+    ///     assert_eq!([0.5, -0.25].scale_amp(Gain(0.5)), [0.25, -0.125]);
+    ///     assert_eq!([1_000i16, -1_000].scale_amp(Decibels(-20.0)), [100, -100]);
     /// }
     /// ```
     #[inline]
-    fn scale_amp(self, amp: <Self::Sample as Sample>::Float) -> Self {
+    fn scale_amp<A>(self, amp: A) -> Self
+    where
+        A: IntoAmp<<Self::Sample as Sample>::Float>,
+    {
+        let amp = amp.into_amp();
         self.map(|s| s.mul_amp(amp))
     }
 
@@ -278,6 +403,13 @@ pub trait Frame: Copy + Clone + PartialEq {
 /// This trait is implemented for types `N1`...`N32`.
 pub trait NumChannels {}
 
+/// Restricts `Frame::NumChannels` to types whose number of channels is fixed at compile-time.
+///
+/// Operations that determine the number of channels within a frame from `Frame::CHANNELS` require
+/// `F::NumChannels: FixedChannels`. This trait is implemented for `NChannels`, but not for the
+/// `DynChannels` of a `DynFrame`, whose `CHANNELS` is only its capacity.
+pub trait FixedChannels: NumChannels {}
+
 pub type Mono<S> = [S; 1];
 pub type Stereo<S> = [S; 2];
 
@@ -297,6 +429,7 @@ pub struct ChannelsMut<'a, F: Frame>(core::slice::IterMut<'a, F::Sample>);
 
 pub struct NChannels<const N: usize> {}
 impl<const N: usize> NumChannels for NChannels<N> {}
+impl<const N: usize> FixedChannels for NChannels<N> {}
 
 impl<S, const N: usize> Frame for [S; N]
 where
@@ -540,8 +673,11 @@ macro_rules! impl_frame_for_sample {
                 }
 
                 #[inline]
-                fn scale_amp(self, amp: <$T as Sample>::Float) -> Self {
-                    Sample::mul_amp(self, amp)
+                fn scale_amp<A>(self, amp: A) -> Self
+                where
+                    A: IntoAmp<<$T as Sample>::Float>,
+                {
+                    Sample::mul_amp(self, amp.into_amp())
                 }
 
                 #[inline]
@@ -568,6 +704,15 @@ impl_frame_for_sample! {
     dasp_sample::types::I48
     dasp_sample::types::U24
     dasp_sample::types::U48
+    dasp_sample::types::MuLaw
+    dasp_sample::types::ALaw
+    dasp_sample::types::Q15
+    dasp_sample::types::Q31
+}
+#[cfg(feature = "half")]
+impl_frame_for_sample! {
+    dasp_sample::f16
+    dasp_sample::bf16
 }
 
 impl<F> Iterator for Channels<F>
@@ -590,7 +735,7 @@ where
 {
     #[inline]
     fn len(&self) -> usize {
-        F::CHANNELS - self.next_idx
+        self.frame.channels_ref().len() - self.next_idx
     }
 }
 
@@ -0,0 +1,315 @@
+//! Converting frames between channel counts with a matrix of mixing coefficients.
+//!
+//! Each channel of an output frame produced by a [**Matrix**](./struct.Matrix.html) is the sum
+//! of every channel of the input frame multiplied by its coefficient. Matrices may be written by
+//! hand, taken from the standard ITU-R BS.775 downmixes, or derived from a pair of
+//! **ChannelLayout**s along with an [**Upmix**](./enum.Upmix.html) strategy for output channels
+//! that are not present within the input.
+
+use crate::layout::{ChannelLayout, ChannelOrder, Speaker, Standard};
+use crate::{Frame, NChannels};
+use dasp_sample::Sample;
+
+/// -3 dB, the gain at which a channel is folded into another by the standard downmixes.
+const MINUS_3DB: f32 = core::f32::consts::FRAC_1_SQRT_2;
+
+/// A matrix of coefficients for mixing frames of `I` channels into frames of `O` channels.
+///
+/// The coefficients are stored as one row of `I` coefficients per output channel.
+///
+/// # Example
+///
+/// ```rust
+/// use dasp_frame::remix::Matrix;
+///
+/// fn main() {
+///     let matrix = Matrix::stereo_to_mono();
+///     let mono = matrix.apply([0.5f32, 0.5]);
+///     assert!((mono[0] - 0.70710677).abs() < 1e-6);
+///
+///     let swap = Matrix::new([[0.0, 1.0], [1.0, 0.0]]);
+///     assert_eq!(swap.apply([1i16, 2]), [2, 1]);
+/// }
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Matrix<const I: usize, const O: usize> {
+    coefficients: [[f32; I]; O],
+}
+
+/// The strategy used by **Matrix::from_layouts** to derive output channels that are not present
+/// within the input layout.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Upmix {
+    /// Output channels that are not present within the input are silent.
+    Silent,
+    /// A missing front center channel is derived from the front left and right channels at -3
+    /// dB. Other missing channels are silent.
+    Center,
+    /// As **Center**, and missing side and back channels are derived from the surround channel
+    /// on the same side, or from the front channel on the same side at -3 dB.
+    Surround,
+}
+
+impl<const I: usize, const O: usize> Matrix<I, O> {
+    /// Construct a matrix from a row of `I` input coefficients for each of the `O` output
+    /// channels.
+    pub const fn new(coefficients: [[f32; I]; O]) -> Self {
+        Matrix { coefficients }
+    }
+
+    /// A matrix whose output is always silent.
+    pub const fn zero() -> Self {
+        Matrix::new([[0.0; I]; O])
+    }
+
+    /// A matrix that passes each input channel to the output channel with the same index.
+    ///
+    /// Input channels beyond `O` are discarded and output channels beyond `I` are silent.
+    pub fn identity() -> Self {
+        let mut matrix = Self::zero();
+        for (i, row) in matrix.coefficients.iter_mut().enumerate().take(I) {
+            row[i] = 1.0;
+        }
+        matrix
+    }
+
+    /// The matrix that mixes frames of the `from` layout into frames of the `to` layout.
+    ///
+    /// - Channels present within both layouts are passed through at unity gain.
+    /// - Input channels that are not present within the output are folded into the nearest
+    ///   available speakers at -3 dB, following the ITU-R BS.775 downmix. Low frequency effects
+    ///   and discrete channels without a match are discarded.
+    /// - Output channels that are not present within the input are derived according to the
+    ///   given **Upmix** strategy.
+    ///
+    /// Returns `None` if the length of `from` is not `I` or the length of `to` is not `O`.
+    pub fn from_layouts(from: &ChannelLayout, to: &ChannelLayout, upmix: Upmix) -> Option<Self> {
+        if from.len() != I || to.len() != O {
+            return None;
+        }
+        let mut matrix = Self::zero();
+        for (i, &speaker) in from.speakers().iter().enumerate() {
+            match to.index_of(speaker) {
+                Some(o) => matrix.coefficients[o][i] = 1.0,
+                None => {
+                    let targets = fold(speaker)
+                        .iter()
+                        .find(|targets| targets.iter().all(|&(s, _)| to.contains(s)));
+                    for &(s, gain) in targets.into_iter().flat_map(|t| t.iter()) {
+                        // Moving a surround channel to a position that is otherwise unused is a
+                        // relocation rather than a fold, so the level is preserved.
+                        let gain = if is_surround_pair(speaker, s) && !from.contains(s) {
+                            1.0
+                        } else {
+                            gain
+                        };
+                        let o = to.index_of(s).expect("checked above");
+                        matrix.coefficients[o][i] += gain;
+                    }
+                }
+            }
+        }
+        for (o, &speaker) in to.speakers().iter().enumerate() {
+            let row = &matrix.coefficients[o];
+            if from.contains(speaker) || row.iter().any(|&c| c != 0.0) {
+                continue;
+            }
+            for &(s, gain) in derive(speaker, from, upmix) {
+                if let Some(i) = from.index_of(s) {
+                    matrix.coefficients[o][i] += gain;
+                }
+            }
+        }
+        Some(matrix)
+    }
+
+    /// The coefficients of the matrix, as one row of input coefficients per output channel.
+    pub fn coefficients(&self) -> &[[f32; I]; O] {
+        &self.coefficients
+    }
+
+    /// The coefficient of the given input channel within the given output channel.
+    ///
+    /// **Panics** if `output >= O` or `input >= I`.
+    pub fn coefficient(&self, output: usize, input: usize) -> f32 {
+        self.coefficients[output][input]
+    }
+
+    /// Set the coefficient of the given input channel within the given output channel.
+    ///
+    /// **Panics** if `output >= O` or `input >= I`.
+    pub fn set_coefficient(&mut self, output: usize, input: usize, coefficient: f32) {
+        self.coefficients[output][input] = coefficient;
+    }
+
+    /// The matrix that applies `self` followed by `next`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use dasp_frame::remix::Matrix;
+    ///
+    /// fn main() {
+    ///     let to_51 = Matrix::surround71_to_surround51();
+    ///     let to_stereo = to_51.then(&Matrix::surround51_to_stereo());
+    ///     // The back left channel is folded into side left, then into front left.
+    ///     let [l, r] = to_stereo.apply([0.0f32, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0]);
+    ///     assert!((l - 0.5).abs() < 1e-6);
+    ///     assert_eq!(r, 0.0);
+    /// }
+    /// ```
+    pub fn then<const P: usize>(&self, next: &Matrix<O, P>) -> Matrix<I, P> {
+        let mut matrix = Matrix::zero();
+        for (row, next_row) in matrix.coefficients.iter_mut().zip(next.coefficients.iter()) {
+            for (i, coefficient) in row.iter_mut().enumerate() {
+                *coefficient = next_row
+                    .iter()
+                    .zip(self.coefficients.iter())
+                    .map(|(&n, self_row)| n * self_row[i])
+                    .sum();
+            }
+        }
+        matrix
+    }
+
+    /// Mix the given frame into a frame of `O` channels.
+    ///
+    /// The mix is performed using the frame's associated floating point sample format.
+    ///
+    /// The frame must have exactly `I` channels, which is checked at compile time:
+    ///
+    /// ```compile_fail
+    /// use dasp_frame::remix::Matrix;
+    ///
+    /// fn main() {
+    ///     Matrix::stereo_to_mono().apply([0.0f32; 3]);
+    /// }
+    /// ```
+    #[inline]
+    pub fn apply<F>(&self, frame: F) -> [F::Sample; O]
+    where
+        F: Frame<NumChannels = NChannels<I>>,
+    {
+        let frame = frame.to_float_frame();
+        Frame::from_fn(|o| {
+            let mut sum = <F::Sample as Sample>::Float::EQUILIBRIUM;
+            for (sample, &coefficient) in frame.channels().zip(self.coefficients[o].iter()) {
+                sum = sum.add_amp(sample * coefficient.to_sample());
+            }
+            sum.to_sample()
+        })
+    }
+}
+
+impl Matrix<6, 2> {
+    /// The ITU-R BS.775 downmix from 5.1 in SMPTE order to stereo.
+    ///
+    /// The center and each surround channel are folded into the front channels at -3 dB, while
+    /// the low frequency effects channel is discarded.
+    pub fn surround51_to_stereo() -> Self {
+        Self::from_standards(Standard::Surround51, Standard::Stereo)
+    }
+}
+
+impl Matrix<8, 6> {
+    /// The downmix from 7.1 in SMPTE order to 5.1 in SMPTE order.
+    ///
+    /// Each back channel is folded into the side channel of the same side at -3 dB, following the
+    /// ITU-R BS.775 treatment of surround channels.
+    pub fn surround71_to_surround51() -> Self {
+        Self::from_standards(Standard::Surround71, Standard::Surround51)
+    }
+}
+
+impl Matrix<2, 1> {
+    /// The ITU-R BS.775 downmix from stereo to mono, summing both channels at -3 dB.
+    pub fn stereo_to_mono() -> Self {
+        Self::from_standards(Standard::Stereo, Standard::Mono)
+    }
+}
+
+impl<const I: usize, const O: usize> Matrix<I, O> {
+    fn from_standards(from: Standard, to: Standard) -> Self {
+        let from = ChannelLayout::standard(from, ChannelOrder::Smpte);
+        let to = ChannelLayout::standard(to, ChannelOrder::Smpte);
+        Self::from_layouts(&from, &to, Upmix::Silent).expect("matrix size matches layouts")
+    }
+}
+
+impl<const I: usize, const O: usize> Default for Matrix<I, O> {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+// The speakers into which the given speaker is folded when absent from the output layout, as
+// alternatives in order of preference. The first alternative whose speakers are all present
+// within the output is used.
+fn fold(speaker: Speaker) -> &'static [&'static [(Speaker, f32)]] {
+    use self::Speaker::*;
+    const H: f32 = MINUS_3DB;
+    const Q: f32 = 0.5;
+    match speaker {
+        FrontLeft => &[&[(FrontCenter, H)]],
+        FrontRight => &[&[(FrontCenter, H)]],
+        FrontCenter => &[&[(FrontLeft, H), (FrontRight, H)]],
+        FrontLeftOfCenter => &[&[(FrontLeft, 1.0)], &[(FrontCenter, H)]],
+        FrontRightOfCenter => &[&[(FrontRight, 1.0)], &[(FrontCenter, H)]],
+        SideLeft => &[&[(BackLeft, H)], &[(FrontLeft, H)], &[(FrontCenter, Q)]],
+        SideRight => &[&[(BackRight, H)], &[(FrontRight, H)], &[(FrontCenter, Q)]],
+        BackLeft => &[&[(SideLeft, H)], &[(FrontLeft, H)], &[(FrontCenter, Q)]],
+        BackRight => &[&[(SideRight, H)], &[(FrontRight, H)], &[(FrontCenter, Q)]],
+        BackCenter => &[
+            &[(BackLeft, H), (BackRight, H)],
+            &[(SideLeft, H), (SideRight, H)],
+            &[(FrontLeft, Q), (FrontRight, Q)],
+        ],
+        TopFrontLeft => &[&[(FrontLeft, H)], &[(FrontCenter, Q)]],
+        TopFrontRight => &[&[(FrontRight, H)], &[(FrontCenter, Q)]],
+        TopFrontCenter => &[&[(FrontCenter, H)], &[(FrontLeft, Q), (FrontRight, Q)]],
+        TopBackLeft => &[&[(BackLeft, H)], &[(SideLeft, H)], &[(FrontLeft, Q)]],
+        TopBackRight => &[&[(BackRight, H)], &[(SideRight, H)], &[(FrontRight, Q)]],
+        TopBackCenter => &[
+            &[(BackCenter, H)],
+            &[(BackLeft, Q), (BackRight, Q)],
+            &[(SideLeft, Q), (SideRight, Q)],
+        ],
+        TopCenter => &[&[(FrontLeft, Q), (FrontRight, Q)], &[(FrontCenter, H)]],
+        LowFrequency | Discrete(_) => &[],
+    }
+}
+
+// The input speakers from which the given output speaker is derived when absent from the input
+// layout.
+fn derive(speaker: Speaker, from: &ChannelLayout, upmix: Upmix) -> &'static [(Speaker, f32)] {
+    use self::Speaker::*;
+    const H: f32 = MINUS_3DB;
+    let first_present = |alternatives: &'static [&'static [(Speaker, f32)]]| {
+        alternatives
+            .iter()
+            .find(|a| a.iter().all(|&(s, _)| from.contains(s)))
+            .copied()
+            .unwrap_or(&[])
+    };
+    match (upmix, speaker) {
+        (Upmix::Silent, _) => &[],
+        (_, FrontCenter) => first_present(&[&[(FrontLeft, H), (FrontRight, H)]]),
+        (Upmix::Surround, SideLeft) => first_present(&[&[(BackLeft, 1.0)], &[(FrontLeft, H)]]),
+        (Upmix::Surround, SideRight) => first_present(&[&[(BackRight, 1.0)], &[(FrontRight, H)]]),
+        (Upmix::Surround, BackLeft) => first_present(&[&[(SideLeft, 1.0)], &[(FrontLeft, H)]]),
+        (Upmix::Surround, BackRight) => first_present(&[&[(SideRight, 1.0)], &[(FrontRight, H)]]),
+        _ => &[],
+    }
+}
+
+// Whether or not the two speakers are the side and back positions on the same side.
+fn is_surround_pair(a: Speaker, b: Speaker) -> bool {
+    use self::Speaker::*;
+    matches!(
+        (a, b),
+        (SideLeft, BackLeft)
+            | (BackLeft, SideLeft)
+            | (SideRight, BackRight)
+            | (BackRight, SideRight)
+    )
+}
@@ -0,0 +1,127 @@
+//! Operations on the stereo field of two-channel frames.
+//!
+//! The [**StereoFrame**](./trait.StereoFrame.html) trait is implemented for every **Frame** with
+//! two channels, where channel `0` is the left channel and channel `1` is the right.
+
+use crate::{Frame, NChannels};
+use dasp_sample::{FloatSample, Sample};
+
+/// Operations on the stereo field of two-channel frames.
+///
+/// All operations are performed using the frame's associated floating point sample format.
+///
+/// # Example
+///
+/// ```rust
+/// use dasp_frame::stereo::StereoFrame;
+///
+/// fn main() {
+///     let frame = [0.75f32, 0.25];
+///     let mid_side = frame.to_mid_side();
+///     assert_eq!(mid_side, [0.5, 0.25]);
+///     assert_eq!(mid_side.from_mid_side(), frame);
+///     assert_eq!(frame.stereo_width(0.0), [0.5, 0.5]);
+///     assert_eq!(frame.balance(-0.5), [0.75, 0.125]);
+/// }
+/// ```
+pub trait StereoFrame: Frame<NumChannels = NChannels<2>> {
+    /// The sample of the left channel.
+    #[inline]
+    fn left(&self) -> Self::Sample {
+        // Frames with `NChannels<2>` always have two channels.
+        unsafe { *self.channel_unchecked(0) }
+    }
+
+    /// The sample of the right channel.
+    #[inline]
+    fn right(&self) -> Self::Sample {
+        // Frames with `NChannels<2>` always have two channels.
+        unsafe { *self.channel_unchecked(1) }
+    }
+
+    /// Encode the left and right channels as mid (channel `0`) and side (channel `1`) channels.
+    ///
+    /// The mid channel is `(left + right) / 2` and the side channel is `(left - right) / 2`, so
+    /// that encoding never exceeds the range of the original samples and `from_mid_side` is its
+    /// inverse.
+    #[inline]
+    fn to_mid_side(self) -> Self {
+        let (l, r) = float_channels(&self);
+        let half = half::<Self>();
+        from_float_channels((l + r) * half, (l - r) * half)
+    }
+
+    /// Decode mid (channel `0`) and side (channel `1`) channels as left and right channels.
+    ///
+    /// The left channel is `mid + side` and the right channel is `mid - side`.
+    #[inline]
+    #[allow(clippy::wrong_self_convention)]
+    fn from_mid_side(self) -> Self {
+        let (m, s) = float_channels(&self);
+        from_float_channels(m + s, m - s)
+    }
+
+    /// Scale the width of the stereo field by scaling the side channel by `width`.
+    ///
+    /// A `width` of `0.0` collapses the frame to mono, `1.0` leaves it unchanged and values
+    /// greater than `1.0` widen it.
+    #[inline]
+    fn stereo_width(self, width: <Self::Sample as Sample>::Float) -> Self {
+        let (l, r) = float_channels(&self);
+        let half = half::<Self>();
+        let mid = (l + r) * half;
+        let side = (l - r) * half * width;
+        from_float_channels(mid + side, mid - side)
+    }
+
+    /// Attenuate one side of the stereo field, where a `balance` of `-1.0` silences the right
+    /// channel, `0.0` leaves the frame unchanged and `1.0` silences the left channel.
+    ///
+    /// `balance` is clamped to the range `-1.0..=1.0`. The channel on the side toward which the
+    /// frame is balanced is never boosted.
+    #[inline]
+    fn balance(self, balance: <Self::Sample as Sample>::Float) -> Self {
+        let one = <<Self::Sample as Sample>::Float as FloatSample>::IDENTITY;
+        let balance = if balance > one {
+            one
+        } else if balance < -one {
+            -one
+        } else {
+            balance
+        };
+        let (l, r) = float_channels(&self);
+        let zero = <<Self::Sample as Sample>::Float as Sample>::EQUILIBRIUM;
+        if balance < zero {
+            from_float_channels(l, r * (one + balance))
+        } else {
+            from_float_channels(l * (one - balance), r)
+        }
+    }
+}
+
+impl<F> StereoFrame for F where F: Frame<NumChannels = NChannels<2>> {}
+
+fn half<F>() -> <F::Sample as Sample>::Float
+where
+    F: Frame,
+{
+    0.5f32.to_sample()
+}
+
+fn float_channels<F>(frame: &F) -> (<F::Sample as Sample>::Float, <F::Sample as Sample>::Float)
+where
+    F: StereoFrame,
+{
+    (
+        frame.left().to_float_sample(),
+        frame.right().to_float_sample(),
+    )
+}
+
+fn from_float_channels<F>(l: <F::Sample as Sample>::Float, r: <F::Sample as Sample>::Float) -> F
+where
+    F: StereoFrame,
+{
+    let (l, r) = (l.to_sample(), r.to_sample());
+    F::from_fn(|channel| if channel == 0 { l } else { r })
+}
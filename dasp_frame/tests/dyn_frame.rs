@@ -0,0 +1,75 @@
+use dasp_frame::{DynFrame, Frame};
+
+type Dyn<S> = DynFrame<S, 8>;
+
+#[test]
+fn test_construction() {
+    let frame = Dyn::<i16>::new(3);
+    assert_eq!(frame.as_slice(), &[0, 0, 0]);
+    assert_eq!(Dyn::<u8>::new(2).as_slice(), &[128, 128]);
+    assert!(Dyn::<f32>::new(0).is_empty());
+    assert_eq!(Dyn::<f32>::new(0), Dyn::EQUILIBRIUM);
+    assert_eq!(Dyn::<f32>::new(2), Dyn::EQUILIBRIUM);
+    assert_eq!(Dyn::<u8>::new(2), Dyn::from_slice(&[128]).unwrap());
+    assert_ne!(Dyn::<f32>::new(2), Dyn::from_slice(&[0.0, 0.5]).unwrap());
+
+    let frame = Dyn::from_slice(&[1, 2, 3, 4, 5]).unwrap();
+    assert_eq!(frame.len(), 5);
+    assert_eq!(frame.channel(4), Some(&5));
+    assert_eq!(frame.channel(5), None);
+    assert!(Dyn::from_slice(&[0; 9]).is_none());
+
+    assert_eq!(Dyn::<f32>::from_fn(|_| 0.5).len(), 8);
+    assert_eq!(<Dyn<f32>>::CHANNELS, 8);
+}
+
+#[test]
+#[should_panic]
+fn test_new_beyond_capacity() {
+    Dyn::<f32>::new(9);
+}
+
+#[test]
+fn test_from_samples() {
+    let mut samples = 0..7;
+    let a = Dyn::from_samples_with_channels(&mut samples, 3).unwrap();
+    let b = Dyn::from_samples_with_channels(&mut samples, 3).unwrap();
+    assert_eq!(a.as_slice(), &[0, 1, 2]);
+    assert_eq!(b.as_slice(), &[3, 4, 5]);
+    assert_eq!(Dyn::from_samples_with_channels(&mut samples, 3), None);
+
+    // Without a channel count, samples are read up to the capacity.
+    let mut samples = 0..10;
+    assert_eq!(Dyn::from_samples(&mut samples).unwrap().len(), 8);
+    assert_eq!(Dyn::from_samples(&mut samples).unwrap().as_slice(), &[8, 9]);
+    assert_eq!(Dyn::<i32>::from_samples(&mut samples), None);
+}
+
+#[test]
+fn test_channels() {
+    let mut frame = Dyn::from_slice(&[1, 2, 3]).unwrap();
+    for sample in frame.channels_mut() {
+        *sample *= 10;
+    }
+    assert_eq!(frame.channels_ref().len(), 3);
+    let channels = frame.channels();
+    assert_eq!(channels.len(), 3);
+    assert_eq!(channels.collect::<Vec<_>>(), vec![10, 20, 30]);
+}
+
+#[test]
+fn test_map_and_zip_map() {
+    let frame = Dyn::from_slice(&[0.5f32, -0.25]).unwrap();
+    let mapped: Dyn<i16> = frame.map(|s| (s * 4.0) as i16);
+    assert_eq!(mapped.as_slice(), &[2, -1]);
+    assert_eq!(frame.to_float_frame(), frame);
+    assert_eq!(frame.scale_amp(2.0).as_slice(), &[1.0, -0.5]);
+    assert_eq!(frame.offset_amp(0.5).as_slice(), &[1.0, 0.25]);
+
+    // Missing channels are treated as equilibrium.
+    let other = Dyn::from_slice(&[0.25f32, 0.25, 0.25]).unwrap();
+    assert_eq!(frame.add_amp(other).as_slice(), &[0.75, 0.0, 0.25]);
+    assert_eq!(frame.add_amp(Dyn::EQUILIBRIUM), frame);
+    let empty: Dyn<f32> = Dyn::EQUILIBRIUM;
+    assert!(empty.map::<Dyn<f64>, _>(|s| s as f64).is_empty());
+}
@@ -0,0 +1,86 @@
+use dasp_frame::layout::{ChannelLayout, ChannelOrder, Speaker, Standard};
+
+#[test]
+fn test_standard_layouts() {
+    let standards = [
+        (Standard::Mono, 1),
+        (Standard::Stereo, 2),
+        (Standard::Lcr, 3),
+        (Standard::Quad, 4),
+        (Standard::Surround51, 6),
+        (Standard::Surround71, 8),
+        (Standard::Surround714, 12),
+    ];
+    for &(standard, channels) in standards.iter() {
+        let smpte = ChannelLayout::standard(standard, ChannelOrder::Smpte);
+        let film = ChannelLayout::standard(standard, ChannelOrder::Film);
+        assert_eq!(smpte.len(), channels);
+        assert_eq!(film.len(), channels);
+        assert!(smpte.speakers().iter().all(|&s| film.contains(s)));
+        assert!(smpte.map_to(&film).is_complete());
+        // SMPTE order is the order of the WAVE channel mask bits.
+        let mask = smpte.channel_mask().unwrap();
+        assert_eq!(ChannelLayout::from_channel_mask(mask), smpte);
+    }
+    let film = ChannelLayout::standard(Standard::Surround51, ChannelOrder::Film);
+    assert_eq!(film.channel_mask(), None);
+    assert_eq!(
+        ChannelLayout::from(Standard::Surround51).channel_mask(),
+        Some(0x60F)
+    );
+}
+
+#[test]
+fn test_new_layout() {
+    use Speaker::*;
+    let layout = ChannelLayout::new(&[FrontLeft, Discrete(0), FrontRight]).unwrap();
+    assert_eq!(layout.index_of(Discrete(0)), Some(1));
+    assert_eq!(layout.channel_mask(), None);
+    assert!(ChannelLayout::new(&[FrontLeft, FrontLeft]).is_none());
+    assert!(ChannelLayout::new(&[FrontLeft; 33]).is_none());
+    assert!(ChannelLayout::new(&[]).unwrap().is_empty());
+
+    let discrete = ChannelLayout::discrete(4);
+    assert_eq!(
+        discrete.speakers(),
+        &[Discrete(0), Discrete(1), Discrete(2), Discrete(3)]
+    );
+    assert_eq!(ChannelLayout::discrete(32).len(), 32);
+}
+
+#[test]
+fn test_channel_by_speaker() {
+    let layout = ChannelLayout::standard(Standard::Lcr, ChannelOrder::Film);
+    let mut frame = [0.1, 0.2, 0.3];
+    assert_eq!(layout.channel(&frame, Speaker::FrontCenter), Some(&0.2));
+    *layout.channel_mut(&mut frame, Speaker::FrontRight).unwrap() = 0.5;
+    assert_eq!(frame, [0.1, 0.2, 0.5]);
+    assert_eq!(layout.channel(&frame, Speaker::LowFrequency), None);
+    // Layouts longer than the frame do not index out of bounds.
+    let layout = ChannelLayout::from(Standard::Quad);
+    assert_eq!(layout.channel(&[0.0, 1.0], Speaker::BackRight), None);
+}
+
+#[test]
+fn test_reorder_7_1_4() {
+    let smpte = ChannelLayout::standard(Standard::Surround714, ChannelOrder::Smpte);
+    let film = ChannelLayout::standard(Standard::Surround714, ChannelOrder::Film);
+    let frame: [i32; 12] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11];
+    let reordered: [i32; 12] = smpte.map_to(&film).apply(frame);
+    assert_eq!(reordered, [0, 2, 1, 6, 7, 4, 5, 3, 8, 9, 10, 11]);
+    let restored: [i32; 12] = film.map_to(&smpte).apply(reordered);
+    assert_eq!(restored, frame);
+}
+
+#[test]
+fn test_reorder_between_layouts() {
+    let stereo = ChannelLayout::from(Standard::Stereo);
+    let surround = ChannelLayout::standard(Standard::Surround51, ChannelOrder::Film);
+    let map = stereo.map_to(&surround);
+    assert!(!map.is_complete());
+    assert_eq!(map.sources(), &[Some(0), None, Some(1), None, None, None]);
+    let up: [u8; 6] = map.apply([10u8, 20]);
+    assert_eq!(up, [10, 128, 20, 128, 128, 128]);
+    let down: [u8; 2] = surround.map_to(&stereo).apply(up);
+    assert_eq!(down, [10, 20]);
+}
@@ -0,0 +1,111 @@
+use dasp_frame::layout::{ChannelLayout, ChannelOrder, Standard};
+use dasp_frame::remix::{Matrix, Upmix};
+
+const H: f32 = std::f32::consts::FRAC_1_SQRT_2;
+
+fn layout(standard: Standard) -> ChannelLayout {
+    ChannelLayout::standard(standard, ChannelOrder::Smpte)
+}
+
+#[test]
+fn test_standard_downmixes() {
+    assert_eq!(
+        Matrix::surround51_to_stereo().coefficients(),
+        &[[1.0, 0.0, H, 0.0, H, 0.0], [0.0, 1.0, H, 0.0, 0.0, H]]
+    );
+    assert_eq!(
+        Matrix::surround71_to_surround51().coefficients(),
+        &[
+            [1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0, 0.0, H, 0.0, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 0.0, 0.0, H, 0.0, 1.0],
+        ]
+    );
+    assert_eq!(Matrix::stereo_to_mono().coefficients(), &[[H, H]]);
+}
+
+#[test]
+fn test_apply() {
+    let matrix = Matrix::stereo_to_mono();
+    assert_eq!(matrix.apply([0.0f64, 0.0]), [0.0]);
+    assert_eq!(matrix.apply([i16::MIN, i16::MIN]), [i16::MIN]);
+    let [m] = matrix.apply([0.25f32, -0.25]);
+    assert_eq!(m, 0.0);
+    // Integer samples are mixed around their equilibrium.
+    assert_eq!(Matrix::new([[1.0, 1.0]]).apply([160u8, 96]), [128]);
+    assert_eq!(Matrix::<3, 3>::identity().apply([1, 2, 3]), [1, 2, 3]);
+    assert_eq!(Matrix::<3, 2>::identity().apply([1, 2, 3]), [1, 2]);
+    assert_eq!(Matrix::<2, 3>::identity().apply([1, 2]), [1, 2, 0]);
+    assert_eq!(Matrix::<2, 2>::zero().apply([1, 2]), [0, 0]);
+}
+
+#[test]
+fn test_then() {
+    let mut matrix = Matrix::<2, 2>::zero();
+    matrix.set_coefficient(0, 1, 2.0);
+    matrix.set_coefficient(1, 0, 3.0);
+    assert_eq!(matrix.coefficient(0, 1), 2.0);
+    let composed = matrix.then(&Matrix::new([[1.0, 1.0]]));
+    assert_eq!(composed.coefficients(), &[[3.0, 2.0]]);
+    assert_eq!(composed.apply([1, 1]), [5]);
+}
+
+#[test]
+fn test_upmix_strategies() {
+    let stereo = layout(Standard::Stereo);
+    let surround = layout(Standard::Surround51);
+    let silent = Matrix::<2, 6>::from_layouts(&stereo, &surround, Upmix::Silent).unwrap();
+    assert_eq!(
+        silent.apply([0.5f32, 0.25]),
+        [0.5, 0.25, 0.0, 0.0, 0.0, 0.0]
+    );
+    let center = Matrix::<2, 6>::from_layouts(&stereo, &surround, Upmix::Center).unwrap();
+    assert_eq!(center.coefficients()[2], [H, H]);
+    assert_eq!(center.coefficients()[4], [0.0, 0.0]);
+    let full = Matrix::<2, 6>::from_layouts(&stereo, &surround, Upmix::Surround).unwrap();
+    assert_eq!(full.coefficients()[2], [H, H]);
+    assert_eq!(full.coefficients()[3], [0.0, 0.0]);
+    assert_eq!(full.coefficients()[4], [H, 0.0]);
+    assert_eq!(full.coefficients()[5], [0.0, H]);
+
+    // Mono is spread across the front pair regardless of strategy.
+    let mono = layout(Standard::Mono);
+    let to_stereo = Matrix::<1, 2>::from_layouts(&mono, &stereo, Upmix::Silent).unwrap();
+    assert_eq!(to_stereo.coefficients(), &[[H], [H]]);
+
+    // Surrounds are relocated between side and back positions at unity.
+    let quad = layout(Standard::Quad);
+    let up = Matrix::<4, 6>::from_layouts(&quad, &surround, Upmix::Silent).unwrap();
+    assert_eq!(
+        up.apply([0.1f32, 0.2, 0.3, 0.4]),
+        [0.1, 0.2, 0.0, 0.0, 0.3, 0.4]
+    );
+    let seven = layout(Standard::Surround71);
+    let up = Matrix::<6, 8>::from_layouts(&surround, &seven, Upmix::Surround).unwrap();
+    assert_eq!(up.coefficients()[4], [0.0, 0.0, 0.0, 0.0, 1.0, 0.0]);
+    assert_eq!(up.coefficients()[6], [0.0, 0.0, 0.0, 0.0, 1.0, 0.0]);
+}
+
+#[test]
+fn test_from_layouts_size_mismatch() {
+    let stereo = layout(Standard::Stereo);
+    assert!(Matrix::<2, 3>::from_layouts(&stereo, &stereo, Upmix::Silent).is_none());
+    assert_eq!(
+        Matrix::<2, 2>::from_layouts(&stereo, &stereo, Upmix::Silent),
+        Some(Matrix::identity())
+    );
+}
+
+#[test]
+fn test_film_order_downmix() {
+    let film = ChannelLayout::standard(Standard::Surround51, ChannelOrder::Film);
+    let stereo = layout(Standard::Stereo);
+    let matrix = Matrix::<6, 2>::from_layouts(&film, &stereo, Upmix::Silent).unwrap();
+    assert_eq!(
+        matrix.coefficients(),
+        &[[1.0, H, 0.0, H, 0.0, 0.0], [0.0, H, 1.0, 0.0, H, 0.0]]
+    );
+}
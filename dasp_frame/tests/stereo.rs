@@ -0,0 +1,42 @@
+use dasp_frame::stereo::StereoFrame;
+
+#[test]
+fn test_mid_side_round_trip() {
+    let frames = [[0.5f64, 0.5], [1.0, -1.0], [0.375, -0.75], [-1.0, 0.0]];
+    for &frame in frames.iter() {
+        let [m, s] = frame.to_mid_side();
+        assert_eq!(m, (frame[0] + frame[1]) / 2.0);
+        assert_eq!(s, (frame[0] - frame[1]) / 2.0);
+        assert_eq!([m, s].from_mid_side(), frame);
+    }
+    // Integer samples are encoded relative to their equilibrium.
+    assert_eq!([200u8, 56].to_mid_side(), [128, 200]);
+    assert_eq!([16_384i16, 16_384].to_mid_side(), [16_384, 0]);
+}
+
+#[test]
+fn test_stereo_width() {
+    let frame = [0.75f32, -0.25];
+    assert_eq!(frame.stereo_width(1.0), frame);
+    assert_eq!(frame.stereo_width(0.0), [0.25, 0.25]);
+    assert_eq!(frame.stereo_width(2.0), [1.25, -0.75]);
+    assert_eq!(frame.stereo_width(-1.0), [-0.25, 0.75]);
+}
+
+#[test]
+fn test_balance() {
+    let frame = [0.5f32, -0.5];
+    assert_eq!(frame.balance(0.0), frame);
+    assert_eq!(frame.balance(-1.0), [0.5, 0.0]);
+    assert_eq!(frame.balance(1.0), [0.0, -0.5]);
+    assert_eq!(frame.balance(0.25), [0.375, -0.5]);
+    assert_eq!(frame.balance(-4.0), [0.5, 0.0]);
+    assert_eq!([1_000i16, 1_000].balance(-0.5f32), [1_000, 500]);
+}
+
+#[test]
+fn test_left_right() {
+    let frame = [1i8, 2];
+    assert_eq!(frame.left(), 1);
+    assert_eq!(frame.right(), 2);
+}
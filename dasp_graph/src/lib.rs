@@ -114,6 +114,8 @@
 //! - The **node-delay** feature provides a simple multi-channel `Delay` node.
 //! - The **node-pass** feature provides a `Pass` node that simply passes audio from its
 //!   inputs to its outputs.
+//! - The **node-remix** feature provides a `Remix` node that mixes its input channels into its
+//!   output channels using a `dasp_frame::remix::Matrix`, e.g. for downmixing 5.1 to stereo.
 //! - The **node-sum** feature provides `Sum` and `SumBuffers` `Node` implementations. These are
 //!   useful for mixing together multiple inputs, and for simple mappings between different channel
 //!   layouts.
@@ -9,6 +9,8 @@ pub use delay::Delay;
 pub use graph::GraphNode;
 #[cfg(feature = "node-pass")]
 pub use pass::Pass;
+#[cfg(feature = "node-remix")]
+pub use remix::Remix;
 #[cfg(feature = "node-sum")]
 pub use sum::{Sum, SumBuffers};
 
@@ -20,6 +22,8 @@ mod delay;
 mod graph;
 #[cfg(feature = "node-pass")]
 mod pass;
+#[cfg(feature = "node-remix")]
+mod remix;
 #[cfg(feature = "node-signal")]
 mod signal;
 #[cfg(feature = "node-sum")]
@@ -0,0 +1,39 @@
+use crate::{Buffer, Input, Node};
+use dasp_frame::remix::Matrix;
+
+/// A stateless node that mixes `I` input channels into `O` output channels using a matrix of
+/// mixing coefficients.
+///
+/// The input channels are the buffers of each of the inputs in the order in which the inputs are
+/// passed to `Node::process`, e.g. given two stereo inputs, channels `0` and `1` are the buffers of
+/// the first input while channels `2` and `3` are the buffers of the second. Input channels beyond
+/// `I` are ignored and missing input channels are treated as silence.
+///
+/// Output buffers beyond `O` are filled with silence.
+///
+/// See the `dasp_frame::remix` module for the standard downmix matrices and for deriving matrices
+/// from channel layouts.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Remix<const I: usize, const O: usize>(pub Matrix<I, O>);
+
+impl<const I: usize, const O: usize> Node for Remix<I, O> {
+    fn process(&mut self, inputs: &[Input], output: &mut [Buffer]) {
+        // Fill the output with silence.
+        for out_buffer in output.iter_mut() {
+            out_buffer.silence();
+        }
+        // Sum each scaled input channel onto each output channel.
+        let in_buffers = inputs.iter().flat_map(|input| input.buffers()).take(I);
+        for (i, in_buffer) in in_buffers.enumerate() {
+            for (row, out_buffer) in self.0.coefficients().iter().zip(output.iter_mut()) {
+                let coefficient = row[i];
+                if coefficient == 0.0 {
+                    continue;
+                }
+                for (out, &sample) in out_buffer.iter_mut().zip(in_buffer.iter()) {
+                    *out += sample * coefficient;
+                }
+            }
+        }
+    }
+}
@@ -1,10 +1,11 @@
 use crate::{Buffer, Input, Node};
-use dasp_frame::Frame;
+use dasp_frame::{FixedChannels, Frame};
 use dasp_signal::Signal;
 
 impl<F> Node for dyn Signal<Frame = F>
 where
     F: Frame<Sample = f32>,
+    F::NumChannels: FixedChannels,
 {
     fn process(&mut self, _inputs: &[Input], output: &mut [Buffer]) {
         for ix in 0..Buffer::LEN {
@@ -0,0 +1,78 @@
+#![cfg(all(feature = "node-boxed", feature = "node-remix"))]
+
+use dasp_frame::remix::Matrix;
+use dasp_graph::{node, Buffer, Input, NodeData};
+
+type BoxedNode = dasp_graph::BoxedNode;
+
+// A source node that writes the index of each output buffer plus one to that buffer.
+fn src_node(_inputs: &[Input], output: &mut [Buffer]) {
+    for (i, o) in output.iter_mut().enumerate() {
+        o.iter_mut().for_each(|s| *s = (i + 1) as f32);
+    }
+}
+
+#[test]
+fn test_remix() {
+    // The type of graph to use for this test.
+    type Graph = petgraph::Graph<NodeData<BoxedNode>, (), petgraph::Directed, u32>;
+    type Processor = dasp_graph::Processor<Graph>;
+
+    let mut g = Graph::with_capacity(2, 1);
+    let mut p = Processor::with_capacity(2);
+
+    // A 5.1 source downmixed to stereo.
+    let src_node_ptr = src_node as fn(&[Input], &mut [Buffer]);
+    let src_bufs = vec![Buffer::SILENT; 6];
+    let src = g.add_node(NodeData::new(BoxedNode::new(src_node_ptr), src_bufs));
+    let remix = node::Remix(Matrix::surround51_to_stereo());
+    let remix = g.add_node(NodeData::new2(BoxedNode::new(remix)));
+    g.add_edge(src, remix, ());
+    p.process(&mut g, remix);
+
+    let h = std::f32::consts::FRAC_1_SQRT_2;
+    let l = 1.0 + 3.0 * h + 5.0 * h;
+    let r = 2.0 + 3.0 * h + 6.0 * h;
+    for (buffer, expected) in g[remix].buffers.iter().zip(&[l, r]) {
+        assert!(buffer.iter().all(|s| (s - expected).abs() < 1e-5));
+    }
+}
+
+// A source node that writes `3.0` to each output buffer.
+fn src_node_b(_inputs: &[Input], output: &mut [Buffer]) {
+    for o in output {
+        o.iter_mut().for_each(|s| *s = 3.0);
+    }
+}
+
+#[test]
+fn test_remix_multiple_inputs() {
+    // The type of graph to use for this test.
+    type Graph = petgraph::Graph<NodeData<BoxedNode>, (), petgraph::Directed, u32>;
+    type Processor = dasp_graph::Processor<Graph>;
+
+    let mut g = Graph::with_capacity(3, 2);
+    let mut p = Processor::with_capacity(3);
+
+    // Two mono inputs mixed into three channels, the third of which has no input. The order of
+    // the inputs depends on the graph, so both are mixed equally.
+    let src_a = src_node as fn(&[Input], &mut [Buffer]);
+    let src_b = src_node_b as fn(&[Input], &mut [Buffer]);
+    let src_a = g.add_node(NodeData::new1(BoxedNode::new(src_a)));
+    let src_b = g.add_node(NodeData::new1(BoxedNode::new(src_b)));
+    let matrix = Matrix::new([[0.5, 0.5, 0.0], [1.0, 1.0, 0.0], [0.0, 0.0, 1.0]]);
+    let remix_bufs = vec![Buffer::from([9.0; Buffer::LEN]); 4];
+    let remix = g.add_node(NodeData::new(
+        BoxedNode::new(node::Remix(matrix)),
+        remix_bufs,
+    ));
+    g.add_edge(src_a, remix, ());
+    g.add_edge(src_b, remix, ());
+    p.process(&mut g, remix);
+
+    let buffers = &g[remix].buffers;
+    assert_eq!(buffers[0], Buffer::from([2.0; Buffer::LEN]));
+    assert_eq!(buffers[1], Buffer::from([4.0; Buffer::LEN]));
+    assert_eq!(buffers[2], Buffer::SILENT);
+    assert_eq!(buffers[3], Buffer::SILENT);
+}
@@ -0,0 +1,80 @@
+//! A 4-point Catmull-Rom spline interpolator implementation.
+//!
+//! ### Required Features
+//!
+//! - When using `dasp_interpolate`, this module requires the **catmull-rom** feature to be enabled.
+//! - When using `dasp`, this module requires the **interpolate-catmull-rom** feature to be enabled.
+
+use crate::{weighted_sum, Interpolator};
+use dasp_frame::Frame;
+use dasp_sample::Duplex;
+
+/// Interpolator that follows a Catmull-Rom spline through the two frames either side of the
+/// interpolated position.
+///
+/// The spline passes through every source frame with a continuous first derivative. This is
+/// equivalent to a **Hermite** interpolator with a tension of `0.0`.
+///
+/// ### Required Features
+///
+/// - When using `dasp_interpolate`, this item requires the **catmull-rom** feature to be enabled.
+/// - When using `dasp`, this item requires the **interpolate-catmull-rom** feature to be enabled.
+pub struct CatmullRom<F> {
+    frames: [F; 4],
+}
+
+impl<F> CatmullRom<F> {
+    /// Create a new CatmullRom Interpolator, where `left` and `right` are the first two frames to
+    /// be interpolated, `prev` is the frame preceding `left` and `next` is the frame following
+    /// `right`.
+    ///
+    /// When starting at the beginning of a signal, `prev` is typically `Frame::EQUILIBRIUM`.
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_interpolate`, this item requires the **catmull-rom** feature to be
+    ///   enabled.
+    /// - When using `dasp`, this item requires the **interpolate-catmull-rom** feature to be
+    ///   enabled.
+    pub fn new(prev: F, left: F, right: F, next: F) -> CatmullRom<F> {
+        CatmullRom {
+            frames: [prev, left, right, next],
+        }
+    }
+}
+
+impl<F> Interpolator for CatmullRom<F>
+where
+    F: Frame,
+    F::Sample: Duplex<f64>,
+{
+    type Frame = F;
+
+    fn interpolate(&self, x: f64) -> Self::Frame {
+        let x2 = x * x;
+        let x3 = x2 * x;
+        let weights = [
+            0.5 * (-x3 + 2.0 * x2 - x),
+            0.5 * (3.0 * x3 - 5.0 * x2 + 2.0),
+            0.5 * (-3.0 * x3 + 4.0 * x2 + x),
+            0.5 * (x3 - x2),
+        ];
+        weighted_sum(self.frames.iter().cloned().zip(weights.iter().cloned()))
+    }
+
+    fn next_source_frame(&mut self, source_frame: Self::Frame) {
+        self.frames = [self.frames[1], self.frames[2], self.frames[3], source_frame];
+    }
+
+    fn reset(&mut self) {
+        self.frames = [Self::Frame::EQUILIBRIUM; 4];
+    }
+
+    fn taps(&self) -> usize {
+        4
+    }
+
+    fn latency(&self) -> usize {
+        2
+    }
+}
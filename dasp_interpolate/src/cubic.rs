@@ -0,0 +1,81 @@
+//! A 4-point cubic interpolator implementation.
+//!
+//! ### Required Features
+//!
+//! - When using `dasp_interpolate`, this module requires the **cubic** feature to be enabled.
+//! - When using `dasp`, this module requires the **interpolate-cubic** feature to be enabled.
+
+use crate::{weighted_sum, Interpolator};
+use dasp_frame::Frame;
+use dasp_sample::Duplex;
+
+/// Interpolator that fits a cubic polynomial through the two frames either side of the
+/// interpolated position.
+///
+/// The polynomial passes through all four frames, making this equivalent to a 4-point
+/// **Lagrange** interpolator without the need for a ring buffer.
+///
+/// Smoother than **Linear** at a fraction of the cost of **Sinc**, at the expense of one frame of
+/// additional latency.
+///
+/// ### Required Features
+///
+/// - When using `dasp_interpolate`, this item requires the **cubic** feature to be enabled.
+/// - When using `dasp`, this item requires the **interpolate-cubic** feature to be enabled.
+pub struct Cubic<F> {
+    frames: [F; 4],
+}
+
+impl<F> Cubic<F> {
+    /// Create a new Cubic Interpolator, where `left` and `right` are the first two frames to be
+    /// interpolated, `prev` is the frame preceding `left` and `next` is the frame following
+    /// `right`.
+    ///
+    /// When starting at the beginning of a signal, `prev` is typically `Frame::EQUILIBRIUM`.
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_interpolate`, this item requires the **cubic** feature to be enabled.
+    /// - When using `dasp`, this item requires the **interpolate-cubic** feature to be enabled.
+    pub fn new(prev: F, left: F, right: F, next: F) -> Cubic<F> {
+        Cubic {
+            frames: [prev, left, right, next],
+        }
+    }
+}
+
+impl<F> Interpolator for Cubic<F>
+where
+    F: Frame,
+    F::Sample: Duplex<f64>,
+{
+    type Frame = F;
+
+    fn interpolate(&self, x: f64) -> Self::Frame {
+        // The Lagrange basis polynomials for frames at positions `-1`, `0`, `1` and `2`.
+        let (xp1, xm1, xm2) = (x + 1.0, x - 1.0, x - 2.0);
+        let weights = [
+            -x * xm1 * xm2 / 6.0,
+            xp1 * xm1 * xm2 / 2.0,
+            -xp1 * x * xm2 / 2.0,
+            xp1 * x * xm1 / 6.0,
+        ];
+        weighted_sum(self.frames.iter().cloned().zip(weights.iter().cloned()))
+    }
+
+    fn next_source_frame(&mut self, source_frame: Self::Frame) {
+        self.frames = [self.frames[1], self.frames[2], self.frames[3], source_frame];
+    }
+
+    fn reset(&mut self) {
+        self.frames = [Self::Frame::EQUILIBRIUM; 4];
+    }
+
+    fn taps(&self) -> usize {
+        4
+    }
+
+    fn latency(&self) -> usize {
+        2
+    }
+}
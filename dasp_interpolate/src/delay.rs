@@ -0,0 +1,415 @@
+//! A delay line supporting reads at fractional delays, e.g. for chorus, flanger and Doppler
+//! effects.
+//!
+//! The [**DelayLine**](./struct.DelayLine.html) type stores the most recently written frames
+//! within a `dasp_ring_buffer::Fixed` ring buffer. Any number of taps may read from the line at
+//! once, each at its own (possibly modulated) delay, via a [**Kernel**](./trait.Kernel.html) that
+//! interpolates between the stored frames. Every [**Interpolator**](../trait.Interpolator.html) may
+//! be used as a kernel.
+//!
+//! ### Required Features
+//!
+//! - When using `dasp_interpolate`, this module requires the **delay** feature to be enabled.
+//! - When using `dasp`, this module requires the **interpolate-delay** feature to be enabled.
+
+use crate::{catmull_rom, Interpolator};
+use dasp_frame::Frame;
+use dasp_ring_buffer as ring_buffer;
+use dasp_sample::{Duplex, Sample};
+
+/// A line of the most recently written frames that may be read at fractional delays.
+///
+/// A delay of `0.0` reads the most recently written frame, while a delay of `max_delay()` reads
+/// the oldest frame retained by the line.
+///
+/// # Example
+///
+/// ```
+/// use dasp_interpolate::delay::{CatmullRom, DelayLine, Linear};
+///
+/// fn main() {
+///     let mut line = DelayLine::from([[0.0]; 4]);
+///     for &frame in &[[1.0], [2.0], [3.0], [4.0]] {
+///         line.write(frame);
+///     }
+///     assert_eq!(line.get(0), [4.0]);
+///     assert_eq!(line.get(3), [1.0]);
+///     assert_eq!(line.read(&mut Linear, 0.25), [3.75]);
+///     assert_eq!(line.read(&mut CatmullRom, 1.5), [2.5]);
+/// }
+/// ```
+///
+/// ### Required Features
+///
+/// - When using `dasp_interpolate`, this item requires the **delay** feature to be enabled.
+/// - When using `dasp`, this item requires the **interpolate-delay** feature to be enabled.
+#[derive(Clone, Debug)]
+pub struct DelayLine<S> {
+    buffer: ring_buffer::Fixed<S>,
+}
+
+/// Interpolates between the frames of a **DelayLine** in order to read at a fractional delay.
+///
+/// Stateful kernels (e.g. **Allpass**) expect to be read exactly once per frame written to the
+/// line. Use a separate kernel for each tap.
+///
+/// Every **Interpolator** is also a kernel. On each read, the interpolator is passed the `taps()`
+/// frames surrounding the delay, offset by its `latency()`, replacing any frames it held before.
+/// Frames beyond either end of the line are substituted with the nearest frame within the line.
+///
+/// ```
+/// use dasp_interpolate::catmull_rom::CatmullRom;
+/// use dasp_interpolate::delay::DelayLine;
+///
+/// fn main() {
+///     let mut line = DelayLine::from([0.0; 8]);
+///     for i in 0..8 {
+///         line.write(i as f64 * 0.5);
+///     }
+///     let mut interp = CatmullRom::new(0.0, 0.0, 0.0, 0.0);
+///     assert_eq!(line.read(&mut interp, 2.5), 2.25);
+/// }
+/// ```
+///
+/// ### Required Features
+///
+/// - When using `dasp_interpolate`, this item requires the **delay** feature to be enabled.
+/// - When using `dasp`, this item requires the **interpolate-delay** feature to be enabled.
+pub trait Kernel<F>
+where
+    F: Frame,
+{
+    /// Read the frame at the given delay, where `delay` lies within `0.0..=line.max_delay()`.
+    fn read<S>(&mut self, line: &DelayLine<S>, delay: f64) -> F
+    where
+        S: ring_buffer::Slice<Element = F>;
+
+    /// Resets any state held by the kernel.
+    ///
+    /// Call this when there's a break in the continuity of the frames written to the line.
+    fn reset(&mut self) {}
+}
+
+/// Reads the frame at the nearest integer delay.
+///
+/// ### Required Features
+///
+/// - When using `dasp_interpolate`, this item requires the **delay** feature to be enabled.
+/// - When using `dasp`, this item requires the **interpolate-delay** feature to be enabled.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Nearest;
+
+/// Interpolates linearly between the two frames either side of the delay.
+///
+/// ### Required Features
+///
+/// - When using `dasp_interpolate`, this item requires the **delay** feature to be enabled.
+/// - When using `dasp`, this item requires the **interpolate-delay** feature to be enabled.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Linear;
+
+/// Interpolates with a Catmull-Rom spline through the four frames surrounding the delay.
+///
+/// Reads as the `catmull_rom::CatmullRom` interpolator would, without holding any frames between
+/// reads. Frames beyond either end of the line are substituted with the nearest frame within the
+/// line.
+///
+/// ### Required Features
+///
+/// - When using `dasp_interpolate`, this item requires the **delay** feature to be enabled.
+/// - When using `dasp`, this item requires the **interpolate-delay** feature to be enabled.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CatmullRom;
+
+/// A first-order allpass interpolator.
+///
+/// Unlike **Linear** and **CatmullRom**, allpass interpolation has a flat magnitude response, making
+/// it well suited to delays that are read from feedback paths. It holds the previously read frame
+/// as state, so it must be read exactly once per frame written to the line and is best suited to
+/// slowly modulated delays.
+///
+/// ### Required Features
+///
+/// - When using `dasp_interpolate`, this item requires the **delay** feature to be enabled.
+/// - When using `dasp`, this item requires the **interpolate-delay** feature to be enabled.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Allpass<F> {
+    prev: F,
+}
+
+/// A read position within a **DelayLine**, pairing a **Kernel** with a delay that may be
+/// modulated between reads.
+///
+/// # Example
+///
+/// ```
+/// use dasp_interpolate::delay::{DelayLine, Linear, Tap};
+///
+/// fn main() {
+///     let mut line = DelayLine::from([0.0; 8]);
+///     let mut taps = [Tap::new(Linear, 1.0), Tap::new(Linear, 4.0)];
+///     let mut out = vec![];
+///     for i in 0..6 {
+///         line.write(i as f64);
+///         // Sweep the second tap's delay, as a flanger would.
+///         taps[1].set_delay(4.0 - i as f64 * 0.5);
+///         out.push([taps[0].read(&line), taps[1].read(&line)]);
+///     }
+///     assert_eq!(out[5], [4.0, 3.5]);
+/// }
+/// ```
+///
+/// ### Required Features
+///
+/// - When using `dasp_interpolate`, this item requires the **delay** feature to be enabled.
+/// - When using `dasp`, this item requires the **interpolate-delay** feature to be enabled.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Tap<K> {
+    kernel: K,
+    delay: f64,
+}
+
+impl<S> DelayLine<S>
+where
+    S: ring_buffer::Slice,
+    S::Element: Frame,
+{
+    /// Create a delay line from the given ring buffer.
+    ///
+    /// The front of the ring buffer is treated as the oldest frame in the line.
+    pub fn new(buffer: ring_buffer::Fixed<S>) -> Self {
+        DelayLine { buffer }
+    }
+
+    /// The greatest delay at which the line may be read.
+    ///
+    /// This is one less than the number of frames retained by the line.
+    pub fn max_delay(&self) -> usize {
+        self.buffer.len() - 1
+    }
+
+    /// Borrow the underlying ring buffer, ordered from the oldest frame to the newest.
+    pub fn buffer(&self) -> &ring_buffer::Fixed<S> {
+        &self.buffer
+    }
+
+    /// Consume the delay line and return the underlying ring buffer.
+    pub fn into_buffer(self) -> ring_buffer::Fixed<S> {
+        self.buffer
+    }
+
+    /// Write a new frame to the line, discarding the oldest frame and returning it.
+    pub fn write(&mut self, frame: S::Element) -> S::Element
+    where
+        S: ring_buffer::SliceMut,
+    {
+        self.buffer.push(frame)
+    }
+
+    /// The frame at the given integer delay.
+    ///
+    /// The delay is clamped to `max_delay()`.
+    #[inline]
+    pub fn get(&self, delay: usize) -> S::Element {
+        let max_delay = self.max_delay();
+        let delay = if delay > max_delay { max_delay } else { delay };
+        self.buffer[max_delay - delay]
+    }
+
+    /// Read the frame at the given fractional delay, interpolated using the given kernel.
+    ///
+    /// The delay is clamped to the range `0.0..=max_delay()`.
+    #[inline]
+    pub fn read<K>(&self, kernel: &mut K, delay: f64) -> S::Element
+    where
+        K: Kernel<S::Element>,
+    {
+        let max_delay = self.max_delay() as f64;
+        let delay = if delay > max_delay {
+            max_delay
+        } else if delay > 0.0 {
+            delay
+        } else {
+            0.0
+        };
+        kernel.read(self, delay)
+    }
+}
+
+impl<S> From<S> for DelayLine<S>
+where
+    S: ring_buffer::Slice,
+    S::Element: Frame,
+{
+    /// Create a delay line from the given data, treating the first element as the oldest frame.
+    ///
+    /// **Panic!**s if the given `data` buffer is empty.
+    fn from(data: S) -> Self {
+        DelayLine::new(ring_buffer::Fixed::from(data))
+    }
+}
+
+impl<F> Allpass<F>
+where
+    F: Frame,
+{
+    /// Create a new allpass interpolator with no previous output.
+    pub fn new() -> Self {
+        Allpass {
+            prev: F::EQUILIBRIUM,
+        }
+    }
+}
+
+impl<F> Default for Allpass<F>
+where
+    F: Frame,
+{
+    fn default() -> Self {
+        Allpass::new()
+    }
+}
+
+impl<K> Tap<K> {
+    /// A tap reading at the given delay in frames with the given kernel.
+    pub fn new(kernel: K, delay: f64) -> Self {
+        Tap { kernel, delay }
+    }
+
+    /// The delay in frames at which the tap reads.
+    pub fn delay(&self) -> f64 {
+        self.delay
+    }
+
+    /// Set the delay in frames at which the tap reads.
+    pub fn set_delay(&mut self, delay: f64) {
+        self.delay = delay;
+    }
+
+    /// Borrow the tap's kernel.
+    pub fn kernel(&self) -> &K {
+        &self.kernel
+    }
+
+    /// Read the frame at the tap's delay within the given line.
+    #[inline]
+    pub fn read<S>(&mut self, line: &DelayLine<S>) -> S::Element
+    where
+        S: ring_buffer::Slice,
+        S::Element: Frame,
+        K: Kernel<S::Element>,
+    {
+        line.read(&mut self.kernel, self.delay)
+    }
+}
+
+impl<F> Kernel<F> for Nearest
+where
+    F: Frame,
+{
+    #[inline]
+    fn read<S>(&mut self, line: &DelayLine<S>, delay: f64) -> F
+    where
+        S: ring_buffer::Slice<Element = F>,
+    {
+        line.get((delay + 0.5) as usize)
+    }
+}
+
+impl<F> Kernel<F> for Linear
+where
+    F: Frame,
+    F::Sample: Duplex<f64>,
+{
+    #[inline]
+    fn read<S>(&mut self, line: &DelayLine<S>, delay: f64) -> F
+    where
+        S: ring_buffer::Slice<Element = F>,
+    {
+        let idx = delay as usize;
+        let t = delay - idx as f64;
+        line.get(idx).zip_map(line.get(idx + 1), |a, b| {
+            let a = a.to_sample::<f64>();
+            let b = b.to_sample::<f64>();
+            (a + (b - a) * t).to_sample()
+        })
+    }
+}
+
+impl<F> Kernel<F> for CatmullRom
+where
+    F: Frame,
+    F::Sample: Duplex<f64>,
+{
+    #[inline]
+    fn read<S>(&mut self, line: &DelayLine<S>, delay: f64) -> F
+    where
+        S: ring_buffer::Slice<Element = F>,
+    {
+        let eq = F::EQUILIBRIUM;
+        catmull_rom::CatmullRom::new(eq, eq, eq, eq).read(line, delay)
+    }
+}
+
+impl<F> Kernel<F> for Allpass<F>
+where
+    F: Frame,
+    F::Sample: Duplex<f64>,
+{
+    #[inline]
+    fn read<S>(&mut self, line: &DelayLine<S>, delay: f64) -> F
+    where
+        S: ring_buffer::Slice<Element = F>,
+    {
+        let idx = delay as usize;
+        let t = delay - idx as f64;
+        // The allpass introduces the fractional delay `t` when `coeff = (1 - t) / (1 + t)`.
+        let coeff = (1.0 - t) / (1.0 + t);
+        let a = line.get(idx);
+        let b = line.get(idx + 1);
+        let mut frame = F::EQUILIBRIUM;
+        for (ch, y) in frame.channels_mut().enumerate() {
+            let x0 = a.channel(ch).unwrap().to_sample::<f64>();
+            let x1 = b.channel(ch).unwrap().to_sample::<f64>();
+            let prev = self.prev.channel(ch).unwrap().to_sample::<f64>();
+            *y = (coeff * x0 + x1 - coeff * prev).to_sample();
+        }
+        self.prev = frame;
+        frame
+    }
+
+    fn reset(&mut self) {
+        self.prev = F::EQUILIBRIUM;
+    }
+}
+
+impl<I> Kernel<I::Frame> for I
+where
+    I: Interpolator,
+{
+    #[inline]
+    fn read<S>(&mut self, line: &DelayLine<S>, delay: f64) -> I::Frame
+    where
+        S: ring_buffer::Slice<Element = I::Frame>,
+    {
+        // Interpolate forwards in time from the frame at the delay rounded up.
+        let floor = delay as usize;
+        let ceil = if (floor as f64) < delay {
+            floor + 1
+        } else {
+            floor
+        };
+        // The delay of the most recent frame to pass to the interpolator, which may lie beyond
+        // the newest end of the line.
+        let newest = ceil as isize - self.latency() as isize;
+        for offset in (0..self.taps()).rev() {
+            let delay = newest + offset as isize;
+            self.next_source_frame(line.get(if delay < 0 { 0 } else { delay as usize }));
+        }
+        self.interpolate(ceil as f64 - delay)
+    }
+
+    fn reset(&mut self) {
+        Interpolator::reset(self);
+    }
+}
@@ -49,4 +49,12 @@ where
     fn reset(&mut self) {
         self.left = Self::Frame::EQUILIBRIUM;
     }
+
+    fn taps(&self) -> usize {
+        1
+    }
+
+    fn latency(&self) -> usize {
+        0
+    }
 }
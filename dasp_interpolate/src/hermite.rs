@@ -0,0 +1,88 @@
+//! A 4-point Hermite spline interpolator implementation.
+//!
+//! ### Required Features
+//!
+//! - When using `dasp_interpolate`, this module requires the **hermite** feature to be enabled.
+//! - When using `dasp`, this module requires the **interpolate-hermite** feature to be enabled.
+
+use crate::{weighted_sum, Interpolator};
+use dasp_frame::Frame;
+use dasp_sample::Duplex;
+
+/// Interpolator that follows a cubic Hermite spline through the two frames either side of the
+/// interpolated position, with tangents derived from the surrounding frames.
+///
+/// The `tension` scales the tangents by `1.0 - tension`. A tension of `0.0` yields a Catmull-Rom
+/// spline, while a tension of `1.0` flattens the tangents so that the curve eases in and out of
+/// every source frame.
+///
+/// ### Required Features
+///
+/// - When using `dasp_interpolate`, this item requires the **hermite** feature to be enabled.
+/// - When using `dasp`, this item requires the **interpolate-hermite** feature to be enabled.
+pub struct Hermite<F> {
+    frames: [F; 4],
+    tension: f64,
+}
+
+impl<F> Hermite<F> {
+    /// Create a new Hermite Interpolator with the given `tension`, where `left` and `right` are the
+    /// first two frames to be interpolated, `prev` is the frame preceding `left` and `next` is the
+    /// frame following `right`.
+    ///
+    /// When starting at the beginning of a signal, `prev` is typically `Frame::EQUILIBRIUM`.
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_interpolate`, this item requires the **hermite** feature to be enabled.
+    /// - When using `dasp`, this item requires the **interpolate-hermite** feature to be enabled.
+    pub fn new(prev: F, left: F, right: F, next: F, tension: f64) -> Hermite<F> {
+        Hermite {
+            frames: [prev, left, right, next],
+            tension,
+        }
+    }
+
+    /// The tension of the spline.
+    pub fn tension(&self) -> f64 {
+        self.tension
+    }
+}
+
+impl<F> Interpolator for Hermite<F>
+where
+    F: Frame,
+    F::Sample: Duplex<f64>,
+{
+    type Frame = F;
+
+    fn interpolate(&self, x: f64) -> Self::Frame {
+        let x2 = x * x;
+        let x3 = x2 * x;
+        // The Hermite basis functions.
+        let h00 = 2.0 * x3 - 3.0 * x2 + 1.0;
+        let h10 = x3 - 2.0 * x2 + x;
+        let h01 = -2.0 * x3 + 3.0 * x2;
+        let h11 = x3 - x2;
+        // The tangents at `left` and `right` are `c * (right - prev)` and `c * (next - left)`.
+        let c = 0.5 * (1.0 - self.tension);
+        let weights = [-c * h10, h00 - c * h11, h01 + c * h10, c * h11];
+        weighted_sum(self.frames.iter().cloned().zip(weights.iter().cloned()))
+    }
+
+    fn next_source_frame(&mut self, source_frame: Self::Frame) {
+        self.frames = [self.frames[1], self.frames[2], self.frames[3], source_frame];
+    }
+
+    fn reset(&mut self) {
+        self.frames = [Self::Frame::EQUILIBRIUM; 4];
+    }
+
+    fn taps(&self) -> usize {
+        4
+    }
+
+    fn latency(&self) -> usize {
+        2
+    }
+}
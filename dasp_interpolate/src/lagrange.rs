@@ -0,0 +1,92 @@
+//! An N-point Lagrange polynomial interpolator implementation.
+//!
+//! ### Required Features
+//!
+//! - When using `dasp_interpolate`, this module requires the **lagrange** feature to be enabled.
+//! - When using `dasp`, this module requires the **interpolate-lagrange** feature to be enabled.
+
+use crate::{weighted_sum, Interpolator};
+use dasp_frame::Frame;
+use dasp_ring_buffer as ring_buffer;
+use dasp_sample::Duplex;
+
+/// Interpolator that fits a polynomial of order `N - 1` through the `N` most recent source frames.
+///
+/// The interpolated position lies between the two frames at the centre of the ring buffer, i.e.
+/// between the frames at indices `(N - 1) / 2` and `(N - 1) / 2 + 1`, so the interpolator has a
+/// latency of `N / 2` frames. A 2-point Lagrange interpolator is equivalent to **Linear**.
+///
+/// ### Required Features
+///
+/// - When using `dasp_interpolate`, this item requires the **lagrange** feature to be enabled.
+/// - When using `dasp`, this item requires the **interpolate-lagrange** feature to be enabled.
+pub struct Lagrange<S> {
+    frames: ring_buffer::Fixed<S>,
+}
+
+impl<S> Lagrange<S> {
+    /// Create a new **Lagrange** interpolator with the given ring buffer, whose length is the
+    /// number of points `N`.
+    ///
+    /// The initial contents of the ring_buffer will act as padding for the interpolated signal.
+    ///
+    /// **panic!**s if the given ring buffer's length is less than `2`.
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_interpolate`, this item requires the **lagrange** feature to be enabled.
+    /// - When using `dasp`, this item requires the **interpolate-lagrange** feature to be enabled.
+    pub fn new(frames: ring_buffer::Fixed<S>) -> Self
+    where
+        S: ring_buffer::SliceMut,
+        S::Element: Frame,
+    {
+        assert!(frames.len() >= 2);
+        Lagrange { frames }
+    }
+}
+
+impl<S> Interpolator for Lagrange<S>
+where
+    S: ring_buffer::SliceMut,
+    S::Element: Frame,
+    <S::Element as Frame>::Sample: Duplex<f64>,
+{
+    type Frame = S::Element;
+
+    fn interpolate(&self, x: f64) -> Self::Frame {
+        let n = self.frames.len();
+        let pos = ((n - 1) / 2) as f64 + x;
+        let weight = |k: usize| {
+            (0..n)
+                .filter(|&j| j != k)
+                .fold(1.0, |w, j| w * (pos - j as f64) / (k as f64 - j as f64))
+        };
+        weighted_sum(
+            self.frames
+                .iter()
+                .cloned()
+                .enumerate()
+                .map(|(k, frame)| (frame, weight(k))),
+        )
+    }
+
+    fn next_source_frame(&mut self, source_frame: Self::Frame) {
+        let _old_frame = self.frames.push(source_frame);
+    }
+
+    fn reset(&mut self) {
+        self.frames.set_first(0);
+        for frame in self.frames.iter_mut() {
+            *frame = Self::Frame::EQUILIBRIUM;
+        }
+    }
+
+    fn taps(&self) -> usize {
+        self.frames.len()
+    }
+
+    fn latency(&self) -> usize {
+        self.frames.len() / 2
+    }
+}
@@ -8,10 +8,24 @@
 //!
 //! ### Optional Features
 //!
+//! - The **catmull-rom** feature (or **interpolate-catmull-rom** feature if using `dasp`)
+//!   provides a 4-point Catmull-Rom spline interpolator implementation.
+//! - The **cubic** feature (or **interpolate-cubic** feature if using `dasp`) provides a 4-point
+//!   cubic interpolator implementation.
+//! - The **delay** feature (or **interpolate-delay** feature if using `dasp`) provides a delay
+//!   line that may be read at fractional delays.
 //! - The **floor** feature (or **interpolate-floor** feature if using `dasp`) provides a floor
 //!   interpolator implementation.
+//! - The **hermite** feature (or **interpolate-hermite** feature if using `dasp`) provides a
+//!   4-point Hermite spline interpolator implementation with adjustable tension.
+//! - The **lagrange** feature (or **interpolate-lagrange** feature if using `dasp`) provides an
+//!   N-point Lagrange polynomial interpolator implementation.
 //! - The **linear** feature (or **interpolate-linear** feature if using `dasp`) provides a linear
 //!   interpolator implementation.
+//! - The **polyphase** feature (or **interpolate-polyphase** feature if using `dasp`) provides a
+//!   polyphase sinc interpolator implementation with an anti-aliasing cutoff.
+//! - The **rational** feature (or **interpolate-rational** feature if using `dasp`) provides a
+//!   drift-free resampler for fixed integer ratios between rates, driven by any interpolator.
 //! - The **sinc** feature (or **interpolate-sinc** feature if using `dasp`) provides a sinc
 //!   interpolator implementation.
 //!
@@ -27,10 +41,24 @@
 
 use dasp_frame::Frame;
 
+#[cfg(feature = "catmull-rom")]
+pub mod catmull_rom;
+#[cfg(feature = "cubic")]
+pub mod cubic;
+#[cfg(feature = "delay")]
+pub mod delay;
 #[cfg(feature = "floor")]
 pub mod floor;
+#[cfg(feature = "hermite")]
+pub mod hermite;
+#[cfg(feature = "lagrange")]
+pub mod lagrange;
 #[cfg(feature = "linear")]
 pub mod linear;
+#[cfg(feature = "polyphase")]
+pub mod polyphase;
+#[cfg(feature = "rational")]
+pub mod rational;
 #[cfg(feature = "sinc")]
 pub mod sinc;
 
@@ -53,4 +81,56 @@ pub trait Interpolator {
     ///
     /// Call this when there's a break in the continuity of the input data stream.
     fn reset(&mut self);
+
+    /// The number of source frames that contribute to each interpolated frame.
+    ///
+    /// Passing this many frames to `next_source_frame` replaces every source frame held by the
+    /// interpolator. Defaults to `2`, i.e. the frames either side of the interpolated position.
+    fn taps(&self) -> usize {
+        2
+    }
+
+    /// The number of source frames by which the interpolated signal lags the source signal.
+    ///
+    /// That is, `interpolate(0.0)` yields the source frame that was passed to `next_source_frame`
+    /// this many frames before the most recent one. Defaults to `1`.
+    fn latency(&self) -> usize {
+        1
+    }
+
+    /// Called whenever the playback rate multiplier (the ratio of the source sample rate to the
+    /// target sample rate) of the converter driving the interpolator changes.
+    ///
+    /// Interpolators whose filter depends on the conversion ratio should update it here. Does
+    /// nothing by default.
+    fn set_playback_hz_scale(&mut self, _scale: f64) {}
+}
+
+/// Sum each channel of the given frames scaled by their respective weights.
+///
+/// The iterator is cloned and iterated once per channel.
+#[cfg(any(
+    feature = "catmull-rom",
+    feature = "cubic",
+    feature = "hermite",
+    feature = "lagrange",
+    feature = "polyphase"
+))]
+fn weighted_sum<F, I>(weighted: I) -> F
+where
+    F: Frame,
+    F::Sample: dasp_sample::Duplex<f64>,
+    I: Iterator<Item = (F, f64)> + Clone,
+{
+    use dasp_sample::Sample;
+
+    let mut frame = F::EQUILIBRIUM;
+    for (ch, out) in frame.channels_mut().enumerate() {
+        let sum: f64 = weighted
+            .clone()
+            .map(|(f, w)| f.channel(ch).unwrap().to_sample::<f64>() * w)
+            .sum();
+        *out = sum.to_sample();
+    }
+    frame
 }
@@ -0,0 +1,322 @@
+//! A polyphase sinc interpolator implementation with an anti-aliasing cutoff.
+//!
+//! ### Required Features
+//!
+//! - When using `dasp_interpolate`, this module requires the **polyphase** feature to be enabled.
+//! - When using `dasp`, this module requires the **interpolate-polyphase** feature to be enabled.
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+use crate::{weighted_sum, Interpolator};
+use core::f64::consts::PI;
+use dasp_frame::Frame;
+use dasp_ring_buffer as ring_buffer;
+use dasp_sample::Duplex;
+use ops::f64::{ceil, sin, sqrt};
+
+mod ops;
+
+#[cfg(not(feature = "std"))]
+type Vec<T> = alloc::vec::Vec<T>;
+#[cfg(feature = "std")]
+type Vec<T> = std::vec::Vec<T>;
+
+/// Presets trading the cost of a **Polyphase** interpolator against its quality.
+///
+/// ### Required Features
+///
+/// - When using `dasp_interpolate`, this item requires the **polyphase** feature to be enabled.
+/// - When using `dasp`, this item requires the **interpolate-polyphase** feature to be enabled.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Quality {
+    /// 8 zero crossings, suitable for previews and real-time use on constrained hardware.
+    ///
+    /// Attenuates aliases by roughly 60 dB.
+    Low,
+    /// 16 zero crossings, suitable for most real-time use.
+    ///
+    /// Attenuates aliases by roughly 80 dB.
+    Medium,
+    /// 32 zero crossings, suitable for offline rendering.
+    ///
+    /// Attenuates aliases by roughly 100 dB.
+    High,
+}
+
+/// The design of the filter used by a **Polyphase** interpolator.
+///
+/// ### Required Features
+///
+/// - When using `dasp_interpolate`, this item requires the **polyphase** feature to be enabled.
+/// - When using `dasp`, this item requires the **interpolate-polyphase** feature to be enabled.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Parameters {
+    /// The number of zero crossings of the sinc function either side of its centre.
+    ///
+    /// Higher values steepen the transition from the passband to the stopband at the cost of
+    /// latency and computation.
+    pub zero_crossings: usize,
+    /// The number of fractional positions between two frames at which the filter is precomputed.
+    ///
+    /// The filter is linearly interpolated between adjacent phases.
+    pub phases: usize,
+    /// The `β` parameter of the Kaiser window applied to the sinc function.
+    ///
+    /// Higher values attenuate the stopband further at the cost of a wider transition.
+    pub kaiser_beta: f64,
+    /// The cutoff frequency as a fraction of the lower of the source and target Nyquist
+    /// frequencies, within `0.0..=1.0`.
+    pub rolloff: f64,
+}
+
+/// Interpolator for polyphase sinc interpolation.
+///
+/// Rather than evaluating the sinc function for every interpolated frame as **Sinc** does, the
+/// windowed sinc filter is computed once for a fixed number of phases up front.
+///
+/// When downsampling, the cutoff of the filter is lowered along with the target Nyquist frequency
+/// so that content that cannot be represented at the target rate is removed rather than aliased.
+///
+/// The interpolator has a latency of `latency()` source frames, during which it yields silence.
+///
+/// # Example
+///
+/// ```
+/// use dasp_interpolate::polyphase::{Polyphase, Quality};
+/// use dasp_interpolate::Interpolator;
+///
+/// fn main() {
+///     let mut interp = Polyphase::<[f32; 2]>::new(Quality::Medium, 48_000.0, 22_050.0);
+///     assert!(interp.cutoff() < 22_050.0 / 48_000.0);
+///     for _ in 0..interp.taps() {
+///         interp.next_source_frame([0.5, -0.5]);
+///     }
+///     let [l, r] = interp.interpolate(0.25);
+///     assert!((l - 0.5).abs() < 1e-3 && (r + 0.5).abs() < 1e-3);
+/// }
+/// ```
+///
+/// ### Required Features
+///
+/// - When using `dasp_interpolate`, this item requires the **polyphase** feature to be enabled.
+/// - When using `dasp`, this item requires the **interpolate-polyphase** feature to be enabled.
+#[derive(Clone, Debug)]
+pub struct Polyphase<F> {
+    parameters: Parameters,
+    cutoff: f64,
+    taps: usize,
+    // `phases + 1` rows of `taps` coefficients. Row `p` holds the coefficient of each frame in the
+    // history when interpolating at the position `p / phases`.
+    table: Vec<f64>,
+    frames: ring_buffer::Fixed<Vec<F>>,
+}
+
+impl Quality {
+    /// The filter design for the preset.
+    pub fn parameters(self) -> Parameters {
+        match self {
+            Quality::Low => Parameters {
+                zero_crossings: 8,
+                phases: 64,
+                kaiser_beta: 6.0,
+                rolloff: 0.85,
+            },
+            Quality::Medium => Parameters {
+                zero_crossings: 16,
+                phases: 128,
+                kaiser_beta: 8.0,
+                rolloff: 0.9,
+            },
+            Quality::High => Parameters {
+                zero_crossings: 32,
+                phases: 512,
+                kaiser_beta: 10.0,
+                rolloff: 0.94,
+            },
+        }
+    }
+}
+
+impl From<Quality> for Parameters {
+    fn from(quality: Quality) -> Self {
+        quality.parameters()
+    }
+}
+
+impl<F> Polyphase<F>
+where
+    F: Frame,
+{
+    /// Create a new **Polyphase** interpolator for converting from `source_hz` to `target_hz`.
+    ///
+    /// `parameters` may be either a **Quality** preset or custom **Parameters**.
+    ///
+    /// **panic!**s if `zero_crossings` or `phases` is `0`, or if `rolloff` is not within
+    /// `0.0..=1.0`.
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_interpolate`, this item requires the **polyphase** feature to be enabled.
+    /// - When using `dasp`, this item requires the **interpolate-polyphase** feature to be enabled.
+    pub fn new<P>(parameters: P, source_hz: f64, target_hz: f64) -> Self
+    where
+        P: Into<Parameters>,
+    {
+        let parameters = parameters.into();
+        assert!(parameters.zero_crossings > 0);
+        assert!(parameters.phases > 0);
+        assert!(parameters.rolloff > 0.0 && parameters.rolloff <= 1.0);
+        let mut polyphase = Polyphase {
+            parameters,
+            cutoff: 0.0,
+            taps: 0,
+            table: Vec::new(),
+            frames: ring_buffer::Fixed::from(vec_of(F::EQUILIBRIUM, 1)),
+        };
+        polyphase.set_hz_to_hz(source_hz, target_hz);
+        polyphase
+    }
+
+    /// Update the filter for converting from `source_hz` to `target_hz`.
+    ///
+    /// This is called by `Interpolator::set_playback_hz_scale` whenever the rate of the
+    /// `Converter` driving the interpolator is changed. The filter table and history are
+    /// reallocated only if the number of taps changes, in which case the history is reset.
+    ///
+    /// **Panic!**s if either rate is not finite and positive.
+    pub fn set_hz_to_hz(&mut self, source_hz: f64, target_hz: f64) {
+        assert!(
+            source_hz.is_finite() && source_hz > 0.0,
+            "the source rate must be finite and positive"
+        );
+        assert!(
+            target_hz.is_finite() && target_hz > 0.0,
+            "the target rate must be finite and positive"
+        );
+        let ratio = target_hz / source_hz;
+        let scale = if ratio < 1.0 { ratio } else { 1.0 };
+        self.cutoff = self.parameters.rolloff * scale;
+
+        // Stretch the filter so that it retains its zero crossings at the lower cutoff.
+        let half_len = self.parameters.zero_crossings as f64 / self.cutoff;
+        let taps = 2 * ceil(half_len) as usize;
+        if taps != self.taps {
+            self.taps = taps;
+            self.table = vec_of(0.0, (self.parameters.phases + 1) * taps);
+            self.frames = ring_buffer::Fixed::from(vec_of(F::EQUILIBRIUM, taps));
+        }
+
+        let beta = self.parameters.kaiser_beta;
+        let i0_beta = bessel_i0(beta);
+        let phases = self.parameters.phases;
+        let left = (taps / 2 - 1) as f64;
+        for (p, row) in self.table.chunks_mut(taps).enumerate() {
+            let pos = left + p as f64 / phases as f64;
+            let mut sum = 0.0;
+            for (k, coeff) in row.iter_mut().enumerate() {
+                let t = pos - k as f64;
+                let u = t / half_len;
+                *coeff = if !(-1.0..=1.0).contains(&u) {
+                    0.0
+                } else {
+                    let window = bessel_i0(beta * sqrt(1.0 - u * u)) / i0_beta;
+                    self.cutoff * sinc(self.cutoff * t) * window
+                };
+                sum += *coeff;
+            }
+            // Normalise each phase to unity gain at DC.
+            for coeff in row.iter_mut() {
+                *coeff /= sum;
+            }
+        }
+    }
+
+    /// The design of the filter.
+    pub fn parameters(&self) -> Parameters {
+        self.parameters
+    }
+
+    /// The cutoff frequency of the filter as a fraction of the source Nyquist frequency.
+    pub fn cutoff(&self) -> f64 {
+        self.cutoff
+    }
+}
+
+impl<F> Interpolator for Polyphase<F>
+where
+    F: Frame,
+    F::Sample: Duplex<f64>,
+{
+    type Frame = F;
+
+    fn interpolate(&self, x: f64) -> Self::Frame {
+        let phases = self.parameters.phases;
+        let pos = x * phases as f64;
+        let mut phase = pos as usize;
+        if phase >= phases {
+            phase = phases - 1;
+        }
+        let t = pos - phase as f64;
+        let a = &self.table[phase * self.taps..(phase + 1) * self.taps];
+        let b = &self.table[(phase + 1) * self.taps..(phase + 2) * self.taps];
+        let weights = a.iter().zip(b).map(|(a, b)| a + (b - a) * t);
+        weighted_sum(self.frames.iter().cloned().zip(weights))
+    }
+
+    fn next_source_frame(&mut self, source_frame: Self::Frame) {
+        let _old_frame = self.frames.push(source_frame);
+    }
+
+    fn reset(&mut self) {
+        self.frames.set_first(0);
+        for frame in self.frames.iter_mut() {
+            *frame = Self::Frame::EQUILIBRIUM;
+        }
+    }
+
+    fn taps(&self) -> usize {
+        self.taps
+    }
+
+    fn latency(&self) -> usize {
+        self.taps / 2
+    }
+
+    fn set_playback_hz_scale(&mut self, scale: f64) {
+        self.set_hz_to_hz(scale, 1.0);
+    }
+}
+
+// A `Vec` of `len` copies of `elem`.
+fn vec_of<T>(elem: T, len: usize) -> Vec<T>
+where
+    T: Copy,
+{
+    let mut vec = Vec::with_capacity(len);
+    vec.resize(len, elem);
+    vec
+}
+
+// The normalised sinc function.
+fn sinc(x: f64) -> f64 {
+    if x == 0.0 {
+        1.0
+    } else {
+        sin(PI * x) / (PI * x)
+    }
+}
+
+// The zeroth-order modified Bessel function of the first kind.
+fn bessel_i0(x: f64) -> f64 {
+    let half_x = x / 2.0;
+    let mut sum = 1.0;
+    let mut term = 1.0;
+    let mut k = 1.0;
+    while term > sum * 1e-21 {
+        term *= (half_x / k) * (half_x / k);
+        sum += term;
+        k += 1.0;
+    }
+    sum
+}
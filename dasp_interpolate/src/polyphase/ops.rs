@@ -0,0 +1,30 @@
+#![allow(dead_code)]
+
+pub mod f64 {
+    #[cfg(not(feature = "std"))]
+    pub fn sin(x: f64) -> f64 {
+        unsafe { core::intrinsics::sinf64(x) }
+    }
+    #[cfg(feature = "std")]
+    pub fn sin(x: f64) -> f64 {
+        x.sin()
+    }
+
+    #[cfg(not(feature = "std"))]
+    pub fn sqrt(x: f64) -> f64 {
+        unsafe { core::intrinsics::sqrtf64(x) }
+    }
+    #[cfg(feature = "std")]
+    pub fn sqrt(x: f64) -> f64 {
+        x.sqrt()
+    }
+
+    #[cfg(not(feature = "std"))]
+    pub fn ceil(x: f64) -> f64 {
+        unsafe { core::intrinsics::ceilf64(x) }
+    }
+    #[cfg(feature = "std")]
+    pub fn ceil(x: f64) -> f64 {
+        x.ceil()
+    }
+}
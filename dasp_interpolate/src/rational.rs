@@ -0,0 +1,216 @@
+//! A resampler for conversions between rates with a fixed rational ratio.
+//!
+//! ### Required Features
+//!
+//! - When using `dasp_interpolate`, this module requires the **rational** feature to be enabled.
+//! - When using `dasp`, this module requires the **interpolate-rational** feature to be enabled.
+
+use crate::Interpolator;
+
+/// Resamples by the integer ratio `up / down`, e.g. `160 / 147` for 44.1k to 48k.
+///
+/// The position between source frames is tracked as an integer number of `1 / up` steps, rather
+/// than as an accumulated `f64` as in `dasp_signal`'s `Converter`. As a result, every `down`
+/// source frames yield exactly `up` target frames, no matter how long the conversion runs or how
+/// the input is divided into blocks.
+///
+/// Frames may be pushed through in blocks via `process`, or pulled one at a time via `next_frame`.
+/// Interleaved sample slices may be processed in blocks by first converting them to frame slices,
+/// e.g. via `dasp_slice::to_frame_slice`.
+///
+/// # Example
+///
+/// ```
+/// use dasp_interpolate::linear::Linear;
+/// use dasp_interpolate::rational::Rational;
+///
+/// fn main() {
+///     let mut rational = Rational::new(Linear::new(0.0, 0.0), 48_000, 96_000);
+///     assert_eq!((rational.up(), rational.down()), (2, 1));
+///     let mut output = [0.0; 8];
+///     assert_eq!(rational.output_len(3), 8);
+///     assert_eq!(rational.process(&[1.0, 2.0, 3.0], &mut output), (3, 8));
+///     assert_eq!(output, [0.0, 0.0, 0.0, 0.5, 1.0, 1.5, 2.0, 2.5]);
+/// }
+/// ```
+///
+/// ### Required Features
+///
+/// - When using `dasp_interpolate`, this item requires the **rational** feature to be enabled.
+/// - When using `dasp`, this item requires the **interpolate-rational** feature to be enabled.
+#[derive(Clone, Debug)]
+pub struct Rational<I> {
+    interpolator: I,
+    up: u64,
+    down: u64,
+    // The position of the next target frame after the interpolator's current source frame, in
+    // steps of `1 / up` source frames.
+    phase: u64,
+}
+
+impl<I> Rational<I> {
+    /// Create a resampler converting from `source_hz` to `target_hz` with the given interpolator.
+    ///
+    /// The ratio between the rates is reduced to its lowest terms.
+    ///
+    /// **panic!**s if either rate is `0`.
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_interpolate`, this item requires the **rational** feature to be enabled.
+    /// - When using `dasp`, this item requires the **interpolate-rational** feature to be enabled.
+    pub fn new(interpolator: I, source_hz: u32, target_hz: u32) -> Self {
+        Self::from_ratio(interpolator, target_hz, source_hz)
+    }
+
+    /// Create a resampler that yields `up` target frames for every `down` source frames.
+    ///
+    /// The ratio is reduced to its lowest terms.
+    ///
+    /// **panic!**s if either `up` or `down` is `0`.
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_interpolate`, this item requires the **rational** feature to be enabled.
+    /// - When using `dasp`, this item requires the **interpolate-rational** feature to be enabled.
+    pub fn from_ratio(interpolator: I, up: u32, down: u32) -> Self {
+        assert!(up > 0 && down > 0);
+        let divisor = gcd(up as u64, down as u64);
+        Rational {
+            interpolator,
+            up: up as u64 / divisor,
+            down: down as u64 / divisor,
+            phase: 0,
+        }
+    }
+
+    /// The number of target frames produced for every `down()` source frames.
+    pub fn up(&self) -> u64 {
+        self.up
+    }
+
+    /// The number of source frames consumed for every `up()` target frames.
+    pub fn down(&self) -> u64 {
+        self.down
+    }
+
+    /// The position of the next target frame relative to the latest source frame passed to the
+    /// interpolator, in steps of `1 / up()` source frames.
+    ///
+    /// The next target frame requires further source frames while this is at least `up()`.
+    pub fn phase(&self) -> u64 {
+        self.phase
+    }
+
+    /// Borrow the interpolator.
+    pub fn interpolator(&self) -> &I {
+        &self.interpolator
+    }
+
+    /// Mutably borrow the interpolator.
+    pub fn interpolator_mut(&mut self) -> &mut I {
+        &mut self.interpolator
+    }
+
+    /// Consume the resampler and return its interpolator.
+    pub fn into_interpolator(self) -> I {
+        self.interpolator
+    }
+
+    /// The number of target frames that `process` will produce from `input_len` source frames,
+    /// given enough room in the output slice.
+    pub fn output_len(&self, input_len: usize) -> usize {
+        // Target frame `j` requires `(phase + j * down) / up` more source frames.
+        let limit = (input_len as u128 + 1) * self.up as u128;
+        let phase = self.phase as u128;
+        if limit <= phase {
+            return 0;
+        }
+        let down = self.down as u128;
+        ((limit - phase - 1) / down + 1) as usize
+    }
+
+    /// The number of source frames that `process` requires in order to produce `output_len`
+    /// target frames.
+    pub fn input_len(&self, output_len: usize) -> usize {
+        if output_len == 0 {
+            return 0;
+        }
+        let last = self.phase as u128 + (output_len as u128 - 1) * self.down as u128;
+        (last / self.up as u128) as usize
+    }
+
+    /// Reset the position between source frames along with the state of the interpolator.
+    ///
+    /// Call this when there's a break in the continuity of the input data stream.
+    pub fn reset(&mut self)
+    where
+        I: Interpolator,
+    {
+        self.phase = 0;
+        self.interpolator.reset();
+    }
+
+    /// Produce the next target frame, pulling as many source frames from `next_source` as
+    /// required.
+    #[inline]
+    pub fn next_frame<N>(&mut self, mut next_source: N) -> I::Frame
+    where
+        I: Interpolator,
+        N: FnMut() -> I::Frame,
+    {
+        while self.phase >= self.up {
+            self.interpolator.next_source_frame(next_source());
+            self.phase -= self.up;
+        }
+        self.interpolate_next()
+    }
+
+    /// Resample the frames of `input` into `output`.
+    ///
+    /// Stops once either `output` is full or another target frame would require more source
+    /// frames than remain in `input`. Source frames are only consumed when they are required, so
+    /// the remainder of `input` should be passed to the next call.
+    ///
+    /// Returns the number of source frames consumed and the number of target frames produced.
+    pub fn process(&mut self, input: &[I::Frame], output: &mut [I::Frame]) -> (usize, usize)
+    where
+        I: Interpolator,
+    {
+        let mut consumed = 0;
+        for (produced, out) in output.iter_mut().enumerate() {
+            while self.phase >= self.up {
+                match input.get(consumed) {
+                    Some(&frame) => self.interpolator.next_source_frame(frame),
+                    None => return (consumed, produced),
+                }
+                consumed += 1;
+                self.phase -= self.up;
+            }
+            *out = self.interpolate_next();
+        }
+        (consumed, output.len())
+    }
+
+    #[inline]
+    fn interpolate_next(&mut self) -> I::Frame
+    where
+        I: Interpolator,
+    {
+        let frame = self
+            .interpolator
+            .interpolate(self.phase as f64 / self.up as f64);
+        self.phase += self.down;
+        frame
+    }
+}
+
+// The greatest common divisor of `a` and `b`.
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+    a
+}
@@ -129,4 +129,12 @@ where
             *frame = Self::Frame::EQUILIBRIUM;
         }
     }
+
+    fn taps(&self) -> usize {
+        self.frames.len()
+    }
+
+    fn latency(&self) -> usize {
+        self.depth() - 1
+    }
 }
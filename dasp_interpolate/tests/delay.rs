@@ -0,0 +1,109 @@
+#![cfg(feature = "delay")]
+
+use dasp_interpolate::delay::{Allpass, CatmullRom, DelayLine, Kernel, Linear, Nearest, Tap};
+
+fn ramp_line() -> DelayLine<[[f32; 2]; 8]> {
+    let mut line = DelayLine::from([[0.0; 2]; 8]);
+    for i in 0..8 {
+        line.write([i as f32, -(i as f32)]);
+    }
+    line
+}
+
+#[test]
+fn test_integer_delays() {
+    let line = ramp_line();
+    assert_eq!(line.max_delay(), 7);
+    assert_eq!(line.get(0), [7.0, -7.0]);
+    assert_eq!(line.get(7), [0.0, 0.0]);
+    assert_eq!(line.get(100), [0.0, 0.0]);
+    for &delay in &[0.0, 3.0, 7.0] {
+        let expected = line.get(delay as usize);
+        assert_eq!(line.read(&mut Nearest, delay), expected);
+        assert_eq!(line.read(&mut Linear, delay), expected);
+        assert_eq!(line.read(&mut CatmullRom, delay), expected);
+    }
+}
+
+#[test]
+fn test_fractional_delays() {
+    let line = ramp_line();
+    assert_eq!(line.read(&mut Nearest, 2.4), [5.0, -5.0]);
+    assert_eq!(line.read(&mut Nearest, 2.6), [4.0, -4.0]);
+    assert_eq!(line.read(&mut Linear, 2.5), [4.5, -4.5]);
+    // A cubic spline through a straight line is the same straight line.
+    assert_eq!(line.read(&mut CatmullRom, 1.25), [5.75, -5.75]);
+    assert_eq!(line.read(&mut CatmullRom, 5.5), [1.5, -1.5]);
+}
+
+#[test]
+fn test_delays_are_clamped() {
+    let line = ramp_line();
+    assert_eq!(line.read(&mut Linear, -1.0), [7.0, -7.0]);
+    assert_eq!(line.read(&mut Linear, 20.0), [0.0, 0.0]);
+    assert_eq!(line.read(&mut CatmullRom, 7.5), [0.0, 0.0]);
+}
+
+#[test]
+fn test_allpass() {
+    // At an integer delay the allpass passes frames through unchanged.
+    let mut line = DelayLine::from([0.0; 4]);
+    let mut tap = Tap::new(Allpass::new(), 2.0);
+    for i in 1..16 {
+        line.write(i as f64);
+        assert_eq!(tap.read(&line), line.get(2));
+    }
+
+    // At a fractional delay a constant signal settles to the same constant.
+    let mut line = DelayLine::from([0.0; 4]);
+    let mut allpass = Allpass::new();
+    let mut out = 0.0;
+    for _ in 0..64 {
+        line.write(0.5);
+        out = line.read(&mut allpass, 1.3);
+    }
+    assert!((out - 0.5f64).abs() < 1e-9);
+
+    allpass.reset();
+    assert_eq!(allpass, Allpass::new());
+}
+
+#[test]
+fn test_interpolators_as_kernels() {
+    let line = ramp_line();
+    let eq = [0.0; 2];
+    let mut catmull_rom = dasp_interpolate::catmull_rom::CatmullRom::new(eq, eq, eq, eq);
+    assert_eq!(line.read(&mut catmull_rom, 1.25), [5.75, -5.75]);
+    assert_eq!(line.read(&mut catmull_rom, 7.5), [0.0, 0.0]);
+
+    #[cfg(feature = "floor")]
+    {
+        let mut floor = dasp_interpolate::floor::Floor::new(eq);
+        assert_eq!(line.read(&mut floor, 2.5), [4.0, -4.0]);
+        assert_eq!(line.read(&mut floor, 3.0), [4.0, -4.0]);
+    }
+
+    #[cfg(feature = "linear")]
+    {
+        let mut linear = dasp_interpolate::linear::Linear::new(eq, eq);
+        assert_eq!(line.read(&mut linear, 2.5), [4.5, -4.5]);
+        assert_eq!(line.read(&mut linear, 0.25), [6.75, -6.75]);
+    }
+
+    #[cfg(feature = "lagrange")]
+    {
+        use dasp_ring_buffer as ring_buffer;
+        let mut lagrange =
+            dasp_interpolate::lagrange::Lagrange::new(ring_buffer::Fixed::from([eq; 4]));
+        let [l, r] = line.read(&mut lagrange, 3.5);
+        assert!((l - 3.5).abs() < 1e-5 && (r + 3.5).abs() < 1e-5);
+    }
+
+    #[cfg(feature = "sinc")]
+    {
+        use dasp_ring_buffer as ring_buffer;
+        let mut sinc = dasp_interpolate::sinc::Sinc::new(ring_buffer::Fixed::from([eq; 4]));
+        let [l, r] = line.read(&mut sinc, 3.0);
+        assert!((l - 4.0).abs() < 1e-5 && (r + 4.0).abs() < 1e-5);
+    }
+}
@@ -0,0 +1,102 @@
+#![cfg(all(
+    feature = "catmull-rom",
+    feature = "cubic",
+    feature = "hermite",
+    feature = "lagrange"
+))]
+
+use dasp_interpolate::catmull_rom::CatmullRom;
+use dasp_interpolate::cubic::Cubic;
+use dasp_interpolate::hermite::Hermite;
+use dasp_interpolate::lagrange::Lagrange;
+use dasp_interpolate::Interpolator;
+use dasp_ring_buffer as ring_buffer;
+
+const XS: [f64; 5] = [0.0, 0.125, 0.25, 0.5, 0.75];
+
+// Interpolators with four points of history, initialised with the same frames.
+fn interpolators(frames: [[f64; 2]; 4]) -> Vec<Box<dyn Interpolator<Frame = [f64; 2]>>> {
+    let [a, b, c, d] = frames;
+    vec![
+        Box::new(Cubic::new(a, b, c, d)),
+        Box::new(CatmullRom::new(a, b, c, d)),
+        Box::new(Hermite::new(a, b, c, d, 0.5)),
+        Box::new(Hermite::new(a, b, c, d, -0.5)),
+        Box::new(Lagrange::new(ring_buffer::Fixed::from(frames))),
+    ]
+}
+
+#[test]
+fn test_dc_preservation() {
+    let dc = [0.5, -0.25];
+    for mut interp in interpolators([dc; 4]) {
+        for _ in 0..4 {
+            for &x in XS.iter() {
+                assert_eq!(interp.interpolate(x), dc);
+            }
+            interp.next_source_frame(dc);
+        }
+    }
+}
+
+#[test]
+fn test_passes_through_source_frames() {
+    let frames = [[0.0, 1.0], [0.5, -1.0], [-0.25, 0.75], [1.0, 0.0]];
+    for mut interp in interpolators(frames) {
+        assert_eq!(interp.interpolate(0.0), frames[1]);
+        interp.next_source_frame([0.25, 0.25]);
+        assert_eq!(interp.interpolate(0.0), frames[2]);
+    }
+}
+
+#[test]
+fn test_continuity() {
+    let source = [0.0, 0.5, -0.25, 1.0, 0.75, -1.0, 0.0, 0.25, 0.5];
+    let frames = [
+        [source[0]; 2],
+        [source[1]; 2],
+        [source[2]; 2],
+        [source[3]; 2],
+    ];
+    for mut interp in interpolators(frames) {
+        for &s in &source[4..] {
+            let end = interp.interpolate(1.0);
+            interp.next_source_frame([s; 2]);
+            let start = interp.interpolate(0.0);
+            for (e, s) in end.iter().zip(start.iter()) {
+                assert!((e - s).abs() < 1e-12);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_reproduces_lines() {
+    // Every cubic interpolator reproduces a straight line exactly.
+    let frames = [[0.0, 3.0], [1.0, 2.0], [2.0, 1.0], [3.0, 0.0]];
+    let mut interps = interpolators(frames);
+    // Excluding a Hermite with non-zero tension, whose tangents are scaled.
+    interps.remove(3);
+    interps.remove(2);
+    for interp in interps {
+        for &x in XS.iter() {
+            assert_eq!(interp.interpolate(x), [1.0 + x, 2.0 - x]);
+        }
+    }
+}
+
+#[test]
+fn test_lagrange_polynomial() {
+    // A 5-point Lagrange interpolator reproduces a quartic exactly.
+    let f = |t: f64| t * t * t * t - 2.0 * t * t + t;
+    let frames: Vec<f64> = (0..5).map(|t| f(t as f64)).collect();
+    let mut interp = Lagrange::new(ring_buffer::Fixed::from(frames));
+    for &x in XS.iter() {
+        assert!((interp.interpolate(x) - f(2.0 + x)).abs() < 1e-9);
+    }
+    interp.next_source_frame(f(5.0));
+    assert!((interp.interpolate(0.5) - f(3.5)).abs() < 1e-9);
+
+    interp.reset();
+    assert_eq!(interp.interpolate(0.5), 0.0);
+}
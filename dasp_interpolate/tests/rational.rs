@@ -0,0 +1,114 @@
+#![cfg(all(feature = "linear", feature = "rational"))]
+
+use dasp_interpolate::linear::Linear;
+use dasp_interpolate::rational::Rational;
+
+fn sine(len: usize) -> Vec<[f32; 2]> {
+    (0..len)
+        .map(|i| {
+            let s = (i as f32 * 0.05).sin();
+            [s, -s]
+        })
+        .collect()
+}
+
+fn linear(source_hz: u32, target_hz: u32) -> Rational<Linear<[f32; 2]>> {
+    Rational::new(Linear::new([0.0; 2], [0.0; 2]), source_hz, target_hz)
+}
+
+#[test]
+fn test_reduced_ratio() {
+    let rational = linear(44_100, 48_000);
+    assert_eq!((rational.up(), rational.down()), (160, 147));
+    let rational = linear(96_000, 48_000);
+    assert_eq!((rational.up(), rational.down()), (1, 2));
+    let rational = Rational::from_ratio(Linear::new(0.0, 0.0), 6, 4);
+    assert_eq!((rational.up(), rational.down()), (3, 2));
+}
+
+#[test]
+#[should_panic]
+fn test_zero_hz() {
+    let _ = linear(0, 48_000);
+}
+
+#[test]
+fn test_chunked_matches_whole() {
+    let input = sine(4_410);
+    let mut whole = linear(44_100, 48_000);
+    let mut expected = vec![[0.0; 2]; whole.output_len(input.len())];
+    assert_eq!(
+        whole.process(&input, &mut expected),
+        (input.len(), expected.len())
+    );
+
+    // Split the input and output into uneven blocks that don't line up with each other.
+    let mut chunked = linear(44_100, 48_000);
+    let mut output = Vec::new();
+    let mut remaining = &input[..];
+    let mut block = [[0.0; 2]; 61];
+    let mut chunk_len = 1;
+    while !remaining.is_empty() {
+        let len = chunk_len.min(remaining.len());
+        let mut chunk = &remaining[..len];
+        loop {
+            let (consumed, produced) = chunked.process(chunk, &mut block);
+            output.extend_from_slice(&block[..produced]);
+            chunk = &chunk[consumed..];
+            if produced < block.len() {
+                break;
+            }
+        }
+        assert!(chunk.is_empty());
+        remaining = &remaining[len..];
+        chunk_len = chunk_len % 97 + 13;
+    }
+    assert_eq!(output, expected);
+}
+
+#[test]
+fn test_drift_free_output_len() {
+    let rates = [(44_100, 48_000), (48_000, 44_100), (48_000, 96_000)];
+    for &(source_hz, target_hz) in rates.iter() {
+        let mut rational = linear(source_hz, target_hz);
+        let input = sine(source_hz as usize);
+        let mut output = vec![[0.0; 2]; 2 * target_hz as usize];
+        let first = rational.output_len(input.len());
+        assert_eq!(rational.process(&input, &mut output), (input.len(), first));
+        let phase = rational.phase();
+        // Every further second of source frames yields exactly one second of target frames.
+        for _ in 0..60 {
+            assert_eq!(rational.output_len(input.len()), target_hz as usize);
+            let (consumed, produced) = rational.process(&input, &mut output);
+            assert_eq!((consumed, produced), (input.len(), target_hz as usize));
+            assert_eq!(rational.phase(), phase);
+        }
+    }
+}
+
+#[test]
+fn test_input_len() {
+    let input = sine(64);
+    let mut output = [[0.0; 2]; 64];
+    let mut rational = linear(48_000, 44_100);
+    for output_len in 0..32 {
+        let input_len = rational.input_len(output_len);
+        assert!(rational.output_len(input_len) >= output_len);
+        let produced = rational.process(&input[..input_len], &mut output[..output_len]);
+        assert_eq!(produced, (input_len, output_len));
+    }
+}
+
+#[test]
+fn test_reset() {
+    let input = sine(100);
+    let mut output = [[0.0; 2]; 200];
+    let mut rational = linear(48_000, 96_000);
+    let (consumed, produced) = rational.process(&input, &mut output);
+    let expected = output;
+    rational.process(&input[..33], &mut output);
+    rational.reset();
+    assert_eq!(rational.phase(), 0);
+    assert_eq!(rational.process(&input, &mut output), (consumed, produced));
+    assert_eq!(&output[..produced], &expected[..produced]);
+}
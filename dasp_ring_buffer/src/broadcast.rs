@@ -0,0 +1,585 @@
+//! Ring buffers with a single writer and any number of independent readers.
+//!
+//! Every reader observes every element written to the buffer, tracking its own position via a
+//! [**Cursor**](./struct.Cursor.html). The writer never waits for readers. A reader that falls more
+//! than `max_len` elements behind the writer has been *overrun*: its next read returns an
+//! [**Overrun**](./struct.Overrun.html) describing the number of elements it missed and moves it
+//! on to the oldest element still retained.
+//!
+//! - [**Broadcast**](./struct.Broadcast.html) holds the buffer and is read via separately stored
+//!   cursors, for use within a single thread.
+//! - The [**channel**](./fn.channel.html) function splits a buffer into a
+//!   [**Writer**](./struct.Writer.html) and a [**Reader**](./struct.Reader.html) which may be
+//!   cloned and sent to other threads. Neither handle ever blocks, locks or allocates. The
+//!   elements of a channel must implement [**Element**](./trait.Element.html).
+
+use core::cell::UnsafeCell;
+use core::fmt;
+use core::mem::{self, MaybeUninit};
+use core::sync::atomic::{self, AtomicU8, AtomicUsize, Ordering};
+
+use crate::{Slice, SliceMut};
+
+#[cfg(not(feature = "std"))]
+type Arc<T> = alloc::sync::Arc<T>;
+#[cfg(feature = "std")]
+type Arc<T> = std::sync::Arc<T>;
+
+/// A ring buffer with a single writer whose elements may be read by any number of cursors.
+///
+/// ```
+/// use dasp_ring_buffer::broadcast::{Broadcast, Overrun};
+///
+/// fn main() {
+///     let mut rb = Broadcast::from([0; 4]);
+///     let mut a = rb.cursor();
+///     let mut b = rb.cursor();
+///     rb.extend_from_slice(&[1, 2, 3]);
+///     assert_eq!(rb.read(&mut a), Ok(Some(1)));
+///     assert_eq!(rb.read(&mut a), Ok(Some(2)));
+///     assert_eq!(rb.read(&mut b), Ok(Some(1)));
+///
+///     // `b` falls more than 4 elements behind and misses the oldest.
+///     rb.extend_from_slice(&[4, 5, 6]);
+///     assert_eq!(rb.read(&mut a), Ok(Some(3)));
+///     assert_eq!(rb.read(&mut b), Err(Overrun { skipped: 1 }));
+///     assert_eq!(rb.read(&mut b), Ok(Some(3)));
+/// }
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Broadcast<S> {
+    data: S,
+    // The index of the slot to which the next element will be written.
+    index: usize,
+    // The number of slots that contain written elements.
+    filled: usize,
+    // The total number of elements written, wrapping on overflow.
+    written: usize,
+}
+
+/// The position of a single reader within a broadcast ring buffer.
+///
+/// A cursor should only be used with the buffer from which it was created.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Cursor {
+    // The total number of elements written before the next element to be read, wrapping on
+    // overflow.
+    pos: usize,
+    // The index of the slot of the next element to be read.
+    index: usize,
+}
+
+/// Returned when a reader has fallen so far behind the writer that elements it had not yet read
+/// were overwritten.
+///
+/// The reader's cursor has been moved on to the oldest element still retained by the buffer.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Overrun {
+    /// The number of elements that the reader missed.
+    pub skipped: usize,
+}
+
+/// Types that may be the elements of a thread-safe broadcast ring buffer.
+///
+/// A reader may copy an element while the writer is overwriting it, in which case the copy is
+/// discarded. To keep this free of data races, elements are copied one byte at a time via atomic
+/// operations, and a discarded copy may hold a mix of the old and new element.
+///
+/// Implemented for the primitive integer and floating point types, along with arrays of those.
+///
+/// # Safety
+///
+/// Implementors must contain no padding bytes and must be valid for any bit pattern.
+pub unsafe trait Element: Copy {}
+
+/// The writing half of a thread-safe broadcast ring buffer.
+///
+/// Created via the [**channel**](./fn.channel.html) function.
+pub struct Writer<S>
+where
+    S: SliceMut,
+{
+    shared: Arc<Shared<S>>,
+    ptr: *mut S::Element,
+    index: usize,
+    written: usize,
+}
+
+/// A reading half of a thread-safe broadcast ring buffer.
+///
+/// Cloning a reader produces a new, independent reader at the same position.
+///
+/// Created via the [**channel**](./fn.channel.html) function or the **Writer**'s `reader` method.
+pub struct Reader<S>
+where
+    S: SliceMut,
+{
+    shared: Arc<Shared<S>>,
+    ptr: *mut S::Element,
+    cursor: Cursor,
+}
+
+// The state shared between the writer and all readers.
+//
+// This is a sequence lock: the writer advances `claimed` before overwriting any slots and
+// `written` after it is done. Readers copy elements out of the buffer optimistically and then
+// check `claimed` to discard anything that may have been overwritten during the copy. The slots
+// themselves are only ever accessed via relaxed atomic operations once shared.
+struct Shared<S> {
+    // Owns the storage. Only ever accessed via the pointers held by the handles.
+    _data: UnsafeCell<S>,
+    max_len: usize,
+    // The total number of elements that the writer has begun writing, wrapping on overflow.
+    claimed: AtomicUsize,
+    // The total number of elements that the writer has finished writing, wrapping on overflow.
+    written: AtomicUsize,
+}
+
+// The writer is the only handle that writes to the storage, and readers validate every read.
+unsafe impl<S> Send for Writer<S>
+where
+    S: SliceMut + Send,
+    S::Element: Send + Sync,
+{
+}
+
+unsafe impl<S> Send for Reader<S>
+where
+    S: SliceMut + Send,
+    S::Element: Send + Sync,
+{
+}
+
+/// Split the given data slice into the writer and the first reader of an empty broadcast ring
+/// buffer.
+///
+/// The maximum length of the ring buffer is the length of `data`. The existing elements of
+/// `data` are never read.
+///
+/// **Panic!**s if the given `data` buffer is empty.
+///
+/// ```
+/// use std::thread;
+///
+/// fn main() {
+///     let (mut writer, reader) = dasp_ring_buffer::broadcast::channel([0i32; 64]);
+///     let handles: Vec<_> = (0..2)
+///         .map(|_| {
+///             let mut reader = reader.clone();
+///             thread::spawn(move || {
+///                 let mut sum = 0;
+///                 let mut received = 0;
+///                 while received < 10 {
+///                     if let Ok(Some(i)) = reader.read() {
+///                         sum += i;
+///                         received += 1;
+///                     }
+///                 }
+///                 sum
+///             })
+///         })
+///         .collect();
+///     writer.extend_from_slice(&[0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+///     for handle in handles {
+///         assert_eq!(handle.join().unwrap(), 45);
+///     }
+/// }
+/// ```
+pub fn channel<S>(data: S) -> (Writer<S>, Reader<S>)
+where
+    S: SliceMut,
+    S::Element: Element,
+{
+    let max_len = data.slice().len();
+    assert!(max_len > 0);
+    let shared = Arc::new(Shared {
+        _data: UnsafeCell::new(data),
+        max_len,
+        claimed: AtomicUsize::new(0),
+        written: AtomicUsize::new(0),
+    });
+    // The data now has a fixed address within the `Arc`, and no handle exists yet.
+    let ptr = unsafe { (*shared._data.get()).slice_mut().as_mut_ptr() };
+    let writer = Writer {
+        shared,
+        ptr,
+        index: 0,
+        written: 0,
+    };
+    let reader = writer.reader();
+    (writer, reader)
+}
+
+impl<S> Broadcast<S>
+where
+    S: Slice,
+    S::Element: Copy,
+{
+    /// The maximum number of elements retained for readers.
+    #[inline]
+    pub fn max_len(&self) -> usize {
+        self.data.slice().len()
+    }
+
+    /// The total number of elements written to the buffer, wrapping on overflow.
+    #[inline]
+    pub fn written(&self) -> usize {
+        self.written
+    }
+
+    /// A cursor that will read the next element written to the buffer.
+    #[inline]
+    pub fn cursor(&self) -> Cursor {
+        Cursor {
+            pos: self.written,
+            index: self.index,
+        }
+    }
+
+    /// A cursor that will read the oldest element retained by the buffer.
+    #[inline]
+    pub fn oldest_cursor(&self) -> Cursor {
+        let index = if self.filled == self.max_len() {
+            self.index
+        } else {
+            0
+        };
+        Cursor {
+            pos: self.written.wrapping_sub(self.filled),
+            index,
+        }
+    }
+
+    /// The number of elements that may be read by the given cursor.
+    ///
+    /// Returns an `Overrun` without modifying the cursor if it has been overrun.
+    #[inline]
+    pub fn available(&self, cursor: &Cursor) -> Result<usize, Overrun> {
+        available(cursor, self.written, self.max_len())
+    }
+
+    /// Read the next element at the given cursor, advancing the cursor.
+    ///
+    /// Returns `None` if the cursor has already read every element written to the buffer.
+    ///
+    /// If the cursor has been overrun, it is moved to the oldest retained element and the number
+    /// of elements it missed is returned as an `Overrun`.
+    pub fn read(&self, cursor: &mut Cursor) -> Result<Option<S::Element>, Overrun> {
+        let max_len = self.max_len();
+        if catch_up(cursor, self.written, max_len)? == 0 {
+            return Ok(None);
+        }
+        let elem = self.data.slice()[cursor.index];
+        cursor.advance(1, max_len);
+        Ok(Some(elem))
+    }
+
+    /// Read as many elements as are available at the given cursor into the front of the given
+    /// slice, advancing the cursor.
+    ///
+    /// Returns the number of elements read.
+    ///
+    /// If the cursor has been overrun, no elements are read, the cursor is moved to the oldest
+    /// retained element and the number of elements it missed is returned as an `Overrun`.
+    pub fn read_into_slice(
+        &self,
+        cursor: &mut Cursor,
+        dst: &mut [S::Element],
+    ) -> Result<usize, Overrun> {
+        let max_len = self.max_len();
+        let n = core::cmp::min(dst.len(), catch_up(cursor, self.written, max_len)?);
+        crate::read_wrapped(self.data.slice(), cursor.index, &mut dst[..n]);
+        cursor.advance(n, max_len);
+        Ok(n)
+    }
+
+    /// Write the given element to the buffer, overwriting the oldest element once the buffer is
+    /// full.
+    pub fn push(&mut self, elem: S::Element)
+    where
+        S: SliceMut,
+    {
+        self.extend_from_slice(&[elem]);
+    }
+
+    /// Write every element of the given slice to the buffer, overwriting the oldest elements once
+    /// the buffer is full.
+    ///
+    /// If `elems` is longer than `max_len`, only the last `max_len` elements are retained.
+    pub fn extend_from_slice(&mut self, elems: &[S::Element])
+    where
+        S: SliceMut,
+    {
+        let max_len = self.max_len();
+        let (index, retained) = retained(self.index, elems, max_len);
+        crate::write_wrapped(self.data.slice_mut(), index, retained);
+        self.index = (self.index + elems.len() % max_len) % max_len;
+        self.filled = core::cmp::min(self.filled + retained.len(), max_len);
+        self.written = self.written.wrapping_add(elems.len());
+    }
+}
+
+impl<S> From<S> for Broadcast<S>
+where
+    S: Slice,
+    S::Element: Copy,
+{
+    /// Construct an empty `Broadcast` ring buffer from the given data slice.
+    ///
+    /// **Panic!**s if the given `data` buffer is empty.
+    #[inline]
+    fn from(data: S) -> Self {
+        assert!(!data.slice().is_empty());
+        Broadcast {
+            data,
+            index: 0,
+            filled: 0,
+            written: 0,
+        }
+    }
+}
+
+impl<S> Writer<S>
+where
+    S: SliceMut,
+    S::Element: Element,
+{
+    /// The maximum number of elements retained for readers.
+    #[inline]
+    pub fn max_len(&self) -> usize {
+        self.shared.max_len
+    }
+
+    /// The total number of elements written to the buffer, wrapping on overflow.
+    #[inline]
+    pub fn written(&self) -> usize {
+        self.written
+    }
+
+    /// A new reader that will read the next element written to the buffer.
+    pub fn reader(&self) -> Reader<S> {
+        Reader {
+            shared: self.shared.clone(),
+            ptr: self.ptr,
+            cursor: Cursor {
+                pos: self.written,
+                index: self.index,
+            },
+        }
+    }
+
+    /// Write the given element to the buffer, overwriting the oldest element once the buffer is
+    /// full.
+    pub fn push(&mut self, elem: S::Element) {
+        self.extend_from_slice(&[elem]);
+    }
+
+    /// Write every element of the given slice to the buffer, overwriting the oldest elements once
+    /// the buffer is full.
+    ///
+    /// If `elems` is longer than `max_len`, only the last `max_len` elements are retained.
+    pub fn extend_from_slice(&mut self, elems: &[S::Element]) {
+        let max_len = self.max_len();
+        let written = self.written.wrapping_add(elems.len());
+        self.shared.claimed.store(written, Ordering::Relaxed);
+        // Ensure readers that observe any of the following writes also observe the claim.
+        atomic::fence(Ordering::Release);
+        let (mut index, retained) = retained(self.index, elems, max_len);
+        for &elem in retained {
+            unsafe {
+                store(self.ptr.add(index), elem);
+            }
+            index += 1;
+            if index == max_len {
+                index = 0;
+            }
+        }
+        self.shared.written.store(written, Ordering::Release);
+        self.index = (self.index + elems.len() % max_len) % max_len;
+        self.written = written;
+    }
+}
+
+impl<S> Reader<S>
+where
+    S: SliceMut,
+    S::Element: Element,
+{
+    /// The maximum number of elements retained for readers.
+    #[inline]
+    pub fn max_len(&self) -> usize {
+        self.shared.max_len
+    }
+
+    /// The number of elements that may currently be read.
+    ///
+    /// Returns an `Overrun` without moving the reader if it has been overrun.
+    #[inline]
+    pub fn available(&self) -> Result<usize, Overrun> {
+        let written = self.shared.written.load(Ordering::Acquire);
+        available(&self.cursor, written, self.max_len())
+    }
+
+    /// Read the next element.
+    ///
+    /// Returns `None` if the reader has already read every element written to the buffer.
+    ///
+    /// If the reader has been overrun, it is moved to the oldest retained element and the number
+    /// of elements it missed is returned as an `Overrun`.
+    pub fn read(&mut self) -> Result<Option<S::Element>, Overrun> {
+        let mut elem = None;
+        self.read_with(1, |ptr, index, _| {
+            elem = Some(unsafe { load(ptr.add(index)) });
+        })?;
+        Ok(elem)
+    }
+
+    /// Read as many elements as are available into the front of the given slice.
+    ///
+    /// Returns the number of elements read.
+    ///
+    /// If the reader has been overrun, the reader is moved to the oldest retained element and the
+    /// number of elements it missed is returned as an `Overrun`. As the writer may have overwritten
+    /// elements while they were being copied, the contents of `dst` are then unspecified.
+    pub fn read_into_slice(&mut self, dst: &mut [S::Element]) -> Result<usize, Overrun> {
+        let max_len = self.max_len();
+        self.read_with(dst.len(), |ptr, mut index, n| {
+            for elem in &mut dst[..n] {
+                *elem = unsafe { load(ptr.add(index)) };
+                index += 1;
+                if index == max_len {
+                    index = 0;
+                }
+            }
+        })
+    }
+
+    // Copy up to `max` elements via the given function, then validate that none were overwritten
+    // during the copy before advancing the cursor.
+    fn read_with<C>(&mut self, max: usize, copy: C) -> Result<usize, Overrun>
+    where
+        C: FnOnce(*const S::Element, usize, usize),
+    {
+        let max_len = self.max_len();
+        let written = self.shared.written.load(Ordering::Acquire);
+        let n = core::cmp::min(max, catch_up(&mut self.cursor, written, max_len)?);
+        if n == 0 {
+            return Ok(0);
+        }
+        copy(self.ptr, self.cursor.index, n);
+        // Ensure the claim is loaded after the copy.
+        atomic::fence(Ordering::Acquire);
+        let claimed = self.shared.claimed.load(Ordering::Relaxed);
+        catch_up(&mut self.cursor, claimed, max_len)?;
+        self.cursor.advance(n, max_len);
+        Ok(n)
+    }
+}
+
+impl<S> Clone for Reader<S>
+where
+    S: SliceMut,
+{
+    fn clone(&self) -> Self {
+        Reader {
+            shared: self.shared.clone(),
+            ptr: self.ptr,
+            cursor: self.cursor,
+        }
+    }
+}
+
+impl Cursor {
+    #[inline]
+    fn advance(&mut self, n: usize, max_len: usize) {
+        self.pos = self.pos.wrapping_add(n);
+        self.index = (self.index + n % max_len) % max_len;
+    }
+}
+
+impl fmt::Display for Overrun {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "reader overrun, {} elements skipped", self.skipped)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Overrun {}
+
+// The number of elements available to the given cursor given the number of elements written.
+#[inline]
+fn available(cursor: &Cursor, written: usize, max_len: usize) -> Result<usize, Overrun> {
+    let lag = written.wrapping_sub(cursor.pos);
+    if lag > max_len {
+        Err(Overrun {
+            skipped: lag - max_len,
+        })
+    } else {
+        Ok(lag)
+    }
+}
+
+// As `available`, but moves an overrun cursor on to the oldest retained element.
+#[inline]
+fn catch_up(cursor: &mut Cursor, written: usize, max_len: usize) -> Result<usize, Overrun> {
+    let result = available(cursor, written, max_len);
+    if let Err(overrun) = result {
+        cursor.advance(overrun.skipped, max_len);
+    }
+    result
+}
+
+// The slot index at which to begin writing and the elements of `elems` that will be retained when
+// writing them all from the slot at `index`.
+#[inline]
+fn retained<T>(index: usize, elems: &[T], max_len: usize) -> (usize, &[T]) {
+    if elems.len() > max_len {
+        let skipped = elems.len() - max_len;
+        ((index + skipped % max_len) % max_len, &elems[skipped..])
+    } else {
+        (index, elems)
+    }
+}
+
+macro_rules! impl_element {
+    ($($T:ty)*) => {
+        $(
+            unsafe impl Element for $T {}
+        )*
+    };
+}
+
+impl_element!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize f32 f64);
+
+unsafe impl<T, const N: usize> Element for [T; N] where T: Element {}
+
+// Write the given element to the slot at `dst` one byte at a time.
+#[inline]
+unsafe fn store<T>(dst: *mut T, elem: T)
+where
+    T: Element,
+{
+    let src = &elem as *const T as *const u8;
+    let dst = dst as *const AtomicU8;
+    for i in 0..mem::size_of::<T>() {
+        (*dst.add(i)).store(*src.add(i), Ordering::Relaxed);
+    }
+}
+
+// Read the element in the slot at `src` one byte at a time.
+//
+// The element may be torn by a concurrent write, though it is still valid as `Element`s are valid
+// for any bit pattern.
+#[inline]
+unsafe fn load<T>(src: *const T) -> T
+where
+    T: Element,
+{
+    let mut elem = MaybeUninit::<T>::uninit();
+    let dst = elem.as_mut_ptr() as *mut u8;
+    let src = src as *const AtomicU8;
+    for i in 0..mem::size_of::<T>() {
+        *dst.add(i) = (*src.add(i)).load(Ordering::Relaxed);
+    }
+    elem.assume_init()
+}
@@ -6,6 +6,13 @@
 //! for types that may be used as the underlying buffer in `Fixed` and `Bounded` ring buffers.
 //! - The [Fixed](./struct.Fixed.html) ring buffer type.
 //! - The [Bounded](./struct.Bounded.html) ring buffer type.
+//! - The [broadcast](./broadcast/index.html) module - ring buffers with a single writer and any
+//! number of independent readers.
+//! - The [spsc](./spsc/index.html) module - a wait-free ring buffer split into a producer and a
+//! consumer for sharing between two threads.
+//!
+//! The `broadcast` and `spsc` modules share their state via `Arc` and so are only available on
+//! targets that support atomic operations on pointers, i.e. `target_has_atomic = "ptr"`.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -18,6 +25,11 @@ use core::ops::{Index, IndexMut};
 use core::ptr;
 use core::slice;
 
+#[cfg(target_has_atomic = "ptr")]
+pub mod broadcast;
+#[cfg(target_has_atomic = "ptr")]
+pub mod spsc;
+
 #[cfg(not(feature = "std"))]
 type Vec<T> = alloc::vec::Vec<T>;
 #[cfg(feature = "std")]
@@ -319,6 +331,87 @@ where
         start.iter_mut().chain(end.iter_mut())
     }
 
+    /// Push every element of the given slice onto the back of the queue, overwriting the same
+    /// number of elements at the front of the queue.
+    ///
+    /// This is equivalent to calling `push` with each element in turn. If `elems` is longer than
+    /// the buffer, only the last `self.len()` elements are retained.
+    ///
+    /// ```
+    /// fn main() {
+    ///     let mut rb = dasp_ring_buffer::Fixed::from([0, 1, 2, 3]);
+    ///     rb.extend_from_slice(&[4, 5, 6]);
+    ///     assert_eq!(rb.slices(), (&[3][..], &[4, 5, 6][..]));
+    ///     rb.extend_from_slice(&[7, 8, 9, 10, 11]);
+    ///     assert_eq!(rb.slices(), (&[8, 9, 10, 11][..], &[][..]));
+    /// }
+    /// ```
+    pub fn extend_from_slice(&mut self, elems: &[S::Element])
+    where
+        S: SliceMut,
+        S::Element: Copy,
+    {
+        let len = self.len();
+        if elems.len() >= len {
+            self.data
+                .slice_mut()
+                .copy_from_slice(&elems[elems.len() - len..]);
+            self.first = 0;
+        } else {
+            write_wrapped(self.data.slice_mut(), self.first, elems);
+            self.first = (self.first + elems.len()) % len;
+        }
+    }
+
+    /// Copy the elements at the front of the queue into the given slice, without modifying the
+    /// ring buffer.
+    ///
+    /// Returns the number of elements copied, i.e. the lesser of `dst.len()` and `self.len()`.
+    ///
+    /// ```
+    /// fn main() {
+    ///     let mut rb = dasp_ring_buffer::Fixed::from([0, 1, 2, 3]);
+    ///     rb.push(4);
+    ///     let mut dst = [0; 3];
+    ///     assert_eq!(rb.peek_into_slice(&mut dst), 3);
+    ///     assert_eq!(dst, [1, 2, 3]);
+    ///     let mut dst = [0; 6];
+    ///     assert_eq!(rb.peek_into_slice(&mut dst), 4);
+    ///     assert_eq!(dst, [1, 2, 3, 4, 0, 0]);
+    /// }
+    /// ```
+    pub fn peek_into_slice(&self, dst: &mut [S::Element]) -> usize
+    where
+        S::Element: Copy,
+    {
+        let n = core::cmp::min(dst.len(), self.len());
+        read_wrapped(self.data.slice(), self.first, &mut dst[..n]);
+        n
+    }
+
+    /// Rotate the underlying data so that the first element of the queue is the first element of
+    /// the data slice, returning all elements of the queue as a single contiguous slice.
+    ///
+    /// ```
+    /// fn main() {
+    ///     let mut rb = dasp_ring_buffer::Fixed::from([0, 1, 2, 3]);
+    ///     rb.push(4);
+    ///     rb.push(5);
+    ///     assert_eq!(rb.make_contiguous(), &[2, 3, 4, 5]);
+    ///     assert_eq!(rb.slices(), (&[2, 3, 4, 5][..], &[][..]));
+    /// }
+    /// ```
+    pub fn make_contiguous(&mut self) -> &mut [S::Element]
+    where
+        S: SliceMut,
+    {
+        let first = self.first;
+        self.first = 0;
+        let data = self.data.slice_mut();
+        data.rotate_left(first);
+        data
+    }
+
     /// Creates a `Fixed` ring buffer from its starting index and data buffer type.
     ///
     /// **Panic!**s if the given index is out of range of the given data slice.
@@ -766,6 +859,158 @@ where
         DrainBounded { bounded: self }
     }
 
+    /// Push as many elements from the front of the given slice onto the back of the buffer as
+    /// will fit without overwriting any existing elements.
+    ///
+    /// Returns the number of elements pushed.
+    ///
+    /// See `overwrite_from_slice` for a version that overwrites the front of the buffer, as `push`
+    /// does.
+    ///
+    /// ```
+    /// fn main() {
+    ///     let mut rb = dasp_ring_buffer::Bounded::from([0; 4]);
+    ///     assert_eq!(rb.extend_from_slice(&[1, 2, 3]), 3);
+    ///     assert_eq!(rb.extend_from_slice(&[4, 5, 6]), 1);
+    ///     assert_eq!(rb.slices(), (&[1, 2, 3, 4][..], &[][..]));
+    /// }
+    /// ```
+    pub fn extend_from_slice(&mut self, elems: &[S::Element]) -> usize
+    where
+        S: SliceMut,
+    {
+        let n = core::cmp::min(elems.len(), self.max_len() - self.len);
+        let end = (self.start + self.len) % self.max_len();
+        write_wrapped(self.data.slice_mut(), end, &elems[..n]);
+        self.len += n;
+        n
+    }
+
+    /// Push every element of the given slice onto the back of the buffer, overwriting elements at
+    /// the front of the buffer once it is full.
+    ///
+    /// This is equivalent to calling `push` with each element in turn. If `elems` is longer than
+    /// `max_len`, only the last `max_len` elements are retained.
+    ///
+    /// Returns the number of elements that were overwritten.
+    ///
+    /// ```
+    /// fn main() {
+    ///     let mut rb = dasp_ring_buffer::Bounded::from([0; 4]);
+    ///     assert_eq!(rb.overwrite_from_slice(&[1, 2, 3]), 0);
+    ///     assert_eq!(rb.overwrite_from_slice(&[4, 5, 6]), 2);
+    ///     assert_eq!(rb.slices(), (&[3, 4][..], &[5, 6][..]));
+    ///     assert_eq!(rb.overwrite_from_slice(&[7, 8, 9, 10, 11]), 5);
+    ///     assert_eq!(rb.slices(), (&[8, 9, 10, 11][..], &[][..]));
+    /// }
+    /// ```
+    pub fn overwrite_from_slice(&mut self, elems: &[S::Element]) -> usize
+    where
+        S: SliceMut,
+    {
+        let max_len = self.max_len();
+        let free = max_len - self.len;
+        if elems.len() <= free {
+            self.extend_from_slice(elems);
+            return 0;
+        }
+        let overwritten = self.len + elems.len() - max_len;
+        if elems.len() >= max_len {
+            self.data
+                .slice_mut()
+                .copy_from_slice(&elems[elems.len() - max_len..]);
+            self.start = 0;
+            self.len = max_len;
+        } else {
+            self.discard(elems.len() - free);
+            self.extend_from_slice(elems);
+        }
+        overwritten
+    }
+
+    /// Copy the elements at the front of the buffer into the given slice, without removing them.
+    ///
+    /// Returns the number of elements copied, i.e. the lesser of `dst.len()` and `self.len()`.
+    ///
+    /// ```
+    /// fn main() {
+    ///     let mut rb = dasp_ring_buffer::Bounded::from_full([0, 1, 2]);
+    ///     let mut dst = [9; 4];
+    ///     assert_eq!(rb.peek_into_slice(&mut dst), 3);
+    ///     assert_eq!(dst, [0, 1, 2, 9]);
+    ///     assert_eq!(rb.len(), 3);
+    /// }
+    /// ```
+    pub fn peek_into_slice(&self, dst: &mut [S::Element]) -> usize {
+        let n = core::cmp::min(dst.len(), self.len);
+        read_wrapped(self.data.slice(), self.start, &mut dst[..n]);
+        n
+    }
+
+    /// Pop elements from the front of the buffer into the given slice.
+    ///
+    /// Returns the number of elements popped, i.e. the lesser of `dst.len()` and `self.len()`.
+    ///
+    /// ```
+    /// fn main() {
+    ///     let mut rb = dasp_ring_buffer::Bounded::from_full([0, 1, 2]);
+    ///     let mut dst = [9; 2];
+    ///     assert_eq!(rb.pop_into_slice(&mut dst), 2);
+    ///     assert_eq!(dst, [0, 1]);
+    ///     assert_eq!(rb.pop_into_slice(&mut dst), 1);
+    ///     assert_eq!(dst, [2, 1]);
+    ///     assert!(rb.is_empty());
+    /// }
+    /// ```
+    pub fn pop_into_slice(&mut self, dst: &mut [S::Element]) -> usize {
+        let n = self.peek_into_slice(dst);
+        self.discard(n)
+    }
+
+    /// Remove up to `n` elements from the front of the buffer without reading them.
+    ///
+    /// Returns the number of elements removed, i.e. the lesser of `n` and `self.len()`.
+    ///
+    /// ```
+    /// fn main() {
+    ///     let mut rb = dasp_ring_buffer::Bounded::from_full([0, 1, 2, 3]);
+    ///     assert_eq!(rb.discard(3), 3);
+    ///     assert_eq!(rb.pop(), Some(3));
+    ///     assert_eq!(rb.discard(3), 0);
+    /// }
+    /// ```
+    pub fn discard(&mut self, n: usize) -> usize {
+        let n = core::cmp::min(n, self.len);
+        self.start = (self.start + n) % self.max_len();
+        self.len -= n;
+        n
+    }
+
+    /// Rotate the underlying data so that the front of the buffer is the first element of the
+    /// data slice, returning all elements of the buffer as a single contiguous slice.
+    ///
+    /// ```
+    /// fn main() {
+    ///     let mut rb = dasp_ring_buffer::Bounded::from([0; 4]);
+    ///     rb.extend_from_slice(&[1, 2, 3, 4]);
+    ///     rb.discard(2);
+    ///     rb.extend_from_slice(&[5]);
+    ///     assert_eq!(rb.make_contiguous(), &[3, 4, 5]);
+    ///     assert_eq!(rb.slices(), (&[3, 4, 5][..], &[][..]));
+    /// }
+    /// ```
+    pub fn make_contiguous(&mut self) -> &mut [S::Element]
+    where
+        S: SliceMut,
+    {
+        let start = self.start;
+        self.start = 0;
+        let len = self.len;
+        let data = self.data.slice_mut();
+        data.rotate_left(start);
+        &mut data[..len]
+    }
+
     /// Creates a `Bounded` ring buffer from its start index, length and data slice.
     ///
     /// The maximum length of the `Bounded` ring buffer is assumed to the length of the given slice.
@@ -903,3 +1148,31 @@ where
         self.bounded.len()
     }
 }
+
+// Copy `elems` into `data` starting at the index `start`, wrapping around to the beginning of
+// `data` if necessary.
+//
+// `elems` must be no longer than `data`.
+fn write_wrapped<T>(data: &mut [T], start: usize, elems: &[T])
+where
+    T: Copy,
+{
+    let first_len = core::cmp::min(elems.len(), data.len() - start);
+    let (first, second) = elems.split_at(first_len);
+    data[start..start + first_len].copy_from_slice(first);
+    data[..second.len()].copy_from_slice(second);
+}
+
+// Copy elements from `data` starting at the index `start` into `dst`, wrapping around to the
+// beginning of `data` if necessary.
+//
+// `dst` must be no longer than `data`.
+fn read_wrapped<T>(data: &[T], start: usize, dst: &mut [T])
+where
+    T: Copy,
+{
+    let first_len = core::cmp::min(dst.len(), data.len() - start);
+    let (first, second) = dst.split_at_mut(first_len);
+    first.copy_from_slice(&data[start..start + first_len]);
+    second.copy_from_slice(&data[..second.len()]);
+}
@@ -0,0 +1,392 @@
+//! A wait-free ring buffer shared between a single producer and a single consumer.
+//!
+//! Unlike the `Bounded` ring buffer, which requires `&mut self` to both push and pop, the
+//! [**channel**](./fn.channel.html) function splits a buffer into a
+//! [**Producer**](./struct.Producer.html) and a [**Consumer**](./struct.Consumer.html) that may
+//! be moved to different threads, e.g. a decoding thread and a real-time audio callback.
+//!
+//! Neither handle ever blocks, locks or allocates. The only allocation occurs within `channel`
+//! when the shared state is created.
+
+use core::cell::UnsafeCell;
+use core::ptr;
+use core::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+use crate::SliceMut;
+
+#[cfg(not(feature = "std"))]
+type Arc<T> = alloc::sync::Arc<T>;
+#[cfg(feature = "std")]
+type Arc<T> = std::sync::Arc<T>;
+
+/// The writing half of a single-producer, single-consumer ring buffer.
+///
+/// Created via the [**channel**](./fn.channel.html) function.
+pub struct Producer<S>
+where
+    S: SliceMut,
+{
+    shared: Arc<Shared<S>>,
+    ptr: *mut S::Element,
+}
+
+/// The reading half of a single-producer, single-consumer ring buffer.
+///
+/// Created via the [**channel**](./fn.channel.html) function.
+pub struct Consumer<S>
+where
+    S: SliceMut,
+{
+    shared: Arc<Shared<S>>,
+    ptr: *mut S::Element,
+}
+
+// The state shared between the producer and the consumer.
+struct Shared<S> {
+    // Owns the storage. Only ever accessed via the pointers held by the handles.
+    _data: UnsafeCell<S>,
+    max_len: usize,
+    // The read position within `0..max_len * 2`. Only written by the consumer.
+    head: AtomicUsize,
+    // The write position within `0..max_len * 2`. Only written by the producer.
+    tail: AtomicUsize,
+    producer_dropped: AtomicBool,
+    consumer_dropped: AtomicBool,
+}
+
+// The handles only access the storage at disjoint indices, synchronised via `head` and `tail`.
+unsafe impl<S> Send for Producer<S>
+where
+    S: SliceMut + Send,
+    S::Element: Send,
+{
+}
+
+unsafe impl<S> Send for Consumer<S>
+where
+    S: SliceMut + Send,
+    S::Element: Send,
+{
+}
+
+/// Split the given data slice into the producer and consumer of an empty ring buffer.
+///
+/// The maximum length of the ring buffer is the length of `data`. The existing elements of
+/// `data` are never read.
+///
+/// **Panic!**s if the given `data` buffer is empty.
+///
+/// ```
+/// use std::thread;
+///
+/// fn main() {
+///     let (mut producer, mut consumer) = dasp_ring_buffer::spsc::channel([0i32; 4]);
+///     let handle = thread::spawn(move || {
+///         for i in 0..100 {
+///             while producer.push(i).is_err() {
+///                 thread::yield_now();
+///             }
+///         }
+///     });
+///     let mut received = 0;
+///     while received < 100 {
+///         if let Some(i) = consumer.pop() {
+///             assert_eq!(i, received);
+///             received += 1;
+///         }
+///     }
+///     handle.join().unwrap();
+/// }
+/// ```
+pub fn channel<S>(data: S) -> (Producer<S>, Consumer<S>)
+where
+    S: SliceMut,
+    S::Element: Copy,
+{
+    let max_len = data.slice().len();
+    assert!(max_len > 0 && max_len <= usize::MAX / 2);
+    let shared = Arc::new(Shared {
+        _data: UnsafeCell::new(data),
+        max_len,
+        head: AtomicUsize::new(0),
+        tail: AtomicUsize::new(0),
+        producer_dropped: AtomicBool::new(false),
+        consumer_dropped: AtomicBool::new(false),
+    });
+    // The data now has a fixed address within the `Arc`, and no handle exists yet.
+    let ptr = unsafe { (*shared._data.get()).slice_mut().as_mut_ptr() };
+    let producer = Producer {
+        shared: shared.clone(),
+        ptr,
+    };
+    let consumer = Consumer { shared, ptr };
+    (producer, consumer)
+}
+
+// Positions run over twice the maximum length so that a full buffer may be distinguished from an
+// empty one without the counters ever overflowing.
+impl<S> Shared<S> {
+    // The number of elements between the given head and tail positions.
+    #[inline]
+    fn len(&self, head: usize, tail: usize) -> usize {
+        if tail >= head {
+            tail - head
+        } else {
+            tail + self.max_len * 2 - head
+        }
+    }
+
+    // The given position advanced by `n` elements.
+    #[inline]
+    fn advance(&self, pos: usize, n: usize) -> usize {
+        let pos = pos + n;
+        if pos >= self.max_len * 2 {
+            pos - self.max_len * 2
+        } else {
+            pos
+        }
+    }
+
+    // The index within the data slice of the given position.
+    #[inline]
+    fn index(&self, pos: usize) -> usize {
+        if pos >= self.max_len {
+            pos - self.max_len
+        } else {
+            pos
+        }
+    }
+}
+
+impl<S> Producer<S>
+where
+    S: SliceMut,
+    S::Element: Copy,
+{
+    /// The maximum number of elements that the ring buffer may hold.
+    #[inline]
+    pub fn max_len(&self) -> usize {
+        self.shared.max_len
+    }
+
+    /// The number of elements currently in the ring buffer.
+    ///
+    /// The consumer may pop elements concurrently, so this is an upper bound.
+    #[inline]
+    pub fn len(&self) -> usize {
+        let head = self.shared.head.load(Ordering::Acquire);
+        let tail = self.shared.tail.load(Ordering::Relaxed);
+        self.shared.len(head, tail)
+    }
+
+    /// The number of elements that may be pushed before the ring buffer is full.
+    ///
+    /// The consumer may pop elements concurrently, so this is a lower bound.
+    #[inline]
+    pub fn free_len(&self) -> usize {
+        self.max_len() - self.len()
+    }
+
+    /// Whether or not the ring buffer's length is equal to `0`.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Whether or not the ring buffer's length is equal to the maximum length.
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.len() == self.max_len()
+    }
+
+    /// Whether or not the consumer has been dropped, in which case pushed elements will never be
+    /// read.
+    #[inline]
+    pub fn is_abandoned(&self) -> bool {
+        self.shared.consumer_dropped.load(Ordering::Acquire)
+    }
+
+    /// Push the given element onto the back of the ring buffer.
+    ///
+    /// Returns the element as an `Err` if the ring buffer is full.
+    ///
+    /// ```
+    /// fn main() {
+    ///     let (mut producer, mut consumer) = dasp_ring_buffer::spsc::channel([0; 2]);
+    ///     assert_eq!(producer.push(1), Ok(()));
+    ///     assert_eq!(producer.push(2), Ok(()));
+    ///     assert_eq!(producer.push(3), Err(3));
+    ///     assert_eq!(consumer.pop(), Some(1));
+    ///     assert_eq!(producer.push(3), Ok(()));
+    /// }
+    /// ```
+    pub fn push(&mut self, elem: S::Element) -> Result<(), S::Element> {
+        let tail = self.shared.tail.load(Ordering::Relaxed);
+        let head = self.shared.head.load(Ordering::Acquire);
+        if self.shared.len(head, tail) == self.max_len() {
+            return Err(elem);
+        }
+        unsafe {
+            ptr::write(self.ptr.add(self.shared.index(tail)), elem);
+        }
+        self.shared
+            .tail
+            .store(self.shared.advance(tail, 1), Ordering::Release);
+        Ok(())
+    }
+
+    /// Push as many elements from the front of the given slice as will fit into the ring buffer.
+    ///
+    /// Returns the number of elements pushed.
+    ///
+    /// ```
+    /// fn main() {
+    ///     let (mut producer, mut consumer) = dasp_ring_buffer::spsc::channel([0; 4]);
+    ///     assert_eq!(producer.push_slice(&[1, 2, 3]), 3);
+    ///     assert_eq!(producer.push_slice(&[4, 5, 6]), 1);
+    ///     assert_eq!(producer.free_len(), 0);
+    ///     assert_eq!(consumer.len(), 4);
+    /// }
+    /// ```
+    pub fn push_slice(&mut self, elems: &[S::Element]) -> usize {
+        let tail = self.shared.tail.load(Ordering::Relaxed);
+        let head = self.shared.head.load(Ordering::Acquire);
+        let max_len = self.max_len();
+        let count = core::cmp::min(elems.len(), max_len - self.shared.len(head, tail));
+        let start = self.shared.index(tail);
+        let first = core::cmp::min(count, max_len - start);
+        unsafe {
+            ptr::copy_nonoverlapping(elems.as_ptr(), self.ptr.add(start), first);
+            ptr::copy_nonoverlapping(elems.as_ptr().add(first), self.ptr, count - first);
+        }
+        self.shared
+            .tail
+            .store(self.shared.advance(tail, count), Ordering::Release);
+        count
+    }
+}
+
+impl<S> Consumer<S>
+where
+    S: SliceMut,
+    S::Element: Copy,
+{
+    /// The maximum number of elements that the ring buffer may hold.
+    #[inline]
+    pub fn max_len(&self) -> usize {
+        self.shared.max_len
+    }
+
+    /// The number of elements currently in the ring buffer.
+    ///
+    /// The producer may push elements concurrently, so this is a lower bound.
+    #[inline]
+    pub fn len(&self) -> usize {
+        let head = self.shared.head.load(Ordering::Relaxed);
+        let tail = self.shared.tail.load(Ordering::Acquire);
+        self.shared.len(head, tail)
+    }
+
+    /// The number of elements that may be pushed before the ring buffer is full.
+    ///
+    /// The producer may push elements concurrently, so this is an upper bound.
+    #[inline]
+    pub fn free_len(&self) -> usize {
+        self.max_len() - self.len()
+    }
+
+    /// Whether or not the ring buffer's length is equal to `0`.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Whether or not the ring buffer's length is equal to the maximum length.
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.len() == self.max_len()
+    }
+
+    /// Whether or not the producer has been dropped, in which case no more elements will be
+    /// pushed.
+    #[inline]
+    pub fn is_abandoned(&self) -> bool {
+        self.shared.producer_dropped.load(Ordering::Acquire)
+    }
+
+    /// Remove an element from the front of the ring buffer.
+    ///
+    /// Returns `None` if the ring buffer is empty.
+    ///
+    /// ```
+    /// fn main() {
+    ///     let (mut producer, mut consumer) = dasp_ring_buffer::spsc::channel([0; 2]);
+    ///     assert_eq!(consumer.pop(), None);
+    ///     producer.push(1).unwrap();
+    ///     assert_eq!(consumer.pop(), Some(1));
+    ///     assert_eq!(consumer.pop(), None);
+    /// }
+    /// ```
+    pub fn pop(&mut self) -> Option<S::Element> {
+        let head = self.shared.head.load(Ordering::Relaxed);
+        let tail = self.shared.tail.load(Ordering::Acquire);
+        if self.shared.len(head, tail) == 0 {
+            return None;
+        }
+        let elem = unsafe { ptr::read(self.ptr.add(self.shared.index(head))) };
+        self.shared
+            .head
+            .store(self.shared.advance(head, 1), Ordering::Release);
+        Some(elem)
+    }
+
+    /// Pop as many elements as are available into the front of the given slice.
+    ///
+    /// Returns the number of elements popped.
+    ///
+    /// ```
+    /// fn main() {
+    ///     let (mut producer, mut consumer) = dasp_ring_buffer::spsc::channel([0; 4]);
+    ///     producer.push_slice(&[1, 2, 3]);
+    ///     let mut elems = [0; 2];
+    ///     assert_eq!(consumer.pop_slice(&mut elems), 2);
+    ///     assert_eq!(elems, [1, 2]);
+    ///     assert_eq!(consumer.pop_slice(&mut elems), 1);
+    ///     assert_eq!(elems, [3, 2]);
+    /// }
+    /// ```
+    pub fn pop_slice(&mut self, elems: &mut [S::Element]) -> usize {
+        let head = self.shared.head.load(Ordering::Relaxed);
+        let tail = self.shared.tail.load(Ordering::Acquire);
+        let max_len = self.max_len();
+        let count = core::cmp::min(elems.len(), self.shared.len(head, tail));
+        let start = self.shared.index(head);
+        let first = core::cmp::min(count, max_len - start);
+        unsafe {
+            ptr::copy_nonoverlapping(self.ptr.add(start), elems.as_mut_ptr(), first);
+            ptr::copy_nonoverlapping(self.ptr, elems.as_mut_ptr().add(first), count - first);
+        }
+        self.shared
+            .head
+            .store(self.shared.advance(head, count), Ordering::Release);
+        count
+    }
+}
+
+impl<S> Drop for Producer<S>
+where
+    S: SliceMut,
+{
+    fn drop(&mut self) {
+        self.shared.producer_dropped.store(true, Ordering::Release);
+    }
+}
+
+impl<S> Drop for Consumer<S>
+where
+    S: SliceMut,
+{
+    fn drop(&mut self) {
+        self.shared.consumer_dropped.store(true, Ordering::Release);
+    }
+}
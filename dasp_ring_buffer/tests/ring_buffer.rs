@@ -39,3 +39,242 @@ fn test_bounded_get_out_of_range() {
     let rb = ring_buffer::Bounded::from([0i32; 3]);
     let _ = rb[0];
 }
+
+#[test]
+fn test_spsc_wrapping_slices() {
+    let (mut producer, mut consumer) = ring_buffer::spsc::channel([0; 3]);
+    let mut elems = [0; 4];
+    for i in 0..10 {
+        assert_eq!(producer.push_slice(&[i, i + 1, i + 2, i + 3]), 3);
+        assert!(producer.is_full());
+        assert_eq!(producer.push(0), Err(0));
+        assert_eq!(consumer.pop_slice(&mut elems[..2]), 2);
+        assert_eq!(&elems[..2], &[i, i + 1]);
+        assert_eq!(consumer.len(), 1);
+        assert_eq!(producer.free_len(), 2);
+        assert_eq!(consumer.pop(), Some(i + 2));
+        assert!(consumer.is_empty());
+        assert_eq!(consumer.pop_slice(&mut elems), 0);
+    }
+}
+
+#[test]
+fn test_spsc_abandoned() {
+    let (producer, consumer) = ring_buffer::spsc::channel(vec![0u8; 2]);
+    assert!(!producer.is_abandoned());
+    assert!(!consumer.is_abandoned());
+    drop(producer);
+    assert!(consumer.is_abandoned());
+}
+
+#[test]
+#[should_panic]
+fn test_spsc_from_empty_vec() {
+    ring_buffer::spsc::channel(Vec::<i32>::new());
+}
+
+#[test]
+fn test_spsc_threads() {
+    const LEN: usize = 10_000;
+    let data = vec![0usize; 64].into_boxed_slice();
+    let (mut producer, mut consumer) = ring_buffer::spsc::channel(data);
+    let handle = std::thread::spawn(move || {
+        let elems: Vec<usize> = (0..LEN).collect();
+        let mut pushed = 0;
+        while pushed < LEN {
+            let end = std::cmp::min(pushed + 7, LEN);
+            pushed += producer.push_slice(&elems[pushed..end]);
+        }
+    });
+    let mut received = Vec::with_capacity(LEN);
+    let mut elems = [0; 5];
+    while received.len() < LEN {
+        let n = consumer.pop_slice(&mut elems);
+        received.extend_from_slice(&elems[..n]);
+    }
+    handle.join().unwrap();
+    assert_eq!(received, (0..LEN).collect::<Vec<_>>());
+    assert!(consumer.is_abandoned());
+}
+
+#[test]
+fn test_bounded_bulk_matches_single() {
+    let mut bulk = ring_buffer::Bounded::from([0; 5]);
+    let mut single = ring_buffer::Bounded::from([0; 5]);
+    let mut dst = [0; 3];
+    for i in 0..20 {
+        let elems = [i, i + 1, i + 2];
+        let overwritten = bulk.overwrite_from_slice(&elems[..i % 4]);
+        let overwritten_single = elems[..i % 4]
+            .iter()
+            .filter_map(|&e| single.push(e))
+            .count();
+        assert_eq!(overwritten, overwritten_single);
+        assert_eq!(bulk.slices(), single.slices());
+
+        let n = bulk.pop_into_slice(&mut dst[..i % 3]);
+        for &e in &dst[..n] {
+            assert_eq!(single.pop(), Some(e));
+        }
+        assert_eq!(bulk.slices(), single.slices());
+    }
+}
+
+#[test]
+fn test_bounded_extend_from_slice_wrapping() {
+    let mut rb = ring_buffer::Bounded::from(vec![0; 4]);
+    assert_eq!(rb.extend_from_slice(&[1, 2, 3]), 3);
+    assert_eq!(rb.discard(2), 2);
+    assert_eq!(rb.extend_from_slice(&[4, 5, 6, 7]), 3);
+    assert!(rb.is_full());
+    assert_eq!(rb.slices(), (&[3, 4][..], &[5, 6][..]));
+
+    let mut dst = [0; 4];
+    assert_eq!(rb.peek_into_slice(&mut dst), 4);
+    assert_eq!(dst, [3, 4, 5, 6]);
+    assert_eq!(rb.make_contiguous(), &[3, 4, 5, 6]);
+    assert_eq!(rb.pop_into_slice(&mut dst), 4);
+    assert!(rb.is_empty());
+    assert_eq!(rb.discard(1), 0);
+}
+
+#[test]
+fn test_fixed_extend_from_slice_matches_push() {
+    let mut bulk = ring_buffer::Fixed::from([0; 5]);
+    let mut single = ring_buffer::Fixed::from([0; 5]);
+    for i in 0..20 {
+        let elems: Vec<i32> = (i..i + i % 7).collect();
+        bulk.extend_from_slice(&elems);
+        single.extend(elems);
+        assert_eq!(
+            bulk.iter().collect::<Vec<_>>(),
+            single.iter().collect::<Vec<_>>()
+        );
+    }
+
+    let mut dst = [0; 5];
+    assert_eq!(bulk.peek_into_slice(&mut dst), 5);
+    assert_eq!(bulk.make_contiguous(), &dst);
+}
+
+#[test]
+fn test_broadcast_cursors() {
+    use ring_buffer::broadcast::{Broadcast, Overrun};
+
+    let mut rb = Broadcast::from([0; 3]);
+    let mut fast = rb.cursor();
+    let mut slow = rb.cursor();
+    let mut dst = [0; 4];
+    for i in 0..10 {
+        rb.extend_from_slice(&[i * 2, i * 2 + 1]);
+        assert_eq!(rb.read_into_slice(&mut fast, &mut dst), Ok(2));
+        assert_eq!(&dst[..2], &[i * 2, i * 2 + 1]);
+    }
+    assert_eq!(rb.written(), 20);
+    assert_eq!(rb.available(&slow), Err(Overrun { skipped: 17 }));
+    assert_eq!(
+        rb.read_into_slice(&mut slow, &mut dst),
+        Err(Overrun { skipped: 17 })
+    );
+    assert_eq!(rb.available(&slow), Ok(3));
+    assert_eq!(rb.read_into_slice(&mut slow, &mut dst), Ok(3));
+    assert_eq!(&dst[..3], &[17, 18, 19]);
+    assert_eq!(slow, fast);
+    assert_eq!(rb.read(&mut slow), Ok(None));
+}
+
+#[test]
+fn test_broadcast_oldest_cursor() {
+    use ring_buffer::broadcast::Broadcast;
+
+    let mut rb = Broadcast::from(vec![0; 4]);
+    rb.push(1);
+    rb.push(2);
+    let mut cursor = rb.oldest_cursor();
+    assert_eq!(rb.read(&mut cursor), Ok(Some(1)));
+
+    // Writing more than `max_len` elements retains only the last `max_len`.
+    rb.extend_from_slice(&[3, 4, 5, 6, 7, 8, 9]);
+    let mut cursor = rb.oldest_cursor();
+    let mut dst = [0; 8];
+    assert_eq!(rb.read_into_slice(&mut cursor, &mut dst), Ok(4));
+    assert_eq!(&dst[..4], &[6, 7, 8, 9]);
+}
+
+#[test]
+fn test_broadcast_channel_overrun() {
+    use ring_buffer::broadcast::{self, Overrun};
+
+    let (mut writer, mut a) = broadcast::channel([0u8; 4]);
+    let mut b = a.clone();
+    writer.extend_from_slice(&[1, 2, 3]);
+    assert_eq!(a.read(), Ok(Some(1)));
+    let mut c = a.clone();
+    writer.extend_from_slice(&[4, 5]);
+    assert_eq!(b.available(), Err(Overrun { skipped: 1 }));
+    assert_eq!(b.read(), Err(Overrun { skipped: 1 }));
+    assert_eq!(b.read(), Ok(Some(2)));
+    let mut dst = [0; 8];
+    assert_eq!(c.read_into_slice(&mut dst), Ok(4));
+    assert_eq!(&dst[..4], &[2, 3, 4, 5]);
+    assert_eq!(writer.reader().read(), Ok(None));
+}
+
+#[test]
+fn test_broadcast_channel_threads() {
+    use ring_buffer::broadcast::{self, Overrun};
+
+    const LEN: usize = 100_000;
+    let (mut writer, reader) = broadcast::channel(vec![0usize; 16].into_boxed_slice());
+    let handles: Vec<_> = (0..3)
+        .map(|_| {
+            let mut reader = reader.clone();
+            std::thread::spawn(move || {
+                // Every element either arrives in order or is accounted for by an overrun.
+                let mut expected = 0;
+                let mut dst = [0; 5];
+                while expected < LEN {
+                    match reader.read_into_slice(&mut dst) {
+                        Ok(n) => {
+                            for &elem in &dst[..n] {
+                                assert_eq!(elem, expected);
+                                expected += 1;
+                            }
+                        }
+                        Err(Overrun { skipped }) => expected += skipped,
+                    }
+                }
+                expected
+            })
+        })
+        .collect();
+    drop(reader);
+    for i in 0..LEN {
+        writer.push(i);
+    }
+    for handle in handles {
+        assert_eq!(handle.join().unwrap(), LEN);
+    }
+}
+
+#[test]
+fn test_broadcast_channel_frames_are_not_torn() {
+    use ring_buffer::broadcast;
+
+    const LEN: u32 = 100_000;
+    let (mut writer, mut reader) = broadcast::channel([[0u32; 2]; 4]);
+    let handle = std::thread::spawn(move || {
+        let mut received = 0;
+        while received < LEN {
+            // Overruns are expected, but every frame that is read must be intact.
+            if let Ok(Some([a, b])) = reader.read() {
+                assert_eq!(b, !a);
+                received = a + 1;
+            }
+        }
+    });
+    for i in 0..LEN {
+        writer.push([i, !i]);
+    }
+    handle.join().unwrap();
+}
@@ -147,7 +147,7 @@ where
         S: ring_buffer::SliceMut,
     {
         // Determine the square of the new frame.
-        let new_frame_square = new_frame.to_float_frame().map(|s| s * s);
+        let new_frame_square = square(new_frame);
         // Push back the new frame_square.
         let removed_frame_square = self.window.push(new_frame_square);
         // Add the new frame square and subtract the removed frame square.
@@ -197,11 +197,46 @@ where
     }
 
     fn calc_rms_squared(&self) -> F::Float {
-        let num_frames_f = Sample::from_sample(self.window.len() as f32);
-        self.square_sum.map(|s| s / num_frames_f)
+        mean_square(self.square_sum, self.window.len())
     }
 }
 
+/// The square of each channel of the given frame, as summed by the **Rms** window.
+///
+/// ```
+/// fn main() {
+///     assert_eq!(dasp_rms::square([0.5, -0.25]), [0.25, 0.0625]);
+/// }
+/// ```
+#[inline]
+pub fn square<F>(frame: F) -> F::Float
+where
+    F: Frame,
+{
+    frame.to_float_frame().map(|s| s * s)
+}
+
+/// The mean of each channel of the given sum of `len` frame squares, i.e. the RMS squared.
+///
+/// Given the sum of the squares of every frame, this produces exactly the same result as an
+/// **Rms** whose window is `len` frames long and was filled with those frames.
+///
+/// ```
+/// fn main() {
+///     let square_sum = [1.0 + 0.25];
+///     assert_eq!(dasp_rms::mean_square(square_sum, 2), [0.625]);
+/// }
+/// ```
+#[inline]
+pub fn mean_square<F>(square_sum: F, len: usize) -> F
+where
+    F: Frame,
+    F::Sample: FloatSample,
+{
+    let num_frames_f = Sample::from_sample(len as f32);
+    square_sum.map(|s| s / num_frames_f)
+}
+
 impl<F, S> fmt::Debug for Rms<F, S>
 where
     F: Frame,
@@ -0,0 +1,523 @@
+//! Reading and writing samples as raw bytes.
+//!
+//! Each byte layout is described by a type implementing the [**Format**](./trait.Format.html)
+//! trait. Formats are generic over their [**Endianness**](./trait.Endianness.html) and the
+//! **Sample** type that they decode to and encode from.
+//!
+//! - [**Packed**](./struct.Packed.html) stores a sample in the fewest whole bytes able to hold it,
+//!   e.g. 3 bytes for an **I24**, 6 bytes for an **I48** and 1 byte for a **MuLaw** or **ALaw**
+//!   code.
+//! - [**Lsb24In32**](./struct.Lsb24In32.html) and [**Msb24In32**](./struct.Msb24In32.html) store
+//!   a 24-bit sample in a 4 byte container, justified to the least or most significant end.
+//! - [**Lsb20In24**](./struct.Lsb20In24.html), [**Msb20In24**](./struct.Msb20In24.html),
+//!   [**Lsb20In32**](./struct.Lsb20In32.html) and [**Msb20In32**](./struct.Msb20In32.html) store
+//!   a 20-bit sample in a 3 or 4 byte container. 20-bit samples are decoded to the most
+//!   significant 20 bits of an **I24** or **U24**.
+//!
+//! Use [**decode_slice**](./fn.decode_slice.html) and [**encode_slice**](./fn.encode_slice.html)
+//! for bulk conversion between byte slices and sample slices, or
+//! [**decode_iter**](./fn.decode_iter.html) to lazily decode samples from any iterator yielding
+//! bytes.
+
+use crate::types::{ALaw, MuLaw, I24, I48, Q15, Q31, U24, U48};
+use crate::Sample;
+use core::marker::PhantomData;
+
+/// A layout in which a single **Sample** is stored as bytes.
+pub trait Format {
+    /// The type of sample encoded by the format.
+    type Sample: Sample;
+    /// The number of bytes occupied by a single sample.
+    const SIZE: usize;
+
+    /// Decode a sample from the first `SIZE` bytes of the given slice.
+    ///
+    /// **Panics** if `bytes` is shorter than `SIZE`.
+    fn decode(bytes: &[u8]) -> Self::Sample;
+
+    /// Encode the sample into the first `SIZE` bytes of the given slice.
+    ///
+    /// **Panics** if `bytes` is shorter than `SIZE`.
+    fn encode(sample: Self::Sample, bytes: &mut [u8]);
+}
+
+/// The order in which the bytes of a multi-byte sample are stored.
+pub trait Endianness {
+    /// Read an unsigned integer from the first `n` bytes of `bytes` where `n <= 8`.
+    fn read_uint(bytes: &[u8], n: usize) -> u64;
+    /// Write the least significant `n` bytes of `value` to the first `n` bytes of `bytes` where
+    /// `n <= 8`.
+    fn write_uint(value: u64, bytes: &mut [u8], n: usize);
+}
+
+/// Bytes are stored from least to most significant.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LittleEndian {}
+
+/// Bytes are stored from most to least significant.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BigEndian {}
+
+/// The endianness of the target platform.
+#[cfg(target_endian = "little")]
+pub type NativeEndian = LittleEndian;
+/// The endianness of the target platform.
+#[cfg(target_endian = "big")]
+pub type NativeEndian = BigEndian;
+
+/// A sample stored in the fewest whole bytes able to hold it.
+///
+/// Implemented for all primitive sample types along with **I24** and **U24** (3 bytes) and
+/// **I48** and **U48** (6 bytes).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Packed<S, E> {
+    _marker: PhantomData<(S, E)>,
+}
+
+/// A 24-bit sample stored in the least significant 3 bytes of a 4 byte container.
+///
+/// When encoding, the most significant byte is set to the sign extension for **I24** and zero for
+/// **U24**. It is ignored when decoding.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Lsb24In32<S, E> {
+    _marker: PhantomData<(S, E)>,
+}
+
+/// A 24-bit sample stored in the most significant 3 bytes of a 4 byte container.
+///
+/// When encoding, the least significant byte is set to zero. It is ignored when decoding.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Msb24In32<S, E> {
+    _marker: PhantomData<(S, E)>,
+}
+
+/// A 20-bit sample stored in the least significant 20 bits of a 3 byte container.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Lsb20In24<S, E> {
+    _marker: PhantomData<(S, E)>,
+}
+
+/// A 20-bit sample stored in the most significant 20 bits of a 3 byte container.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Msb20In24<S, E> {
+    _marker: PhantomData<(S, E)>,
+}
+
+/// A 20-bit sample stored in the least significant 20 bits of a 4 byte container.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Lsb20In32<S, E> {
+    _marker: PhantomData<(S, E)>,
+}
+
+/// A 20-bit sample stored in the most significant 20 bits of a 4 byte container.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Msb20In32<S, E> {
+    _marker: PhantomData<(S, E)>,
+}
+
+/// An iterator that decodes samples of the format `F` from an iterator yielding bytes.
+///
+/// Any trailing bytes that do not make up a whole sample are discarded.
+#[derive(Clone)]
+pub struct DecodeIter<I, F> {
+    bytes: I,
+    format: PhantomData<F>,
+}
+
+impl Endianness for LittleEndian {
+    #[inline]
+    fn read_uint(bytes: &[u8], n: usize) -> u64 {
+        bytes[..n]
+            .iter()
+            .rev()
+            .fold(0, |acc, &byte| (acc << 8) | byte as u64)
+    }
+
+    #[inline]
+    fn write_uint(value: u64, bytes: &mut [u8], n: usize) {
+        for (i, byte) in bytes[..n].iter_mut().enumerate() {
+            *byte = (value >> (8 * i)) as u8;
+        }
+    }
+}
+
+impl Endianness for BigEndian {
+    #[inline]
+    fn read_uint(bytes: &[u8], n: usize) -> u64 {
+        bytes[..n]
+            .iter()
+            .fold(0, |acc, &byte| (acc << 8) | byte as u64)
+    }
+
+    #[inline]
+    fn write_uint(value: u64, bytes: &mut [u8], n: usize) {
+        for (i, byte) in bytes[..n].iter_mut().rev().enumerate() {
+            *byte = (value >> (8 * i)) as u8;
+        }
+    }
+}
+
+/// Decode every sample in `samples` from the given slice of bytes.
+///
+/// Use `dasp_slice::to_sample_slice_mut` to decode directly into a slice of frames.
+///
+/// **Panics** if the length of `bytes` is not equal to `samples.len() * F::SIZE`.
+///
+/// # Example
+///
+/// ```rust
+/// use dasp_sample::bytes::{self, LittleEndian, Packed};
+/// use dasp_sample::I24;
+///
+/// fn main() {
+///     let bytes = [0x01, 0x00, 0x00, 0xFF, 0xFF, 0xFF];
+///     let mut samples = [I24::new_unchecked(0); 2];
+///     bytes::decode_slice::<Packed<I24, LittleEndian>>(&bytes, &mut samples);
+///     assert_eq!(samples, [I24::new_unchecked(1), I24::new_unchecked(-1)]);
+/// }
+/// ```
+pub fn decode_slice<F>(bytes: &[u8], samples: &mut [F::Sample])
+where
+    F: Format,
+{
+    assert_eq!(bytes.len(), samples.len() * F::SIZE);
+    for (sample, bytes) in samples.iter_mut().zip(bytes.chunks_exact(F::SIZE)) {
+        *sample = F::decode(bytes);
+    }
+}
+
+/// Encode every sample in `samples` to the given slice of bytes.
+///
+/// Use `dasp_slice::to_sample_slice` to encode directly from a slice of frames.
+///
+/// **Panics** if the length of `bytes` is not equal to `samples.len() * F::SIZE`.
+///
+/// # Example
+///
+/// ```rust
+/// use dasp_sample::bytes::{self, BigEndian, Packed};
+///
+/// fn main() {
+///     let samples = [1i16, -2];
+///     let mut bytes = [0u8; 4];
+///     bytes::encode_slice::<Packed<i16, BigEndian>>(&samples, &mut bytes);
+///     assert_eq!(bytes, [0x00, 0x01, 0xFF, 0xFE]);
+/// }
+/// ```
+pub fn encode_slice<F>(samples: &[F::Sample], bytes: &mut [u8])
+where
+    F: Format,
+{
+    assert_eq!(bytes.len(), samples.len() * F::SIZE);
+    for (&sample, bytes) in samples.iter().zip(bytes.chunks_exact_mut(F::SIZE)) {
+        F::encode(sample, bytes);
+    }
+}
+
+/// Lazily decode samples of the format `F` from the given bytes.
+///
+/// The resulting iterator may be passed to `dasp_signal::from_interleaved_samples_iter` in order
+/// to produce a signal from raw interleaved data.
+///
+/// # Example
+///
+/// ```rust
+/// use dasp_sample::bytes::{self, LittleEndian, Msb24In32};
+/// use dasp_sample::{Sample, U24};
+///
+/// fn main() {
+///     let bytes = [0x00, 0x00, 0x00, 0x80, 0x00, 0xFF, 0xFF, 0xFF, 0x00];
+///     let samples: Vec<f32> = bytes::decode_iter::<Msb24In32<U24, LittleEndian>, _>(bytes.to_vec())
+///         .map(Sample::to_sample)
+///         .collect();
+///     assert_eq!(samples.len(), 2);
+///     assert_eq!(samples[0], 0.0);
+///     assert!(samples[1] > 0.99);
+/// }
+/// ```
+pub fn decode_iter<F, I>(bytes: I) -> DecodeIter<I::IntoIter, F>
+where
+    F: Format,
+    I: IntoIterator<Item = u8>,
+{
+    DecodeIter {
+        bytes: bytes.into_iter(),
+        format: PhantomData,
+    }
+}
+
+impl<I, F> Iterator for DecodeIter<I, F>
+where
+    I: Iterator<Item = u8>,
+    F: Format,
+{
+    type Item = F::Sample;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut buffer = [0u8; 8];
+        for byte in buffer[..F::SIZE].iter_mut() {
+            *byte = self.bytes.next()?;
+        }
+        Some(F::decode(&buffer[..F::SIZE]))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.bytes.size_hint();
+        (lower / F::SIZE, upper.map(|upper| upper / F::SIZE))
+    }
+}
+
+macro_rules! impl_packed_format {
+    ($($T:ty: $Bits:ty, $size:expr;)*) => {
+        $(
+            impl<E> Format for Packed<$T, E>
+            where
+                E: Endianness,
+            {
+                type Sample = $T;
+                const SIZE: usize = $size;
+
+                #[inline]
+                fn decode(bytes: &[u8]) -> Self::Sample {
+                    <$T as Bits>::from_bits(E::read_uint(bytes, $size) as $Bits)
+                }
+
+                #[inline]
+                fn encode(sample: Self::Sample, bytes: &mut [u8]) {
+                    E::write_uint(Bits::to_bits(sample) as u64, bytes, $size);
+                }
+            }
+        )*
+    };
+}
+
+/// Integer and custom sample types from and to their raw, unsigned bit representation.
+///
+/// Mirrors the `from_bits` and `to_bits` methods of the primitive float types.
+trait Bits {
+    type Bits;
+    fn from_bits(bits: Self::Bits) -> Self;
+    fn to_bits(self) -> Self::Bits;
+}
+
+macro_rules! impl_bits {
+    ($($T:ty: $Bits:ty),*) => {
+        $(
+            impl Bits for $T {
+                type Bits = $Bits;
+                #[inline]
+                fn from_bits(bits: $Bits) -> Self {
+                    bits as $T
+                }
+                #[inline]
+                fn to_bits(self) -> $Bits {
+                    self as $Bits
+                }
+            }
+        )*
+    };
+}
+
+impl_bits!(i8: u8, u8: u8, i16: u16, u16: u16, i32: u32, u32: u32, i64: u64, u64: u64);
+
+impl Bits for I24 {
+    type Bits = u32;
+    #[inline]
+    fn from_bits(bits: u32) -> Self {
+        // Sign extend from 24 bits.
+        I24::new_unchecked(((bits << 8) as i32) >> 8)
+    }
+    #[inline]
+    fn to_bits(self) -> u32 {
+        self.inner() as u32 & 0x00FF_FFFF
+    }
+}
+
+impl Bits for U24 {
+    type Bits = u32;
+    #[inline]
+    fn from_bits(bits: u32) -> Self {
+        U24::new_unchecked((bits & 0x00FF_FFFF) as i32)
+    }
+    #[inline]
+    fn to_bits(self) -> u32 {
+        self.inner() as u32
+    }
+}
+
+impl Bits for I48 {
+    type Bits = u64;
+    #[inline]
+    fn from_bits(bits: u64) -> Self {
+        // Sign extend from 48 bits.
+        I48::new_unchecked(((bits << 16) as i64) >> 16)
+    }
+    #[inline]
+    fn to_bits(self) -> u64 {
+        self.inner() as u64 & 0x0000_FFFF_FFFF_FFFF
+    }
+}
+
+impl Bits for U48 {
+    type Bits = u64;
+    #[inline]
+    fn from_bits(bits: u64) -> Self {
+        U48::new_unchecked((bits & 0x0000_FFFF_FFFF_FFFF) as i64)
+    }
+    #[inline]
+    fn to_bits(self) -> u64 {
+        self.inner() as u64
+    }
+}
+
+impl Bits for Q15 {
+    type Bits = u16;
+    #[inline]
+    fn from_bits(bits: u16) -> Self {
+        Q15::from_bits(bits as i16)
+    }
+    #[inline]
+    fn to_bits(self) -> u16 {
+        Q15::to_bits(self) as u16
+    }
+}
+
+impl Bits for Q31 {
+    type Bits = u32;
+    #[inline]
+    fn from_bits(bits: u32) -> Self {
+        Q31::from_bits(bits as i32)
+    }
+    #[inline]
+    fn to_bits(self) -> u32 {
+        Q31::to_bits(self) as u32
+    }
+}
+
+impl Bits for f32 {
+    type Bits = u32;
+    #[inline]
+    fn from_bits(bits: u32) -> Self {
+        f32::from_bits(bits)
+    }
+    #[inline]
+    fn to_bits(self) -> u32 {
+        f32::to_bits(self)
+    }
+}
+
+impl Bits for f64 {
+    type Bits = u64;
+    #[inline]
+    fn from_bits(bits: u64) -> Self {
+        f64::from_bits(bits)
+    }
+    #[inline]
+    fn to_bits(self) -> u64 {
+        f64::to_bits(self)
+    }
+}
+
+macro_rules! impl_bits_for_companded {
+    ($($T:ident)*) => {
+        $(
+            impl Bits for $T {
+                type Bits = u8;
+                #[inline]
+                fn from_bits(bits: u8) -> Self {
+                    $T::from_bits(bits)
+                }
+                #[inline]
+                fn to_bits(self) -> u8 {
+                    $T::to_bits(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_bits_for_companded!(MuLaw ALaw);
+
+impl_packed_format! {
+    i8: u8, 1;
+    u8: u8, 1;
+    i16: u16, 2;
+    u16: u16, 2;
+    I24: u32, 3;
+    U24: u32, 3;
+    i32: u32, 4;
+    u32: u32, 4;
+    I48: u64, 6;
+    U48: u64, 6;
+    i64: u64, 8;
+    u64: u64, 8;
+    f32: u32, 4;
+    f64: u64, 8;
+    MuLaw: u8, 1;
+    ALaw: u8, 1;
+    Q15: u16, 2;
+    Q31: u32, 4;
+}
+
+// Justified formats store `BITS` significant bits starting at bit `SHIFT` of a `SIZE` byte
+// container. The significant bits are decoded to the most significant bits of an `I24` or `U24`.
+macro_rules! impl_justified_format {
+    ($($Format:ident: bits: $bits:expr, size: $size:expr, shift: $shift:expr;)*) => {
+        $(
+            impl<E> Format for $Format<I24, E>
+            where
+                E: Endianness,
+            {
+                type Sample = I24;
+                const SIZE: usize = $size;
+
+                #[inline]
+                fn decode(bytes: &[u8]) -> Self::Sample {
+                    let raw = E::read_uint(bytes, $size) as u32;
+                    let top = (raw << (32 - $bits - $shift)) & !(u32::MAX >> $bits);
+                    I24::new_unchecked(top as i32 >> 8)
+                }
+
+                #[inline]
+                fn encode(sample: Self::Sample, bytes: &mut [u8]) {
+                    let top = (sample.inner() << 8) as u32 & !(u32::MAX >> $bits);
+                    let raw = (top as i32 >> (32 - $bits - $shift)) as u32;
+                    E::write_uint(raw as u64, bytes, $size);
+                }
+            }
+
+            impl<E> Format for $Format<U24, E>
+            where
+                E: Endianness,
+            {
+                type Sample = U24;
+                const SIZE: usize = $size;
+
+                #[inline]
+                fn decode(bytes: &[u8]) -> Self::Sample {
+                    let raw = E::read_uint(bytes, $size) as u32;
+                    let top = (raw << (32 - $bits - $shift)) & !(u32::MAX >> $bits);
+                    U24::new_unchecked((top >> 8) as i32)
+                }
+
+                #[inline]
+                fn encode(sample: Self::Sample, bytes: &mut [u8]) {
+                    let top = ((sample.inner() as u32) << 8) & !(u32::MAX >> $bits);
+                    let raw = top >> (32 - $bits - $shift);
+                    E::write_uint(raw as u64, bytes, $size);
+                }
+            }
+        )*
+    };
+}
+
+impl_justified_format! {
+    Lsb24In32: bits: 24, size: 4, shift: 0;
+    Msb24In32: bits: 24, size: 4, shift: 8;
+    Lsb20In24: bits: 20, size: 3, shift: 0;
+    Msb20In24: bits: 20, size: 3, shift: 4;
+    Lsb20In32: bits: 20, size: 4, shift: 0;
+    Msb20In32: bits: 20, size: 4, shift: 12;
+}
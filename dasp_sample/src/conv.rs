@@ -1,5 +1,11 @@
 //! Pure functions and traits for converting between i8, i16, I24, i32, I48, i64, u8, u16, U24,
-//! u32, U48, u64, f32 and f64.
+//! u32, U48, u64, Q15, Q31, f32 and f64.
+//!
+//! With the **half** feature enabled, the half precision `f16` and `bf16` types are converted to and
+//! from all of the above via `f32` or `f64`.
+//!
+//! The G.711 companded `MuLaw` and `ALaw` types are converted to and from all of the above via
+//! 16-bit linear PCM.
 //!
 //! Each conversion function is performance focused, memory-sensitive and expects that the user has
 //! validated their input prior to the function call.
@@ -10,10 +16,18 @@
 //! The conversion functions do *not* check the range of incoming values for floating point values
 //! or any of the custom `I24`, `U24`, `I48` and `U48` types.
 //!
-//! Note that floating point conversions use the range -1.0 <= v < 1.0:
-//! `(1.0 as f64).to_sample::<i16>()` will overflow!
+//! Note that floating point conversions use the range -1.0 <= v <= 1.0, where `1.0` maps to the
+//! maximum value of the target integer type. Floating point values outside of this range saturate
+//! when converted to integer types, but are passed through unchanged when converted to other
+//! floating point types.
+//!
+//! The [**NominalRange**](./trait.NominalRange.html) trait allows for choosing an explicit policy
+//! for handling out-of-range values via the `Sample::to_sample_saturating`,
+//! `Sample::to_sample_wrapping`, `Sample::to_sample_checked` and `Sample::to_sample_debug_checked`
+//! methods.
 
-use crate::types::{I24, I48, U24, U48};
+use crate::ops;
+use crate::types::{self, ALaw, MuLaw, I24, I48, Q15, Q31, U24, U48};
 
 macro_rules! conversion_fn {
     ($Rep:ty, $s:ident to_i8 { $body:expr }) => {
@@ -113,6 +127,36 @@ macro_rules! conversion_fn {
             $body
         }
     };
+
+    ($Rep:ty, $s:ident to_q15 { $body:expr }) => {
+        #[inline]
+        pub fn to_q15($s: $Rep) -> Q15 {
+            $body
+        }
+    };
+
+    ($Rep:ty, $s:ident to_q31 { $body:expr }) => {
+        #[inline]
+        pub fn to_q31($s: $Rep) -> Q31 {
+            $body
+        }
+    };
+
+    ($Rep:ty, $s:ident to_f16 { $body:expr }) => {
+        #[cfg(feature = "half")]
+        #[inline]
+        pub fn to_f16($s: $Rep) -> half::f16 {
+            $body
+        }
+    };
+
+    ($Rep:ty, $s:ident to_bf16 { $body:expr }) => {
+        #[cfg(feature = "half")]
+        #[inline]
+        pub fn to_bf16($s: $Rep) -> half::bf16 {
+            $body
+        }
+    };
 }
 
 macro_rules! conversion_fns {
@@ -124,9 +168,10 @@ macro_rules! conversion_fns {
 }
 
 macro_rules! conversions {
-    ($T:ident, $mod_name:ident { $($rest:tt)* }) => {
+    ($T:ty, $mod_name:ident { $($rest:tt)* }) => {
         pub mod $mod_name {
-            use $crate::types::{I24, U24, I48, U48};
+            #[allow(unused_imports)]
+            use $crate::types::{I24, U24, I48, U48, Q15, Q31};
             conversion_fns!($T, $($rest)*);
         }
     };
@@ -179,6 +224,10 @@ conversions!(i8, i8 {
     s to_f64 {
         s as f64 / 128.0
     }
+    s to_q15 { Q15::from_bits(to_i16(s)) }
+    s to_q31 { Q31::from_bits(to_i32(s)) }
+    s to_f16 { half::f16::from_f32(to_f32(s)) }
+    s to_bf16 { half::bf16::from_f32(to_f32(s)) }
 });
 
 conversions!(i16, i16 {
@@ -232,6 +281,10 @@ conversions!(i16, i16 {
     s to_f64 {
         s as f64 / 32_768.0
     }
+    s to_q15 { Q15::from_bits(s) }
+    s to_q31 { Q31::from_bits(to_i32(s)) }
+    s to_f16 { half::f16::from_f32(to_f32(s)) }
+    s to_bf16 { half::bf16::from_f32(to_f32(s)) }
 });
 
 conversions!(I24, i24 {
@@ -264,6 +317,10 @@ conversions!(I24, i24 {
     s to_f64 {
         s.inner() as f64 / 8_388_608.0
     }
+    s to_q15 { Q15::from_bits(to_i16(s)) }
+    s to_q31 { Q31::from_bits(to_i32(s)) }
+    s to_f16 { half::f16::from_f32(to_f32(s)) }
+    s to_bf16 { half::bf16::from_f32(to_f32(s)) }
 });
 
 conversions!(i32, i32 {
@@ -304,6 +361,10 @@ conversions!(i32, i32 {
     s to_f64 {
         s as f64 / 2_147_483_648.0
     }
+    s to_q15 { Q15::from_bits(to_i16(s)) }
+    s to_q31 { Q31::from_bits(s) }
+    s to_f16 { half::f16::from_f32(to_f32(s)) }
+    s to_bf16 { half::bf16::from_f32(to_f32(s)) }
 });
 
 conversions!(I48, i48 {
@@ -336,6 +397,10 @@ conversions!(I48, i48 {
     s to_f64 {
         s.inner() as f64 / 140_737_488_355_328.0
     }
+    s to_q15 { Q15::from_bits(to_i16(s)) }
+    s to_q31 { Q31::from_bits(to_i32(s)) }
+    s to_f16 { half::f16::from_f64(to_f64(s)) }
+    s to_bf16 { half::bf16::from_f64(to_f64(s)) }
 });
 
 conversions!(i64, i64 {
@@ -372,6 +437,10 @@ conversions!(i64, i64 {
     s to_f64 {
         s as f64 / 9_223_372_036_854_775_808.0
     }
+    s to_q15 { Q15::from_bits(to_i16(s)) }
+    s to_q31 { Q31::from_bits(to_i32(s)) }
+    s to_f16 { half::f16::from_f64(to_f64(s)) }
+    s to_bf16 { half::bf16::from_f64(to_f64(s)) }
 });
 
 conversions!(u8, u8 {
@@ -404,6 +473,10 @@ conversions!(u8, u8 {
     s to_u64 { (s as u64) << 56 }
     s to_f32 { super::i8::to_f32(to_i8(s)) }
     s to_f64 { super::i8::to_f64(to_i8(s)) }
+    s to_q15 { Q15::from_bits(to_i16(s)) }
+    s to_q31 { Q31::from_bits(to_i32(s)) }
+    s to_f16 { half::f16::from_f32(to_f32(s)) }
+    s to_bf16 { half::bf16::from_f32(to_f32(s)) }
 });
 
 conversions!(u16, u16 {
@@ -434,6 +507,10 @@ conversions!(u16, u16 {
     s to_u64 { (s as u64) << 48 }
     s to_f32 { super::i16::to_f32(to_i16(s)) }
     s to_f64 { super::i16::to_f64(to_i16(s)) }
+    s to_q15 { Q15::from_bits(to_i16(s)) }
+    s to_q31 { Q31::from_bits(to_i32(s)) }
+    s to_f16 { half::f16::from_f32(to_f32(s)) }
+    s to_bf16 { half::bf16::from_f32(to_f32(s)) }
 });
 
 conversions!(U24, u24 {
@@ -458,6 +535,10 @@ conversions!(U24, u24 {
     s to_u64 { (s.inner() as u64) << 40 }
     s to_f32 { super::i24::to_f32(to_i24(s)) }
     s to_f64 { super::i24::to_f64(to_i24(s)) }
+    s to_q15 { Q15::from_bits(to_i16(s)) }
+    s to_q31 { Q31::from_bits(to_i32(s)) }
+    s to_f16 { half::f16::from_f32(to_f32(s)) }
+    s to_bf16 { half::bf16::from_f32(to_f32(s)) }
 });
 
 conversions!(u32, u32 {
@@ -484,6 +565,10 @@ conversions!(u32, u32 {
     s to_u64 { (s as u64) << 32 }
     s to_f32 { super::i32::to_f32(to_i32(s)) }
     s to_f64 { super::i32::to_f64(to_i32(s)) }
+    s to_q15 { Q15::from_bits(to_i16(s)) }
+    s to_q31 { Q31::from_bits(to_i32(s)) }
+    s to_f16 { half::f16::from_f32(to_f32(s)) }
+    s to_bf16 { half::bf16::from_f32(to_f32(s)) }
 });
 
 conversions!(U48, u48 {
@@ -504,6 +589,10 @@ conversions!(U48, u48 {
     s to_u64 { (s.inner() as u64) << 16 }
     s to_f32 { super::i48::to_f32(to_i48(s)) }
     s to_f64 { super::i48::to_f64(to_i48(s)) }
+    s to_q15 { Q15::from_bits(to_i16(s)) }
+    s to_q31 { Q31::from_bits(to_i32(s)) }
+    s to_f16 { half::f16::from_f64(to_f64(s)) }
+    s to_bf16 { half::bf16::from_f64(to_f64(s)) }
 });
 
 conversions!(u64, u64 {
@@ -526,16 +615,26 @@ conversions!(u64, u64 {
     s to_u48 { U48::new_unchecked((s >> 16) as i64) }
     s to_f32 { super::i64::to_f32(to_i64(s)) }
     s to_f64 { super::i64::to_f64(to_i64(s)) }
+    s to_q15 { Q15::from_bits(to_i16(s)) }
+    s to_q31 { Q31::from_bits(to_i32(s)) }
+    s to_f16 { half::f16::from_f64(to_f64(s)) }
+    s to_bf16 { half::bf16::from_f64(to_f64(s)) }
 });
 
-// The following conversions assume `-1.0 <= s < 1.0` (note that +1.0 is excluded) and will
-// overflow otherwise.
+// Values of `s` outside of `-1.0 <= s <= 1.0` saturate to the minimum and maximum of integer
+// targets and are passed through unchanged to floating point targets.
 conversions!(f32, f32 {
     s to_i8 { (s * 128.0) as i8 }
     s to_i16 { (s * 32_768.0) as i16 }
-    s to_i24 { I24::new_unchecked((s * 8_388_608.0) as i32) }
+    s to_i24 {
+        I24::new_unchecked(((s * 8_388_608.0) as i32).clamp(-8_388_608, 8_388_607))
+    }
     s to_i32 { (s * 2_147_483_648.0) as i32 }
-    s to_i48 { I48::new_unchecked((s * 140_737_488_355_328.0) as i64) }
+    s to_i48 {
+        I48::new_unchecked(
+            ((s * 140_737_488_355_328.0) as i64).clamp(-140_737_488_355_328, 140_737_488_355_327),
+        )
+    }
     s to_i64 { (s * 9_223_372_036_854_775_808.0) as i64 }
     s to_u8 { super::i8::to_u8(to_i8(s)) }
     s to_u16 { super::i16::to_u16(to_i16(s)) }
@@ -544,16 +643,26 @@ conversions!(f32, f32 {
     s to_u48 { super::i48::to_u48(to_i48(s)) }
     s to_u64 { super::i64::to_u64(to_i64(s)) }
     s to_f64 { s as f64 }
+    s to_q15 { Q15::from_bits(to_i16(s)) }
+    s to_q31 { Q31::from_bits(to_i32(s)) }
+    s to_f16 { half::f16::from_f32(s) }
+    s to_bf16 { half::bf16::from_f32(s) }
 });
 
-// The following conversions assume `-1.0 <= s < 1.0` (note that +1.0 is excluded) and will
-// overflow otherwise.
+// Values of `s` outside of `-1.0 <= s <= 1.0` saturate to the minimum and maximum of integer
+// targets and are passed through unchanged to floating point targets.
 conversions!(f64, f64 {
     s to_i8 { (s * 128.0) as i8 }
     s to_i16 { (s * 32_768.0) as i16 }
-    s to_i24 { I24::new_unchecked((s * 8_388_608.0) as i32) }
+    s to_i24 {
+        I24::new_unchecked(((s * 8_388_608.0) as i32).clamp(-8_388_608, 8_388_607))
+    }
     s to_i32 { (s * 2_147_483_648.0) as i32 }
-    s to_i48 { I48::new_unchecked((s * 140_737_488_355_328.0) as i64) }
+    s to_i48 {
+        I48::new_unchecked(
+            ((s * 140_737_488_355_328.0) as i64).clamp(-140_737_488_355_328, 140_737_488_355_327),
+        )
+    }
     s to_i64 { (s * 9_223_372_036_854_775_808.0) as i64 }
     s to_u8 { super::i8::to_u8(to_i8(s)) }
     s to_u16 { super::i16::to_u16(to_i16(s)) }
@@ -562,6 +671,98 @@ conversions!(f64, f64 {
     s to_u48 { super::i48::to_u48(to_i48(s)) }
     s to_u64 { super::i64::to_u64(to_i64(s)) }
     s to_f32 { s as f32 }
+    s to_q15 { Q15::from_bits(to_i16(s)) }
+    s to_q31 { Q31::from_bits(to_i32(s)) }
+    s to_f16 { half::f16::from_f64(s) }
+    s to_bf16 { half::bf16::from_f64(s) }
+});
+
+// Fixed-point samples share the two's complement representation of the integer type of the same
+// width.
+conversions!(Q15, q15 {
+    s to_i8 { super::i16::to_i8(s.to_bits()) }
+    s to_i16 { s.to_bits() }
+    s to_i24 { super::i16::to_i24(s.to_bits()) }
+    s to_i32 { super::i16::to_i32(s.to_bits()) }
+    s to_i48 { super::i16::to_i48(s.to_bits()) }
+    s to_i64 { super::i16::to_i64(s.to_bits()) }
+    s to_u8 { super::i16::to_u8(s.to_bits()) }
+    s to_u16 { super::i16::to_u16(s.to_bits()) }
+    s to_u24 { super::i16::to_u24(s.to_bits()) }
+    s to_u32 { super::i16::to_u32(s.to_bits()) }
+    s to_u48 { super::i16::to_u48(s.to_bits()) }
+    s to_u64 { super::i16::to_u64(s.to_bits()) }
+    s to_f32 { super::i16::to_f32(s.to_bits()) }
+    s to_f64 { super::i16::to_f64(s.to_bits()) }
+    s to_q31 { Q31::from_bits(super::i16::to_i32(s.to_bits())) }
+    s to_f16 { super::i16::to_f16(s.to_bits()) }
+    s to_bf16 { super::i16::to_bf16(s.to_bits()) }
+});
+
+conversions!(Q31, q31 {
+    s to_i8 { super::i32::to_i8(s.to_bits()) }
+    s to_i16 { super::i32::to_i16(s.to_bits()) }
+    s to_i24 { super::i32::to_i24(s.to_bits()) }
+    s to_i32 { s.to_bits() }
+    s to_i48 { super::i32::to_i48(s.to_bits()) }
+    s to_i64 { super::i32::to_i64(s.to_bits()) }
+    s to_u8 { super::i32::to_u8(s.to_bits()) }
+    s to_u16 { super::i32::to_u16(s.to_bits()) }
+    s to_u24 { super::i32::to_u24(s.to_bits()) }
+    s to_u32 { super::i32::to_u32(s.to_bits()) }
+    s to_u48 { super::i32::to_u48(s.to_bits()) }
+    s to_u64 { super::i32::to_u64(s.to_bits()) }
+    s to_f32 { super::i32::to_f32(s.to_bits()) }
+    s to_f64 { super::i32::to_f64(s.to_bits()) }
+    s to_q15 { Q15::from_bits(super::i32::to_i16(s.to_bits())) }
+    s to_f16 { super::i32::to_f16(s.to_bits()) }
+    s to_bf16 { super::i32::to_bf16(s.to_bits()) }
+});
+
+// Half precision values are widened losslessly to `f32` or `f64` before conversion, so values of `s`
+// outside of `-1.0 <= s <= 1.0` are handled as they are for those types.
+#[cfg(feature = "half")]
+conversions!(half::f16, f16 {
+    s to_i8 { super::f32::to_i8(s.to_f32()) }
+    s to_i16 { super::f32::to_i16(s.to_f32()) }
+    s to_i24 { super::f32::to_i24(s.to_f32()) }
+    s to_i32 { super::f32::to_i32(s.to_f32()) }
+    s to_i48 { super::f64::to_i48(s.to_f64()) }
+    s to_i64 { super::f64::to_i64(s.to_f64()) }
+    s to_u8 { super::f32::to_u8(s.to_f32()) }
+    s to_u16 { super::f32::to_u16(s.to_f32()) }
+    s to_u24 { super::f32::to_u24(s.to_f32()) }
+    s to_u32 { super::f32::to_u32(s.to_f32()) }
+    s to_u48 { super::f64::to_u48(s.to_f64()) }
+    s to_u64 { super::f64::to_u64(s.to_f64()) }
+    s to_f32 { s.to_f32() }
+    s to_f64 { s.to_f64() }
+    s to_q15 { super::f32::to_q15(s.to_f32()) }
+    s to_q31 { super::f32::to_q31(s.to_f32()) }
+    s to_bf16 { half::bf16::from_f32(s.to_f32()) }
+});
+
+// Half precision values are widened losslessly to `f32` or `f64` before conversion, so values of `s`
+// outside of `-1.0 <= s <= 1.0` are handled as they are for those types.
+#[cfg(feature = "half")]
+conversions!(half::bf16, bf16 {
+    s to_i8 { super::f32::to_i8(s.to_f32()) }
+    s to_i16 { super::f32::to_i16(s.to_f32()) }
+    s to_i24 { super::f32::to_i24(s.to_f32()) }
+    s to_i32 { super::f32::to_i32(s.to_f32()) }
+    s to_i48 { super::f64::to_i48(s.to_f64()) }
+    s to_i64 { super::f64::to_i64(s.to_f64()) }
+    s to_u8 { super::f32::to_u8(s.to_f32()) }
+    s to_u16 { super::f32::to_u16(s.to_f32()) }
+    s to_u24 { super::f32::to_u24(s.to_f32()) }
+    s to_u32 { super::f32::to_u32(s.to_f32()) }
+    s to_u48 { super::f64::to_u48(s.to_f64()) }
+    s to_u64 { super::f64::to_u64(s.to_f64()) }
+    s to_f32 { s.to_f32() }
+    s to_f64 { s.to_f64() }
+    s to_q15 { super::f32::to_q15(s.to_f32()) }
+    s to_q31 { super::f32::to_q31(s.to_f32()) }
+    s to_f16 { half::f16::from_f32(s.to_f32()) }
 });
 
 /// Similar to the std `From` trait, but specifically for converting between sample types.
@@ -580,7 +781,7 @@ impl<S> FromSample<S> for S {
 
 /// Implement the `FromSample` trait for the given types.
 macro_rules! impl_from_sample {
-    ($T:ty, $fn_name:ident from $({$U:ident: $Umod:ident})*) => {
+    ($T:ty, $fn_name:ident from $({$U:ty: $Umod:ident})*) => {
         $(
             impl FromSample<$U> for $T {
                 #[inline]
@@ -595,87 +796,192 @@ macro_rules! impl_from_sample {
 impl_from_sample! {i8, to_i8 from
     {i16:i16} {I24:i24} {i32:i32} {I48:i48} {i64:i64}
     {u8:u8} {u16:u16} {U24:u24} {u32:u32} {U48:u48} {u64:u64}
+    {Q15:q15} {Q31:q31}
     {f32:f32} {f64:f64}
 }
 
 impl_from_sample! {i16, to_i16 from
     {i8:i8} {I24:i24} {i32:i32} {I48:i48} {i64:i64}
     {u8:u8} {u16:u16} {U24:u24} {u32:u32} {U48:u48} {u64:u64}
+    {Q15:q15} {Q31:q31}
     {f32:f32} {f64:f64}
 }
 
 impl_from_sample! {I24, to_i24 from
     {i8:i8} {i16:i16} {i32:i32} {I48:i48} {i64:i64}
     {u8:u8} {u16:u16} {U24:u24} {u32:u32} {U48:u48} {u64:u64}
+    {Q15:q15} {Q31:q31}
     {f32:f32} {f64:f64}
 }
 
 impl_from_sample! {i32, to_i32 from
     {i8:i8} {i16:i16} {I24:i24} {I48:i48} {i64:i64}
     {u8:u8} {u16:u16} {U24:u24} {u32:u32} {U48:u48} {u64:u64}
+    {Q15:q15} {Q31:q31}
     {f32:f32} {f64:f64}
 }
 
 impl_from_sample! {I48, to_i48 from
     {i8:i8} {i16:i16} {I24:i24} {i32:i32} {i64:i64}
     {u8:u8} {u16:u16} {U24:u24} {u32:u32} {U48:u48} {u64:u64}
+    {Q15:q15} {Q31:q31}
     {f32:f32} {f64:f64}
 }
 
 impl_from_sample! {i64, to_i64 from
     {i8:i8} {i16:i16} {I24:i24} {i32:i32} {I48:i48}
     {u8:u8} {u16:u16} {U24:u24} {u32:u32} {U48:u48} {u64:u64}
+    {Q15:q15} {Q31:q31}
     {f32:f32} {f64:f64}
 }
 
 impl_from_sample! {u8, to_u8 from
     {i8:i8} {i16:i16} {I24:i24} {i32:i32} {I48:i48} {i64:i64}
     {u16:u16} {U24:u24} {u32:u32} {U48:u48} {u64:u64}
+    {Q15:q15} {Q31:q31}
     {f32:f32} {f64:f64}
 }
 
 impl_from_sample! {u16, to_u16 from
     {i8:i8} {i16:i16} {I24:i24} {i32:i32} {I48:i48} {i64:i64}
     {u8:u8} {U24:u24} {u32:u32} {U48:u48} {u64:u64}
+    {Q15:q15} {Q31:q31}
     {f32:f32} {f64:f64}
 }
 
 impl_from_sample! {U24, to_u24 from
     {i8:i8} {i16:i16} {I24:i24} {i32:i32} {I48:i48} {i64:i64}
     {u8:u8} {u16:u16} {u32:u32} {U48:u48} {u64:u64}
+    {Q15:q15} {Q31:q31}
     {f32:f32} {f64:f64}
 }
 
 impl_from_sample! {u32, to_u32 from
     {i8:i8} {i16:i16} {I24:i24} {i32:i32} {I48:i48} {i64:i64}
     {u8:u8} {u16:u16} {U24:u24} {U48:u48} {u64:u64}
+    {Q15:q15} {Q31:q31}
     {f32:f32} {f64:f64}
 }
 
 impl_from_sample! {U48, to_u48 from
     {i8:i8} {i16:i16} {I24:i24} {i32:i32} {I48:i48} {i64:i64}
     {u8:u8} {u16:u16} {U24:u24} {u32:u32} {u64:u64}
+    {Q15:q15} {Q31:q31}
     {f32:f32} {f64:f64}
 }
 
 impl_from_sample! {u64, to_u64 from
     {i8:i8} {i16:i16} {I24:i24} {i32:i32} {I48:i48} {i64:i64}
     {u8:u8} {u16:u16} {U24:u24} {u32:u32} {U48:u48}
+    {Q15:q15} {Q31:q31}
+    {f32:f32} {f64:f64}
+}
+
+impl_from_sample! {Q15, to_q15 from
+    {i8:i8} {i16:i16} {I24:i24} {i32:i32} {I48:i48} {i64:i64}
+    {u8:u8} {u16:u16} {U24:u24} {u32:u32} {U48:u48} {u64:u64}
+    {Q31:q31}
+    {f32:f32} {f64:f64}
+}
+
+impl_from_sample! {Q31, to_q31 from
+    {i8:i8} {i16:i16} {I24:i24} {i32:i32} {I48:i48} {i64:i64}
+    {u8:u8} {u16:u16} {U24:u24} {u32:u32} {U48:u48} {u64:u64}
+    {Q15:q15}
     {f32:f32} {f64:f64}
 }
 
 impl_from_sample! {f32, to_f32 from
     {i8:i8} {i16:i16} {I24:i24} {i32:i32} {I48:i48} {i64:i64}
     {u8:u8} {u16:u16} {U24:u24} {u32:u32} {U48:u48} {u64:u64}
+    {Q15:q15} {Q31:q31}
     {f64:f64}
 }
 
 impl_from_sample! {f64, to_f64 from
     {i8:i8} {i16:i16} {I24:i24} {i32:i32} {I48:i48} {i64:i64}
     {u8:u8} {u16:u16} {U24:u24} {u32:u32} {U48:u48} {u64:u64}
+    {Q15:q15} {Q31:q31}
     {f32:f32}
 }
 
+#[cfg(feature = "half")]
+impl_from_sample! {half::f16, to_f16 from
+    {i8:i8} {i16:i16} {I24:i24} {i32:i32} {I48:i48} {i64:i64}
+    {u8:u8} {u16:u16} {U24:u24} {u32:u32} {U48:u48} {u64:u64}
+    {Q15:q15} {Q31:q31}
+    {f32:f32} {f64:f64} {half::bf16:bf16}
+}
+
+#[cfg(feature = "half")]
+impl_from_sample! {half::bf16, to_bf16 from
+    {i8:i8} {i16:i16} {I24:i24} {i32:i32} {I48:i48} {i64:i64}
+    {u8:u8} {u16:u16} {U24:u24} {u32:u32} {U48:u48} {u64:u64}
+    {Q15:q15} {Q31:q31}
+    {f32:f32} {f64:f64} {half::f16:f16}
+}
+
+/// Implement the `FromSample` trait for each of the given types from the half precision types.
+#[cfg(feature = "half")]
+macro_rules! impl_from_sample_from_half {
+    ($($T:ty, $fn_name:ident;)*) => {
+        $(
+            impl_from_sample! {$T, $fn_name from {half::f16:f16} {half::bf16:bf16}}
+        )*
+    };
+}
+
+#[cfg(feature = "half")]
+impl_from_sample_from_half! {
+    i8, to_i8;
+    i16, to_i16;
+    I24, to_i24;
+    i32, to_i32;
+    I48, to_i48;
+    i64, to_i64;
+    u8, to_u8;
+    u16, to_u16;
+    U24, to_u24;
+    u32, to_u32;
+    U48, to_u48;
+    u64, to_u64;
+    Q15, to_q15;
+    Q31, to_q31;
+    f32, to_f32;
+    f64, to_f64;
+}
+
+/// Implement the `FromSample` trait in both directions between the given companded type and each
+/// of the given linear types, via 16-bit linear PCM.
+macro_rules! impl_from_sample_for_companded {
+    ($T:ident <=> $($U:ty)*) => {
+        $(
+            impl FromSample<$U> for $T {
+                #[inline]
+                fn from_sample_(s: $U) -> Self {
+                    $T::from_linear(i16::from_sample_(s))
+                }
+            }
+
+            impl FromSample<$T> for $U {
+                #[inline]
+                fn from_sample_(s: $T) -> Self {
+                    <$U>::from_sample_(s.to_linear())
+                }
+            }
+        )*
+    };
+}
+
+impl_from_sample_for_companded!(MuLaw <=>
+    i8 i16 I24 i32 I48 i64 u8 u16 U24 u32 U48 u64 Q15 Q31 f32 f64 ALaw);
+impl_from_sample_for_companded!(ALaw <=>
+    i8 i16 I24 i32 I48 i64 u8 u16 U24 u32 U48 u64 Q15 Q31 f32 f64);
+#[cfg(feature = "half")]
+impl_from_sample_for_companded!(MuLaw <=> half::f16 half::bf16);
+#[cfg(feature = "half")]
+impl_from_sample_for_companded!(ALaw <=> half::f16 half::bf16);
+
 /// Similar to the std `Into` trait, but specifically for converting between sample types.
 ///
 /// This trait has a blanket implementation for all types that implement
@@ -697,3 +1003,150 @@ where
 /// Sample types which may be converted to and from some type `S`.
 pub trait Duplex<S>: FromSample<S> + ToSample<S> {}
 impl<S, T> Duplex<S> for T where T: FromSample<S> + ToSample<S> {}
+
+/// Sample types with a nominal range, allowing for conversions with an explicit policy for
+/// handling values that lie outside of it.
+///
+/// The nominal range of the floating point sample types is `-1.0 <= s <= 1.0`, where `1.0` maps to
+/// the maximum value of the integer sample types. The nominal range of the custom `I24`, `U24`,
+/// `I48` and `U48` types is that of their respective `MIN` and `MAX` values. The primitive integer
+/// types and the companded `MuLaw` and `ALaw` types are always within their nominal range.
+///
+/// See the `Sample::to_sample_saturating`, `Sample::to_sample_wrapping`,
+/// `Sample::to_sample_checked` and `Sample::to_sample_debug_checked` methods.
+pub trait NominalRange: crate::Sample {
+    /// Whether or not the sample lies within its nominal range. `NaN` never does.
+    fn is_in_range(self) -> bool;
+    /// Clamp the sample to its nominal range. `NaN` becomes `EQUILIBRIUM`.
+    fn saturate(self) -> Self;
+    /// Wrap the sample around its nominal range, as two's complement integer overflow would.
+    /// `NaN` and infinities become `EQUILIBRIUM`.
+    fn wrap(self) -> Self;
+}
+
+macro_rules! impl_nominal_range_identity {
+    ($($T:ty)*) => {
+        $(
+            impl NominalRange for $T {
+                #[inline]
+                fn is_in_range(self) -> bool {
+                    true
+                }
+                #[inline]
+                fn saturate(self) -> Self {
+                    self
+                }
+                #[inline]
+                fn wrap(self) -> Self {
+                    self
+                }
+            }
+        )*
+    };
+}
+
+impl_nominal_range_identity!(i8 i16 i32 i64 u8 u16 u32 u64 MuLaw ALaw Q15 Q31);
+
+macro_rules! impl_nominal_range_for_custom_int {
+    ($($T:ident: $mod_name:ident, $bits:expr;)*) => {
+        $(
+            impl NominalRange for $T {
+                #[inline]
+                fn is_in_range(self) -> bool {
+                    let s = self.inner();
+                    s >= types::$mod_name::MIN.inner() && s <= types::$mod_name::MAX.inner()
+                }
+                #[inline]
+                fn saturate(self) -> Self {
+                    let s = self.inner();
+                    if s < types::$mod_name::MIN.inner() {
+                        types::$mod_name::MIN
+                    } else if s > types::$mod_name::MAX.inner() {
+                        types::$mod_name::MAX
+                    } else {
+                        self
+                    }
+                }
+                #[inline]
+                fn wrap(self) -> Self {
+                    // Wrap the offset from `MIN` to the type's bit width.
+                    let min = types::$mod_name::MIN.inner();
+                    let offset = self.inner().wrapping_sub(min) & ((1 << $bits) - 1);
+                    $T::new_unchecked(offset + min)
+                }
+            }
+        )*
+    };
+}
+
+impl_nominal_range_for_custom_int! {
+    I24: i24, 24;
+    U24: u24, 24;
+    I48: i48, 48;
+    U48: u48, 48;
+}
+
+macro_rules! impl_nominal_range_for_float {
+    ($($T:ident)*) => {
+        $(
+            impl NominalRange for $T {
+                #[inline]
+                fn is_in_range(self) -> bool {
+                    (-1.0..=1.0).contains(&self)
+                }
+                #[inline]
+                fn saturate(self) -> Self {
+                    if self < -1.0 {
+                        -1.0
+                    } else if self > 1.0 {
+                        1.0
+                    } else if self.is_nan() {
+                        0.0
+                    } else {
+                        self
+                    }
+                }
+                #[inline]
+                fn wrap(self) -> Self {
+                    if self.is_in_range() {
+                        return self;
+                    }
+                    let s = self as f64;
+                    let wrapped = s - 2.0 * ops::f64::floor((s + 1.0) * 0.5);
+                    if wrapped.is_nan() {
+                        0.0
+                    } else {
+                        wrapped as $T
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_nominal_range_for_float!(f32 f64);
+
+#[cfg(feature = "half")]
+macro_rules! impl_nominal_range_for_half {
+    ($($T:ty)*) => {
+        $(
+            impl NominalRange for $T {
+                #[inline]
+                fn is_in_range(self) -> bool {
+                    self.to_f32().is_in_range()
+                }
+                #[inline]
+                fn saturate(self) -> Self {
+                    <$T>::from_f32(self.to_f32().saturate())
+                }
+                #[inline]
+                fn wrap(self) -> Self {
+                    <$T>::from_f32(self.to_f32().wrap())
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "half")]
+impl_nominal_range_for_half!(half::f16 half::bf16);
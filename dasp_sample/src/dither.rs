@@ -0,0 +1,364 @@
+//! Dithering and noise-shaped requantization for reducing the bit-depth of samples.
+//!
+//! Converting a sample to a type of lower resolution via `Sample::to_sample` simply discards the
+//! bits that can no longer be represented. The resulting quantization error is correlated with
+//! the signal and is heard as distortion, particularly in quiet passages.
+//!
+//! The [**Ditherer**](./struct.Ditherer.html) adds a small amount of random noise to each sample
+//! before rounding it to the target resolution, decorrelating the error from the signal. The error
+//! may optionally be fed back through a [**NoiseShaping**](./enum.NoiseShaping.html) filter in
+//! order to move its energy towards the less audible upper end of the spectrum.
+//!
+//! All randomness is drawn from a small, seedable [**Rng**](./struct.Rng.html) so that the output
+//! of a **Ditherer** is deterministic for a given seed.
+
+use crate::types::{I24, I48, Q15, Q31, U24, U48};
+use crate::{ops, FromSample, Sample};
+
+/// The maximum number of error feedback coefficients used by any **NoiseShaping** filter.
+pub const MAX_SHAPING_ORDER: usize = 9;
+
+/// The magnitude (in LSBs) to which the error fed back through a noise shaping filter is limited.
+///
+/// The error produced by rounding a dithered sample never exceeds 1.5 LSB. Larger errors only
+/// occur when the target type clips, in which case limiting the error prevents the feedback loop
+/// from becoming unstable.
+const MAX_SHAPED_ERROR: f64 = 2.0;
+
+/// A small, fast, seedable pseudo-random number generator used to produce dither noise.
+///
+/// This is an *xorshift64\** generator whose state is initialised from the seed via *splitmix64*.
+/// It is not suitable for cryptographic purposes.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Rng {
+    state: u64,
+}
+
+/// The probability density function of the noise added to each sample before rounding.
+///
+/// Values are in units of the target type's least significant bit (LSB).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Dither {
+    /// No dither is applied. Samples are simply rounded to the nearest step.
+    None,
+    /// Rectangular PDF noise in the range `[-0.5, 0.5)` LSB.
+    ///
+    /// Removes distortion, but leaves the noise power modulated by the signal.
+    Rpdf,
+    /// Triangular PDF noise in the range `(-1.0, 1.0)` LSB.
+    ///
+    /// The sum of two independent **Rpdf** values. Renders both the mean and the power of the
+    /// quantization error independent of the signal.
+    Tpdf,
+    /// High-pass triangular PDF noise in the range `(-1.0, 1.0)` LSB.
+    ///
+    /// The difference between successive **Rpdf** values. Has the same amplitude distribution as
+    /// **Tpdf** but with its power concentrated in the upper end of the spectrum, and requires
+    /// only a single random value per sample.
+    HighPassTpdf,
+}
+
+/// The filter through which the quantization error is fed back when requantizing.
+///
+/// Given the coefficients `c` of the filter, the spectrum of the total error is shaped by the
+/// noise transfer function `1 - c[0]z^-1 - c[1]z^-2 - ...`.
+///
+/// The weighted curves are designed for a sample rate of 44.1 or 48 kHz and increase the total
+/// power of the error in exchange for reducing it where the ear is most sensitive.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum NoiseShaping {
+    /// The quantization error is not fed back. The error spectrum is that of the dither.
+    None,
+    /// A first order high-pass curve, `1 - z^-1`.
+    FirstOrder,
+    /// A second order high-pass curve, `(1 - z^-1)^2`.
+    SecondOrder,
+    /// Lipshitz's 5-tap E-weighted curve.
+    Lipshitz,
+    /// Wannamaker's 3-tap F-weighted curve.
+    Wannamaker3,
+    /// Wannamaker's 9-tap F-weighted curve.
+    Wannamaker9,
+}
+
+/// **Sample** types of a known, fixed resolution to which a **Ditherer** may requantize.
+pub trait Quantize: Sample + FromSample<f64> {
+    /// The number of bits used to represent the full range of the sample.
+    const BITS: u32;
+}
+
+/// Requantizes samples to a **Quantize** type with dither and optional noise shaping.
+///
+/// A **Ditherer** holds the error history of a single channel. Multi-channel signals should use
+/// a separate **Ditherer** per channel.
+///
+/// # Example
+///
+/// ```rust
+/// use dasp_sample::Sample;
+/// use dasp_sample::dither::{Dither, Ditherer, NoiseShaping};
+///
+/// fn main() {
+///     let mut ditherer = Ditherer::new(Dither::Tpdf, NoiseShaping::None, 0);
+///     let s: i16 = ditherer.quantize(0.25f32);
+///     assert!((s - 8_192).abs() <= 1);
+///     let s = 0.25f32.to_sample_dithered::<u8>(&mut ditherer);
+///     assert!((s as i16 - 160).abs() <= 1);
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Ditherer {
+    dither: Dither,
+    shaping: NoiseShaping,
+    rng: Rng,
+    errors: [f64; MAX_SHAPING_ORDER],
+    prev_noise: f64,
+}
+
+impl Rng {
+    /// Construct a new generator from the given seed.
+    ///
+    /// Generators constructed from the same seed always produce the same sequence.
+    pub fn new(seed: u64) -> Self {
+        let mut z = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^= z >> 31;
+        // The xorshift state must never be zero.
+        let state = if z == 0 { 0x9E37_79B9_7F4A_7C15 } else { z };
+        Rng { state }
+    }
+
+    /// Yields the next pseudo-random `u64`.
+    #[inline]
+    pub fn next_u64(&mut self) -> u64 {
+        let mut x = self.state;
+        x ^= x >> 12;
+        x ^= x << 25;
+        x ^= x >> 27;
+        self.state = x;
+        x.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    /// Yields the next pseudo-random `f64` uniformly distributed within the range `[0.0, 1.0)`.
+    #[inline]
+    pub fn next_f64(&mut self) -> f64 {
+        const SCALE: f64 = 1.0 / (1u64 << 53) as f64;
+        (self.next_u64() >> 11) as f64 * SCALE
+    }
+}
+
+impl Dither {
+    /// Yields the next dither value in LSBs.
+    ///
+    /// `prev` holds the state of a single channel between calls and is only used by
+    /// **HighPassTpdf**. It should be initialised to `0.0`.
+    #[inline]
+    pub fn next_noise(self, rng: &mut Rng, prev: &mut f64) -> f64 {
+        match self {
+            Dither::None => 0.0,
+            Dither::Rpdf => rng.next_f64() - 0.5,
+            Dither::Tpdf => rng.next_f64() - rng.next_f64(),
+            Dither::HighPassTpdf => {
+                let r = rng.next_f64() - 0.5;
+                let noise = r - *prev;
+                *prev = r;
+                noise
+            }
+        }
+    }
+}
+
+impl NoiseShaping {
+    /// The error feedback coefficients of the filter, starting with the most recent error.
+    ///
+    /// The returned slice never contains more than `MAX_SHAPING_ORDER` coefficients.
+    pub fn coefficients(self) -> &'static [f64] {
+        match self {
+            NoiseShaping::None => &[],
+            NoiseShaping::FirstOrder => &[1.0],
+            NoiseShaping::SecondOrder => &[2.0, -1.0],
+            NoiseShaping::Lipshitz => &[2.033, -2.165, 1.959, -1.590, 0.6149],
+            NoiseShaping::Wannamaker3 => &[1.623, -0.982, 0.109],
+            NoiseShaping::Wannamaker9 => &[
+                2.412, -3.370, 3.937, -4.174, 3.353, -2.205, 1.281, -0.569, 0.0847,
+            ],
+        }
+    }
+
+    /// Sum the given error history, most recent first, weighted by the filter's coefficients.
+    #[inline]
+    pub fn feedback<I>(self, errors: I) -> f64
+    where
+        I: IntoIterator<Item = f64>,
+    {
+        self.coefficients()
+            .iter()
+            .zip(errors)
+            .map(|(c, e)| c * e)
+            .sum()
+    }
+}
+
+impl Ditherer {
+    /// Construct a new **Ditherer** from the given dither, noise shaping filter and seed.
+    pub fn new(dither: Dither, shaping: NoiseShaping, seed: u64) -> Self {
+        Ditherer {
+            dither,
+            shaping,
+            rng: Rng::new(seed),
+            errors: [0.0; MAX_SHAPING_ORDER],
+            prev_noise: 0.0,
+        }
+    }
+
+    /// The kind of dither applied by the **Ditherer**.
+    pub fn dither(&self) -> Dither {
+        self.dither
+    }
+
+    /// The noise shaping filter applied by the **Ditherer**.
+    pub fn shaping(&self) -> NoiseShaping {
+        self.shaping
+    }
+
+    /// Yields the next dither value in LSBs using the **Ditherer**'s random number generator.
+    ///
+    /// See `Dither::next_noise` for details on `prev`.
+    #[inline]
+    pub fn next_noise(&mut self, prev: &mut f64) -> f64 {
+        self.dither.next_noise(&mut self.rng, prev)
+    }
+
+    /// Requantize the given sample to the resolution of `T`.
+    #[inline]
+    pub fn quantize<S, T>(&mut self, sample: S) -> T
+    where
+        S: Sample,
+        T: Quantize,
+    {
+        let x = sample.to_float_sample().to_sample::<f64>();
+        let feedback = self.shaping.feedback(self.errors.iter().cloned());
+        let mut prev_noise = self.prev_noise;
+        let noise = self.next_noise(&mut prev_noise);
+        self.prev_noise = prev_noise;
+        let (quantized, error) = quantize_shaped(x, noise, feedback);
+        self.errors.rotate_right(1);
+        self.errors[0] = error;
+        quantized
+    }
+
+    /// Clear the error history, as though the **Ditherer** had not yet processed any samples.
+    ///
+    /// The state of the random number generator is left untouched.
+    pub fn reset(&mut self) {
+        self.errors = [0.0; MAX_SHAPING_ORDER];
+        self.prev_noise = 0.0;
+    }
+}
+
+/// The size of a single step of `T`'s resolution within the `[-1.0, 1.0)` range.
+#[inline]
+pub fn lsb<T>() -> f64
+where
+    T: Quantize,
+{
+    1.0 / steps::<T>()
+}
+
+/// Round the given `f64` sample to the nearest step of `T`'s resolution, clipping it to `T`'s
+/// range.
+///
+/// Unlike `Sample::to_sample`, which truncates, this introduces no DC offset.
+///
+/// # Example
+///
+/// ```rust
+/// use dasp_sample::dither;
+///
+/// fn main() {
+///     assert_eq!(dither::quantize::<i8>(0.5), 64);
+///     assert_eq!(dither::quantize::<i8>(-0.0039), 0);
+///     assert_eq!(dither::quantize::<i8>(2.0), 127);
+///     assert_eq!(dither::quantize::<u8>(-2.0), 0);
+/// }
+/// ```
+#[inline]
+pub fn quantize<T>(x: f64) -> T
+where
+    T: Quantize,
+{
+    quantize_f64::<T>(x).to_sample()
+}
+
+/// A single step of error feedback requantization of the `f64` sample `x` to `T`.
+///
+/// `noise` is the dither value in LSBs and `feedback` is the sum of past errors weighted by the
+/// noise shaping filter (see `NoiseShaping::feedback`).
+///
+/// Returns the requantized sample along with the error to be fed back for the next sample. The
+/// error is `0.0` if `x` is not finite.
+#[inline]
+pub fn quantize_shaped<T>(x: f64, noise: f64, feedback: f64) -> (T, f64)
+where
+    T: Quantize,
+{
+    let lsb = lsb::<T>();
+    let target = x - feedback;
+    let quantized = quantize_f64::<T>(target + noise * lsb);
+    let max_error = MAX_SHAPED_ERROR * lsb;
+    let error = quantized - target;
+    // A non-finite input would otherwise be fed back into every following sample.
+    let error = if !error.is_finite() {
+        0.0
+    } else if error > max_error {
+        max_error
+    } else if error < -max_error {
+        -max_error
+    } else {
+        error
+    };
+    (quantized.to_sample(), error)
+}
+
+#[inline]
+fn steps<T>() -> f64
+where
+    T: Quantize,
+{
+    (1u64 << (T::BITS - 1)) as f64
+}
+
+/// Round `x` to the nearest step of `T`'s resolution, returning the result as an `f64`.
+#[inline]
+fn quantize_f64<T>(x: f64) -> f64
+where
+    T: Quantize,
+{
+    let steps = steps::<T>();
+    let q = ops::f64::floor(x * steps + 0.5);
+    let q = if q < -steps {
+        -steps
+    } else if q > steps - 1.0 {
+        steps - 1.0
+    } else {
+        q
+    };
+    q / steps
+}
+
+macro_rules! impl_quantize {
+    ($($T:ty: $BITS:expr),*) => {
+        $(
+            impl Quantize for $T {
+                const BITS: u32 = $BITS;
+            }
+        )*
+    };
+}
+
+impl_quantize! {
+    i8: 8, i16: 16, I24: 24, i32: 32, I48: 48, i64: 64,
+    u8: 8, u16: 16, U24: 24, u32: 32, U48: 48, u64: 64,
+    Q15: 16, Q31: 32
+}
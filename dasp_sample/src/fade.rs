@@ -0,0 +1,176 @@
+//! Curves for fades, gain ramps and crossfades.
+//!
+//! A [**Curve**](./enum.Curve.html) describes the shape of a rising gain between `0.0` and `1.0`,
+//! while a [**Ramp**](./struct.Ramp.html) applies a curve between any two gains over a fixed
+//! number of frames. Both are shared by the fading functions of `dasp_slice` and the fading
+//! adaptors of `dasp_signal`, so that a fade applied to a slice and the same fade applied to a
+//! signal yield identical results.
+
+use crate::{ops, IntoAmp};
+
+/// The shape of a fade from silence to unity gain.
+///
+/// Falling ramps (e.g. fade-outs) mirror the curve in time, so that a fade-out is the time
+/// reversal of the equivalent fade-in.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Curve {
+    /// Gain rises at a constant rate.
+    ///
+    /// Crossfading with this curve preserves the amplitude of correlated signals.
+    Linear,
+    /// Gain rises exponentially across a 60 dB range, i.e. roughly linearly in decibels, offset
+    /// so that the curve starts at silence.
+    Exponential,
+    /// Gain rises slowly at the start and end of the curve and quickly in the middle, following
+    /// `3t² - 2t³`.
+    ///
+    /// Crossfading with this curve preserves the amplitude of correlated signals.
+    SCurve,
+    /// Gain follows `√t`.
+    ///
+    /// Crossfading with this curve preserves the power of uncorrelated signals.
+    EqualPower,
+}
+
+/// A gain ramp following a **Curve** from one gain to another over a number of frames.
+///
+/// The first frame of the ramp has the `from` gain and the last frame has the `to` gain. A ramp of
+/// a single frame has only the `from` gain, reaching the `to` gain on the following frame.
+///
+/// # Example
+///
+/// ```rust
+/// use dasp_sample::fade::{Curve, Ramp};
+///
+/// fn main() {
+///     let ramp = Ramp::new(Curve::Linear, 1.0, 0.0, 5);
+///     assert_eq!(ramp.gain_at(0), 1.0);
+///     assert_eq!(ramp.gain_at(1), 0.75);
+///     assert_eq!(ramp.gain_at(4), 0.0);
+///     assert_eq!(ramp.gain_at(9), 0.0);
+/// }
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Ramp {
+    curve: Curve,
+    from: f64,
+    to: f64,
+    len: usize,
+}
+
+// `ln(1000)`, i.e. the exponent of a 60 dB rise.
+const EXPONENTIAL_RANGE: f64 = 6.907_755_278_982_137;
+
+impl Curve {
+    /// The gain of the curve at the given position between `0.0` and `1.0`.
+    ///
+    /// Every curve starts at `0.0` and ends at `1.0`. `t` is clamped to the range `0.0..=1.0`.
+    ///
+    /// ```rust
+    /// use dasp_sample::fade::Curve;
+    ///
+    /// fn main() {
+    ///     assert_eq!(Curve::SCurve.gain(0.5), 0.5);
+    ///     assert_eq!(Curve::EqualPower.gain(0.25), 0.5);
+    ///     assert_eq!(Curve::Exponential.gain(1.0), 1.0);
+    ///     assert_eq!(Curve::Linear.gain(2.0), 1.0);
+    /// }
+    /// ```
+    #[inline]
+    pub fn gain(self, t: f64) -> f64 {
+        let t = if t > 1.0 {
+            1.0
+        } else if t > 0.0 {
+            t
+        } else {
+            0.0
+        };
+        match self {
+            Curve::Linear => t,
+            Curve::Exponential => {
+                if t >= 1.0 {
+                    1.0
+                } else {
+                    (ops::f64::exp(EXPONENTIAL_RANGE * t) - 1.0) / 999.0
+                }
+            }
+            Curve::SCurve => t * t * (3.0 - 2.0 * t),
+            Curve::EqualPower => ops::f64::sqrt(t),
+        }
+    }
+
+    /// The gain of a ramp following the curve from `from` to `to`, at the given position between
+    /// `0.0` and `1.0`.
+    ///
+    /// If `to` is less than `from`, the curve is mirrored in time.
+    #[inline]
+    pub fn ramp(self, from: f64, to: f64, t: f64) -> f64 {
+        if to >= from {
+            from + (to - from) * self.gain(t)
+        } else {
+            to + (from - to) * self.gain(1.0 - t)
+        }
+    }
+}
+
+impl Ramp {
+    /// A ramp following the given curve from the `from` gain to the `to` gain over `len` frames.
+    ///
+    /// Either gain may be given as a raw `f64`, a `Gain` or `Decibels`.
+    pub fn new<A, B>(curve: Curve, from: A, to: B, len: usize) -> Self
+    where
+        A: IntoAmp<f64>,
+        B: IntoAmp<f64>,
+    {
+        Ramp {
+            curve,
+            from: from.into_amp(),
+            to: to.into_amp(),
+            len,
+        }
+    }
+
+    /// A ramp from silence to unity gain over `len` frames.
+    pub fn fade_in(curve: Curve, len: usize) -> Self {
+        Self::new(curve, 0.0, 1.0, len)
+    }
+
+    /// A ramp from unity gain to silence over `len` frames.
+    pub fn fade_out(curve: Curve, len: usize) -> Self {
+        Self::new(curve, 1.0, 0.0, len)
+    }
+
+    /// The curve followed by the ramp.
+    pub fn curve(&self) -> Curve {
+        self.curve
+    }
+
+    /// The number of frames over which the gain changes.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether or not the ramp is zero frames long, in which case every frame has the `to` gain.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The gain of the frame at the given index.
+    ///
+    /// Frames beyond the end of the ramp have the `to` gain.
+    #[inline]
+    pub fn gain_at(&self, idx: usize) -> f64 {
+        if idx >= self.len {
+            return self.to;
+        }
+        // A single frame ramp cannot reach both gains, so it starts at `from` like any other.
+        if self.len == 1 {
+            return self.from;
+        }
+        if idx == self.len - 1 {
+            return self.to;
+        }
+        let t = idx as f64 / (self.len - 1) as f64;
+        self.curve.ramp(self.from, self.to, t)
+    }
+}
@@ -0,0 +1,179 @@
+//! Describing sample types at runtime.
+//!
+//! The [**SampleFormat**](./enum.SampleFormat.html) enum has a variant for every **Sample** type
+//! provided by this crate. This is useful when the format of some audio data is only known at
+//! runtime, e.g. when reading the header of a file or opening an audio device.
+//!
+//! Use the [**KnownFormat**](./trait.KnownFormat.html) trait to get the **SampleFormat** of a
+//! **Sample** type, and the [**FromAnyFormat**](./trait.FromAnyFormat.html) trait to remain
+//! generic over **Sample** types that may be converted from a sample of any **SampleFormat**.
+
+use crate::types::{ALaw, MuLaw, I24, I48, Q15, Q31, U24, U48};
+use crate::{FromSample, Sample};
+
+macro_rules! sample_formats {
+    ($(
+        $(#[doc = $doc:expr])*
+        $(#[cfg($cfg:meta)])*
+        $Variant:ident: $T:ty, $bits:expr, $kind:ident;
+    )*) => {
+        /// The format of a sample, for every **Sample** type provided by this crate.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// use dasp_sample::format::{KnownFormat, SampleFormat};
+        /// use dasp_sample::I24;
+        ///
+        /// fn main() {
+        ///     assert_eq!(SampleFormat::of::<i16>(), SampleFormat::I16);
+        ///     assert_eq!(I24::FORMAT.bits(), 24);
+        ///     assert!(SampleFormat::F32.is_float());
+        ///     assert!(!SampleFormat::U8.is_signed());
+        /// }
+        /// ```
+        #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+        pub enum SampleFormat {
+            $(
+                $(#[doc = $doc])*
+                $(#[cfg($cfg)])*
+                $Variant,
+            )*
+        }
+
+        impl SampleFormat {
+            /// The number of bits used to represent a sample of this format.
+            pub fn bits(self) -> u32 {
+                match self {
+                    $(
+                        $(#[cfg($cfg)])*
+                        SampleFormat::$Variant => $bits,
+                    )*
+                }
+            }
+
+            /// Whether or not samples of this format are represented as floating point numbers.
+            pub fn is_float(self) -> bool {
+                match self {
+                    $(
+                        $(#[cfg($cfg)])*
+                        SampleFormat::$Variant => sample_formats!(@is_float $kind),
+                    )*
+                }
+            }
+
+            /// Whether or not samples of this format have their equilibrium at zero.
+            pub fn is_signed(self) -> bool {
+                match self {
+                    $(
+                        $(#[cfg($cfg)])*
+                        SampleFormat::$Variant => sample_formats!(@is_signed $kind),
+                    )*
+                }
+            }
+        }
+
+        $(
+            $(#[cfg($cfg)])*
+            impl KnownFormat for $T {
+                const FORMAT: SampleFormat = SampleFormat::$Variant;
+            }
+        )*
+
+        sample_formats!(@from_any_format [] $($(#[cfg($cfg)])* $T,)*);
+    };
+    // Accumulate a `FromSample` bound for every format that is always available. Formats behind
+    // a `cfg` are skipped so that enabling a feature never adds bounds to `FromAnyFormat`.
+    (@from_any_format [$($Bound:ty),*] $(#[cfg($cfg:meta)])+ $T:ty, $($rest:tt)*) => {
+        sample_formats!(@from_any_format [$($Bound),*] $($rest)*);
+    };
+    (@from_any_format [$($Bound:ty),*] $T:ty, $($rest:tt)*) => {
+        sample_formats!(@from_any_format [$($Bound,)* $T] $($rest)*);
+    };
+    (@from_any_format [$($Bound:ty),*]) => {
+        /// **Sample** types that may be converted from a sample of any **SampleFormat**.
+        ///
+        /// This trait is implemented for all types that implement **FromSample** for every
+        /// **Sample** type provided by this crate without optional features. Its bounds do not
+        /// change with the enabled features, so formats behind a feature (e.g. `f16` and `bf16`)
+        /// must be converted via one of the always available formats, such as `f32`.
+        pub trait FromAnyFormat: Sample $(+ FromSample<$Bound>)* {}
+
+        impl<S> FromAnyFormat for S where S: Sample $(+ FromSample<$Bound>)* {}
+    };
+    (@is_float float) => { true };
+    (@is_float $kind:ident) => { false };
+    (@is_signed unsigned) => { false };
+    (@is_signed $kind:ident) => { true };
+}
+
+sample_formats! {
+    /// `i8`
+    I8: i8, 8, signed;
+    /// `i16`
+    I16: i16, 16, signed;
+    /// [**I24**](../types/i24/struct.I24.html)
+    I24: I24, 24, signed;
+    /// `i32`
+    I32: i32, 32, signed;
+    /// [**I48**](../types/i48/struct.I48.html)
+    I48: I48, 48, signed;
+    /// `i64`
+    I64: i64, 64, signed;
+    /// `u8`
+    U8: u8, 8, unsigned;
+    /// `u16`
+    U16: u16, 16, unsigned;
+    /// [**U24**](../types/u24/struct.U24.html)
+    U24: U24, 24, unsigned;
+    /// `u32`
+    U32: u32, 32, unsigned;
+    /// [**U48**](../types/u48/struct.U48.html)
+    U48: U48, 48, unsigned;
+    /// `u64`
+    U64: u64, 64, unsigned;
+    /// `f32`
+    F32: f32, 32, float;
+    /// `f64`
+    F64: f64, 64, float;
+    /// [**MuLaw**](../types/mu_law/struct.MuLaw.html)
+    MuLaw: MuLaw, 8, signed;
+    /// [**ALaw**](../types/a_law/struct.ALaw.html)
+    ALaw: ALaw, 8, signed;
+    /// [**Q15**](../types/q15/struct.Q15.html)
+    Q15: Q15, 16, signed;
+    /// [**Q31**](../types/q31/struct.Q31.html)
+    Q31: Q31, 32, signed;
+    /// `f16`
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_sample`, this item requires the **half** feature to be enabled.
+    /// - When using `dasp`, this item requires the **sample-half** feature to be enabled.
+    #[cfg(feature = "half")]
+    F16: half::f16, 16, float;
+    /// `bf16`
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_sample`, this item requires the **half** feature to be enabled.
+    /// - When using `dasp`, this item requires the **sample-half** feature to be enabled.
+    #[cfg(feature = "half")]
+    BF16: half::bf16, 16, float;
+}
+
+impl SampleFormat {
+    /// The format of the **Sample** type `S`.
+    pub fn of<S>() -> Self
+    where
+        S: KnownFormat,
+    {
+        S::FORMAT
+    }
+}
+
+/// **Sample** types whose **SampleFormat** is known.
+pub trait KnownFormat: Sample {
+    /// The format of the **Sample** type.
+    const FORMAT: SampleFormat;
+}
@@ -0,0 +1,227 @@
+//! Typed units for the amplitude of a signal.
+//!
+//! [**Gain**](./struct.Gain.html) is a linear amplitude factor, while
+//! [**Decibels**](./struct.Decibels.html) is the same factor on a logarithmic scale. Both may be
+//! passed to the amplitude scaling methods of `dasp_frame`, `dasp_slice` and `dasp_signal` in
+//! place of a raw **FloatSample** via the [**IntoAmp**](./trait.IntoAmp.html) trait.
+//!
+//! When measuring the level of a **Sample**, both are relative to the full scale of the sample
+//! type, i.e. **Decibels** describe dBFS.
+
+use crate::{ops, FloatSample, Sample};
+
+/// A linear amplitude factor.
+///
+/// A **Gain** of `1.0` leaves a signal unchanged, `0.5` halves its amplitude and `0.0` silences
+/// it. A negative **Gain** also inverts the phase of the signal.
+///
+/// # Example
+///
+/// ```rust
+/// use dasp_sample::{Decibels, Gain};
+///
+/// fn main() {
+///     assert!((Gain::from(Decibels(20.0)).0 - 10.0).abs() < 1e-12);
+///     assert_eq!(Gain(0.5) * Gain(0.5), Gain(0.25));
+///     assert_eq!(Gain::from_sample(-16_384i16), Gain(0.5));
+/// }
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+pub struct Gain(pub f64);
+
+/// An amplitude factor on a logarithmic scale, equal to `20 * log10(gain)`.
+///
+/// A level of `0.0` dB leaves a signal unchanged, roughly `-6.02` dB halves its amplitude and
+/// negative infinity silences it.
+///
+/// # Example
+///
+/// ```rust
+/// use dasp_sample::{Decibels, Gain};
+///
+/// fn main() {
+///     assert!((Decibels::from(Gain(10.0)).0 - 20.0).abs() < 1e-12);
+///     assert_eq!(Decibels(-3.0) + Decibels(-3.0), Decibels(-6.0));
+///     assert_eq!(Decibels::from_sample(1.0f32), Decibels::UNITY);
+///     assert_eq!(Decibels::from_sample(0u8), Decibels::UNITY);
+///     assert_eq!(Decibels::from_sample(0.0f32), Decibels::SILENCE);
+/// }
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+pub struct Decibels(pub f64);
+
+/// Types that may be used as an amplitude factor of the **FloatSample** type `F`.
+///
+/// This is implemented for `F` itself along with **Gain** and **Decibels**, allowing the
+/// amplitude scaling methods throughout dasp to accept any of them.
+pub trait IntoAmp<F>: Copy
+where
+    F: FloatSample,
+{
+    /// Convert `self` to a linear amplitude factor of type `F`.
+    fn into_amp(self) -> F;
+}
+
+impl Gain {
+    /// Leaves the amplitude of a signal unchanged.
+    pub const UNITY: Self = Gain(1.0);
+    /// Silences a signal.
+    pub const SILENCE: Self = Gain(0.0);
+
+    /// The linear gain equivalent to the given level.
+    #[inline]
+    pub fn from_decibels(db: Decibels) -> Self {
+        Gain(ops::f64::exp(db.0 * (core::f64::consts::LN_10 / 20.0)))
+    }
+
+    /// The level equivalent to `self`.
+    ///
+    /// The level of a negative **Gain** is that of its magnitude.
+    #[inline]
+    pub fn to_decibels(self) -> Decibels {
+        Decibels(20.0 * ops::f64::log10(self.0.abs()))
+    }
+
+    /// The magnitude of the given sample relative to the full scale of its type.
+    #[inline]
+    pub fn from_sample<S>(sample: S) -> Self
+    where
+        S: Sample,
+    {
+        Gain(sample.to_float_sample().to_sample::<f64>().abs())
+    }
+
+    /// The positive sample of type `S` whose magnitude relative to full scale is `self`.
+    ///
+    /// Magnitudes beyond the range of `S` are clipped by the sample conversion.
+    #[inline]
+    pub fn to_sample<S>(self) -> S
+    where
+        S: Sample,
+    {
+        self.0.abs().to_sample::<S::Float>().to_sample()
+    }
+}
+
+impl Decibels {
+    /// Leaves the amplitude of a signal unchanged.
+    pub const UNITY: Self = Decibels(0.0);
+    /// Silences a signal.
+    pub const SILENCE: Self = Decibels(f64::NEG_INFINITY);
+
+    /// The level equivalent to the given linear gain.
+    #[inline]
+    pub fn from_gain(gain: Gain) -> Self {
+        gain.to_decibels()
+    }
+
+    /// The linear gain equivalent to `self`.
+    #[inline]
+    pub fn to_gain(self) -> Gain {
+        Gain::from_decibels(self)
+    }
+
+    /// The level of the given sample in dBFS, i.e. relative to the full scale of its type.
+    #[inline]
+    pub fn from_sample<S>(sample: S) -> Self
+    where
+        S: Sample,
+    {
+        Gain::from_sample(sample).to_decibels()
+    }
+
+    /// The positive sample of type `S` whose level is `self` dBFS.
+    ///
+    /// Levels beyond the range of `S` are clipped by the sample conversion.
+    #[inline]
+    pub fn to_sample<S>(self) -> S
+    where
+        S: Sample,
+    {
+        self.to_gain().to_sample()
+    }
+}
+
+impl From<Decibels> for Gain {
+    #[inline]
+    fn from(db: Decibels) -> Self {
+        Gain::from_decibels(db)
+    }
+}
+
+impl From<Gain> for Decibels {
+    #[inline]
+    fn from(gain: Gain) -> Self {
+        gain.to_decibels()
+    }
+}
+
+impl core::ops::Mul for Gain {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        Gain(self.0 * other.0)
+    }
+}
+
+impl core::ops::Div for Gain {
+    type Output = Self;
+    #[inline]
+    fn div(self, other: Self) -> Self {
+        Gain(self.0 / other.0)
+    }
+}
+
+impl core::ops::Add for Decibels {
+    type Output = Self;
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        Decibels(self.0 + other.0)
+    }
+}
+
+impl core::ops::Sub for Decibels {
+    type Output = Self;
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        Decibels(self.0 - other.0)
+    }
+}
+
+impl core::ops::Neg for Decibels {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Decibels(-self.0)
+    }
+}
+
+impl<F> IntoAmp<F> for F
+where
+    F: FloatSample,
+{
+    #[inline]
+    fn into_amp(self) -> F {
+        self
+    }
+}
+
+impl<F> IntoAmp<F> for Gain
+where
+    F: FloatSample,
+{
+    #[inline]
+    fn into_amp(self) -> F {
+        self.0.to_sample()
+    }
+}
+
+impl<F> IntoAmp<F> for Decibels
+where
+    F: FloatSample,
+{
+    #[inline]
+    fn into_amp(self) -> F {
+        self.to_gain().0.to_sample()
+    }
+}
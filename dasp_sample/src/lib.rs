@@ -3,6 +3,12 @@
 //!
 //! The **Sample** trait is the core abstraction throughout dasp on which most other abstractions
 //! are based.
+//!
+//! ### Optional Features
+//!
+//! - The **half** feature (or **sample-half** feature if using `dasp`) provides **Sample**
+//!   implementations and conversions for the half precision `f16` and `bf16` types of the
+//!   [half](https://docs.rs/half) crate, re-exported at the crate root.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -10,10 +16,20 @@
 extern crate alloc;
 
 pub use conv::{Duplex, FromSample, ToSample};
-pub use types::{I24, I48, U24, U48};
+pub use format::SampleFormat;
+pub use gain::{Decibels, Gain, IntoAmp};
+#[cfg(feature = "half")]
+pub use half::{bf16, f16};
+pub use types::{ALaw, MuLaw, I24, I48, Q15, Q31, U24, U48};
 
+pub mod bytes;
 pub mod conv;
+pub mod dither;
+pub mod fade;
+pub mod format;
+pub mod gain;
 mod ops;
+pub mod sanitize;
 pub mod types;
 
 /// A trait for working generically across different **Sample** format types.
@@ -152,6 +168,140 @@ pub trait Sample: Copy + Clone + PartialOrd + PartialEq {
         FromSample::from_sample_(s)
     }
 
+    /// Convert `self` to any type that implements `FromSample<Self>`, first clamping `self` to its
+    /// nominal range.
+    ///
+    /// Floating point samples outside of `-1.0 <= s <= 1.0` are clipped to `-1.0` or `1.0` and
+    /// `NaN` is converted to `EQUILIBRIUM`. See the `conv::NominalRange` trait for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use dasp_sample::Sample;
+    ///
+    /// fn main() {
+    ///     assert_eq!(1.3.to_sample_saturating::<i16>(), 32_767);
+    ///     assert_eq!((-1.3).to_sample_saturating::<u8>(), 0);
+    ///     assert_eq!(1.3f64.to_sample_saturating::<f32>(), 1.0);
+    ///     assert_eq!(std::f32::NAN.to_sample_saturating::<f32>(), 0.0);
+    /// }
+    /// ```
+    #[inline]
+    fn to_sample_saturating<S>(self) -> S
+    where
+        Self: ToSample<S> + conv::NominalRange,
+    {
+        conv::NominalRange::saturate(self).to_sample()
+    }
+
+    /// Convert `self` to any type that implements `FromSample<Self>`, first wrapping `self`
+    /// around its nominal range as two's complement integer overflow would.
+    ///
+    /// See the `conv::NominalRange` trait for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use dasp_sample::Sample;
+    ///
+    /// fn main() {
+    ///     assert_eq!(1.25.to_sample_wrapping::<i16>(), -24_576);
+    ///     assert_eq!(1.25.to_sample_wrapping::<f64>(), -0.75);
+    ///     assert_eq!((-1.5).to_sample_wrapping::<f32>(), 0.5);
+    /// }
+    /// ```
+    #[inline]
+    fn to_sample_wrapping<S>(self) -> S
+    where
+        Self: ToSample<S> + conv::NominalRange,
+    {
+        conv::NominalRange::wrap(self).to_sample()
+    }
+
+    /// Convert `self` to any type that implements `FromSample<Self>`, returning `None` if `self`
+    /// lies outside of its nominal range.
+    ///
+    /// See the `conv::NominalRange` trait for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use dasp_sample::Sample;
+    ///
+    /// fn main() {
+    ///     assert_eq!(1.0.to_sample_checked::<i8>(), Some(127));
+    ///     assert_eq!(1.3.to_sample_checked::<i8>(), None);
+    ///     assert_eq!(std::f64::NAN.to_sample_checked::<f32>(), None);
+    ///     assert_eq!(0u8.to_sample_checked::<f32>(), Some(-1.0));
+    /// }
+    /// ```
+    #[inline]
+    fn to_sample_checked<S>(self) -> Option<S>
+    where
+        Self: ToSample<S> + conv::NominalRange,
+    {
+        if conv::NominalRange::is_in_range(self) {
+            Some(self.to_sample())
+        } else {
+            None
+        }
+    }
+
+    /// Convert `self` to any type that implements `FromSample<Self>`.
+    ///
+    /// **Panics** in debug builds if `self` lies outside of its nominal range. In release builds
+    /// this is equivalent to `to_sample`.
+    ///
+    /// See the `conv::NominalRange` trait for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use dasp_sample::Sample;
+    ///
+    /// fn main() {
+    ///     assert_eq!(0.5.to_sample_debug_checked::<i8>(), 64);
+    /// }
+    /// ```
+    #[inline]
+    fn to_sample_debug_checked<S>(self) -> S
+    where
+        Self: ToSample<S> + conv::NominalRange,
+    {
+        debug_assert!(
+            conv::NominalRange::is_in_range(self),
+            "sample lies outside of its nominal range"
+        );
+        self.to_sample()
+    }
+
+    /// Requantize `self` to the resolution of any **Quantize** type using the given **Ditherer**.
+    ///
+    /// Rather than truncating `self` as `to_sample` does, the sample is dithered and rounded to the
+    /// nearest step of the target type. See the `dither` module for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use dasp_sample::Sample;
+    /// use dasp_sample::dither::{Dither, Ditherer, NoiseShaping};
+    ///
+    /// fn main() {
+    ///     let mut ditherer = Ditherer::new(Dither::None, NoiseShaping::None, 0);
+    ///     assert_eq!(0.99.to_sample::<i8>(), 126);
+    ///     assert_eq!(0.99.to_sample_dithered::<i8>(&mut ditherer), 127);
+    ///     assert_eq!((-0.99).to_sample::<i8>(), -126);
+    ///     assert_eq!((-0.99).to_sample_dithered::<i8>(&mut ditherer), -127);
+    /// }
+    /// ```
+    #[inline]
+    fn to_sample_dithered<S>(self, ditherer: &mut dither::Ditherer) -> S
+    where
+        S: dither::Quantize,
+    {
+        ditherer.quantize(self)
+    }
+
     /// Converts `self` to the equivalent `Sample` in the associated `Signed` format.
     ///
     /// This is a simple wrapper around `Sample::to_sample` which may provide extra convenience in
@@ -271,7 +421,17 @@ impl_sample! {
     U48: Signed: i64, Float: f64, EQUILIBRIUM: types::u48::EQUILIBRIUM,
     u64: Signed: i64, Float: f64, EQUILIBRIUM: 9_223_372_036_854_775_808,
     f32: Signed: f32, Float: f32, EQUILIBRIUM: 0.0,
-    f64: Signed: f64, Float: f64, EQUILIBRIUM: 0.0
+    f64: Signed: f64, Float: f64, EQUILIBRIUM: 0.0,
+    MuLaw: Signed: i16, Float: f32, EQUILIBRIUM: types::mu_law::EQUILIBRIUM,
+    ALaw: Signed: i16, Float: f32, EQUILIBRIUM: types::a_law::EQUILIBRIUM,
+    Q15: Signed: Q15, Float: Q15, EQUILIBRIUM: types::q15::EQUILIBRIUM,
+    Q31: Signed: Q31, Float: Q31, EQUILIBRIUM: types::q31::EQUILIBRIUM
+}
+
+#[cfg(feature = "half")]
+impl_sample! {
+    f16: Signed: f16, Float: f16, EQUILIBRIUM: f16::ZERO,
+    bf16: Signed: bf16, Float: bf16, EQUILIBRIUM: bf16::ZERO
 }
 
 /// Integral and floating-point **Sample** format types whose equilibrium is at 0.
@@ -286,7 +446,9 @@ pub trait SignedSample:
 {
 }
 macro_rules! impl_signed_sample { ($($T:ty)*) => { $( impl SignedSample for $T {} )* } }
-impl_signed_sample!(i8 i16 I24 i32 I48 i64 f32 f64);
+impl_signed_sample!(i8 i16 I24 i32 I48 i64 Q15 Q31 f32 f64);
+#[cfg(feature = "half")]
+impl_signed_sample!(f16 bf16);
 
 /// Sample format types represented as floating point numbers.
 ///
@@ -304,6 +466,21 @@ pub trait FloatSample:
     const IDENTITY: Self;
     /// Calculate the square root of `Self`.
     fn sample_sqrt(self) -> Self;
+
+    /// Whether or not `self` is neither infinite nor NaN.
+    #[inline]
+    fn sample_is_finite(self) -> bool {
+        self.to_sample::<f64>().is_finite()
+    }
+
+    /// Whether or not `self` is subnormal, i.e. too close to zero to be represented in normalized
+    /// form.
+    ///
+    /// Returns `false` by default for types that have no subnormal values.
+    #[inline]
+    fn sample_is_subnormal(self) -> bool {
+        false
+    }
 }
 
 impl FloatSample for f32 {
@@ -312,6 +489,14 @@ impl FloatSample for f32 {
     fn sample_sqrt(self) -> Self {
         ops::f32::sqrt(self)
     }
+    #[inline]
+    fn sample_is_finite(self) -> bool {
+        self.is_finite()
+    }
+    #[inline]
+    fn sample_is_subnormal(self) -> bool {
+        self.is_subnormal()
+    }
 }
 
 impl FloatSample for f64 {
@@ -320,4 +505,73 @@ impl FloatSample for f64 {
     fn sample_sqrt(self) -> Self {
         ops::f64::sqrt(self)
     }
+    #[inline]
+    fn sample_is_finite(self) -> bool {
+        self.is_finite()
+    }
+    #[inline]
+    fn sample_is_subnormal(self) -> bool {
+        self.is_subnormal()
+    }
+}
+
+// The fixed-point types cannot represent `1.0`, so their `MAX` is used as the identity, and their
+// `Mul` treats it as exactly `1.0`. This allows for gain and mixing without floating point
+// arithmetic.
+impl FloatSample for Q15 {
+    const IDENTITY: Self = types::q15::MAX;
+    #[inline]
+    fn sample_sqrt(self) -> Self {
+        self.sqrt()
+    }
+    #[inline]
+    fn sample_is_finite(self) -> bool {
+        true
+    }
+}
+
+impl FloatSample for Q31 {
+    const IDENTITY: Self = types::q31::MAX;
+    #[inline]
+    fn sample_sqrt(self) -> Self {
+        self.sqrt()
+    }
+    #[inline]
+    fn sample_is_finite(self) -> bool {
+        true
+    }
+}
+
+#[cfg(feature = "half")]
+impl FloatSample for f16 {
+    const IDENTITY: Self = f16::ONE;
+    #[inline]
+    fn sample_sqrt(self) -> Self {
+        f16::from_f32(ops::f32::sqrt(self.to_f32()))
+    }
+    #[inline]
+    fn sample_is_finite(self) -> bool {
+        self.is_finite()
+    }
+    #[inline]
+    fn sample_is_subnormal(self) -> bool {
+        self.classify() == core::num::FpCategory::Subnormal
+    }
+}
+
+#[cfg(feature = "half")]
+impl FloatSample for bf16 {
+    const IDENTITY: Self = bf16::ONE;
+    #[inline]
+    fn sample_sqrt(self) -> Self {
+        bf16::from_f32(ops::f32::sqrt(self.to_f32()))
+    }
+    #[inline]
+    fn sample_is_finite(self) -> bool {
+        self.is_finite()
+    }
+    #[inline]
+    fn sample_is_subnormal(self) -> bool {
+        self.classify() == core::num::FpCategory::Subnormal
+    }
 }
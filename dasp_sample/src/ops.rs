@@ -66,4 +66,127 @@ pub mod f64 {
     pub fn sqrt(x: f64) -> f64 {
         x.sqrt()
     }
+
+    /// Floor implementation for f64 via truncating integer casts.
+    /// Values with a magnitude of at least 2^52 (along with NaN and infinities) are returned
+    /// unchanged as they have no fractional component.
+    #[cfg(not(feature = "std"))]
+    pub fn floor(x: f64) -> f64 {
+        const INTEGRAL: f64 = 4_503_599_627_370_496.0;
+        if !(x > -INTEGRAL && x < INTEGRAL) {
+            return x;
+        }
+        let t = x as i64 as f64;
+        if t > x {
+            t - 1.0
+        } else {
+            t
+        }
+    }
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn floor(x: f64) -> f64 {
+        x.floor()
+    }
+
+    /// Natural logarithm implementation for f64.
+    /// Splits `x` into a mantissa in `[sqrt(0.5), sqrt(2))` and a power of two, then sums the
+    /// `atanh` series of the mantissa.
+    /// Accuracy: ~15 decimal places
+    #[cfg(not(feature = "std"))]
+    pub fn ln(x: f64) -> f64 {
+        const LN_2: f64 = core::f64::consts::LN_2;
+        const SQRT_2: f64 = core::f64::consts::SQRT_2;
+        if x.is_nan() || x < 0.0 {
+            return f64::NAN;
+        }
+        if x == 0.0 {
+            return f64::NEG_INFINITY;
+        }
+        if x == f64::INFINITY {
+            return x;
+        }
+
+        // Normalise subnormals so that the exponent bits are meaningful.
+        let (x, mut exp) = if x < f64::MIN_POSITIVE {
+            (x * 18_014_398_509_481_984.0, -54)
+        } else {
+            (x, 0)
+        };
+        let bits = x.to_bits();
+        exp += ((bits >> 52) & 0x7ff) as i32 - 1023;
+        let mut mant = f64::from_bits((bits & 0x000f_ffff_ffff_ffff) | 0x3ff0_0000_0000_0000);
+        if mant > SQRT_2 {
+            mant *= 0.5;
+            exp += 1;
+        }
+
+        let s = (mant - 1.0) / (mant + 1.0);
+        let s2 = s * s;
+        let mut term = s;
+        let mut sum = 0.0;
+        let mut k = 1.0;
+        while k < 40.0 {
+            sum += term / k;
+            term *= s2;
+            k += 2.0;
+        }
+        2.0 * sum + exp as f64 * LN_2
+    }
+
+    /// Base 10 logarithm implementation for f64.
+    #[cfg(not(feature = "std"))]
+    pub fn log10(x: f64) -> f64 {
+        ln(x) / core::f64::consts::LN_10
+    }
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn log10(x: f64) -> f64 {
+        x.log10()
+    }
+
+    /// Exponential function implementation for f64.
+    /// Reduces `x` to `k * ln(2) + r` where `|r| <= ln(2) / 2`, then sums the Taylor series of
+    /// `e^r` and scales the result by `2^k`.
+    /// Accuracy: ~15 decimal places
+    #[cfg(not(feature = "std"))]
+    pub fn exp(x: f64) -> f64 {
+        const LN_2: f64 = core::f64::consts::LN_2;
+        if x.is_nan() {
+            return x;
+        }
+        if x > 709.782_712_893_384 {
+            return f64::INFINITY;
+        }
+        if x < -745.133_219_101_941_1 {
+            return 0.0;
+        }
+
+        let k = floor(x / LN_2 + 0.5);
+        let r = x - k * LN_2;
+        let mut term = 1.0;
+        let mut sum = 1.0;
+        let mut n = 1.0;
+        while n < 20.0 {
+            term *= r / n;
+            sum += term;
+            n += 1.0;
+        }
+
+        // Scale in two steps where `2^k` alone would be subnormal.
+        let pow2 = |k: i64| f64::from_bits(((k + 1023) as u64) << 52);
+        let k = k as i64;
+        if k < -1022 {
+            sum * pow2(-1022) * pow2(k + 1022)
+        } else if k > 1023 {
+            sum * pow2(1023) * 2.0
+        } else {
+            sum * pow2(k)
+        }
+    }
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn exp(x: f64) -> f64 {
+        x.exp()
+    }
 }
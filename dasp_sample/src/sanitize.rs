@@ -0,0 +1,185 @@
+//! Detection and replacement of NaN, infinite and subnormal floating point samples.
+//!
+//! A single NaN or infinity anywhere within a mix propagates to every sample it is summed with or
+//! filtered by, while long runs of subnormal values (e.g. the decaying tail of a feedback loop)
+//! are processed very slowly by many CPUs.
+//!
+//! The [**Sanitizer**](./struct.Sanitizer.html) classifies each sample as a
+//! [**Fault**](./enum.Fault.html) where necessary and replaces it according to its
+//! [**NonFinite**](./enum.NonFinite.html) policy, optionally flushing subnormals to zero. Faults
+//! may be tallied with [**Counts**](./struct.Counts.html) or passed to a reporting hook as a
+//! [**Report**](./struct.Report.html) by the sanitizing functions and adaptors of `dasp_slice`
+//! and `dasp_signal`.
+
+use crate::FloatSample;
+
+/// The kinds of floating point values that are detected by the **Sanitizer**.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Fault {
+    /// The sample is NaN.
+    Nan,
+    /// The sample is positive or negative infinity.
+    Infinite,
+    /// The sample is subnormal.
+    Subnormal,
+}
+
+/// How the **Sanitizer** replaces NaN and infinite samples.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum NonFinite {
+    /// Non-finite samples are counted and reported but left unchanged.
+    Keep,
+    /// Non-finite samples are replaced with the equilibrium.
+    Equilibrium,
+    /// Infinite samples are clamped to the positive or negative `FloatSample::IDENTITY`, while
+    /// NaN samples are replaced with the equilibrium.
+    Clamp,
+}
+
+/// Detects faulty samples and replaces them according to its policy.
+///
+/// # Example
+///
+/// ```rust
+/// use dasp_sample::sanitize::{Fault, NonFinite, Sanitizer};
+///
+/// fn main() {
+///     let sanitizer = Sanitizer::new(NonFinite::Clamp, true);
+///     assert_eq!(sanitizer.sanitize(0.5f32), (0.5, None));
+///     assert_eq!(sanitizer.sanitize(f32::NAN), (0.0, Some(Fault::Nan)));
+///     assert_eq!(sanitizer.sanitize(-f64::INFINITY), (-1.0, Some(Fault::Infinite)));
+///     assert_eq!(sanitizer.sanitize(1e-40f32), (0.0, Some(Fault::Subnormal)));
+/// }
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Sanitizer {
+    non_finite: NonFinite,
+    flush_subnormals: bool,
+}
+
+/// The number of each kind of **Fault** encountered.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Counts {
+    /// The number of NaN samples.
+    pub nan: u64,
+    /// The number of infinite samples.
+    pub infinite: u64,
+    /// The number of subnormal samples.
+    pub subnormal: u64,
+}
+
+/// Describes a single faulty sample, as passed to a reporting hook.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Report {
+    /// The index of the frame containing the sample.
+    pub frame: u64,
+    /// The index of the channel within the frame.
+    pub channel: usize,
+    /// The kind of fault.
+    pub fault: Fault,
+}
+
+impl Fault {
+    /// Classify the given sample, returning `None` if it is a finite, normal value or zero.
+    #[inline]
+    pub fn of<S>(sample: S) -> Option<Self>
+    where
+        S: FloatSample,
+    {
+        if !sample.sample_is_finite() {
+            if sample.to_sample::<f64>().is_nan() {
+                Some(Fault::Nan)
+            } else {
+                Some(Fault::Infinite)
+            }
+        } else if sample.sample_is_subnormal() {
+            Some(Fault::Subnormal)
+        } else {
+            None
+        }
+    }
+}
+
+impl Sanitizer {
+    /// Construct a new **Sanitizer** with the given non-finite policy, flushing subnormals to the
+    /// equilibrium if `flush_subnormals` is `true`.
+    pub fn new(non_finite: NonFinite, flush_subnormals: bool) -> Self {
+        Sanitizer {
+            non_finite,
+            flush_subnormals,
+        }
+    }
+
+    /// The policy used to replace NaN and infinite samples.
+    pub fn non_finite(&self) -> NonFinite {
+        self.non_finite
+    }
+
+    /// Whether or not subnormal samples are flushed to the equilibrium.
+    pub fn flush_subnormals(&self) -> bool {
+        self.flush_subnormals
+    }
+
+    /// Sanitize the given sample, returning the replacement along with its **Fault** if it had
+    /// one.
+    ///
+    /// Faults are returned even when the policy leaves the faulty sample unchanged.
+    #[inline]
+    pub fn sanitize<S>(&self, sample: S) -> (S, Option<Fault>)
+    where
+        S: FloatSample,
+    {
+        let fault = Fault::of(sample);
+        let sanitized = match fault {
+            None => sample,
+            Some(Fault::Subnormal) if self.flush_subnormals => S::EQUILIBRIUM,
+            Some(Fault::Subnormal) => sample,
+            Some(fault) => match self.non_finite {
+                NonFinite::Keep => sample,
+                NonFinite::Equilibrium => S::EQUILIBRIUM,
+                NonFinite::Clamp if fault == Fault::Nan => S::EQUILIBRIUM,
+                NonFinite::Clamp if sample > S::EQUILIBRIUM => <S as FloatSample>::IDENTITY,
+                NonFinite::Clamp => -<S as FloatSample>::IDENTITY,
+            },
+        };
+        (sanitized, fault)
+    }
+}
+
+impl Default for Sanitizer {
+    /// Replaces non-finite samples with the equilibrium and flushes subnormals.
+    fn default() -> Self {
+        Sanitizer::new(NonFinite::Equilibrium, true)
+    }
+}
+
+impl Counts {
+    /// Increment the count of the given kind of fault.
+    #[inline]
+    pub fn record(&mut self, fault: Fault) {
+        match fault {
+            Fault::Nan => self.nan += 1,
+            Fault::Infinite => self.infinite += 1,
+            Fault::Subnormal => self.subnormal += 1,
+        }
+    }
+
+    /// The total number of faults of any kind.
+    pub fn total(&self) -> u64 {
+        self.nan + self.infinite + self.subnormal
+    }
+
+    /// Whether or not any NaN or infinite samples were encountered.
+    pub fn has_non_finite(&self) -> bool {
+        self.nan > 0 || self.infinite > 0
+    }
+}
+
+impl core::ops::AddAssign for Counts {
+    #[inline]
+    fn add_assign(&mut self, other: Self) {
+        self.nan += other.nan;
+        self.infinite += other.infinite;
+        self.subnormal += other.subnormal;
+    }
+}
@@ -1,9 +1,13 @@
 //! A collection of custom, non-std **Sample** types.
 
+pub use self::a_law::ALaw;
 pub use self::i11::I11;
 pub use self::i20::I20;
 pub use self::i24::I24;
 pub use self::i48::I48;
+pub use self::mu_law::MuLaw;
+pub use self::q15::Q15;
+pub use self::q31::Q31;
 pub use self::u11::U11;
 pub use self::u20::U20;
 pub use self::u24::U24;
@@ -68,6 +72,7 @@ macro_rules! new_sample_type {
         const TOTAL: $Rep = $TOTAL;
 
         #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Default)]
+        #[repr(transparent)]
         pub struct $T($Rep);
 
         impl From<$Rep> for $T {
@@ -234,6 +239,188 @@ macro_rules! new_sample_type {
     };
 }
 
+macro_rules! companded_sample_type {
+    ($T:ident, $name:expr, min: $MIN:expr, max: $MAX:expr, eq: $EQ:expr) => {
+        pub const MIN: $T = $T($MIN);
+        pub const MAX: $T = $T($MAX);
+        pub const EQUILIBRIUM: $T = $T($EQ);
+
+        #[doc = concat!("A G.711 ", $name, " companded sample, stored as its 8-bit code.")]
+        ///
+        /// Samples are compared by the 16-bit linear value that they decode to, so codes that
+        /// decode to the same value are equal.
+        #[derive(Copy, Clone, Debug)]
+        pub struct $T(u8);
+
+        impl $T {
+            /// Construct a sample from its 8-bit code as it would be transmitted or stored.
+            #[inline]
+            pub const fn from_bits(bits: u8) -> Self {
+                $T(bits)
+            }
+
+            /// Return the 8-bit code of the sample as it would be transmitted or stored.
+            #[inline]
+            pub const fn to_bits(self) -> u8 {
+                self.0
+            }
+
+            /// Compress a 16-bit linear PCM sample as specified by G.711.
+            #[inline]
+            pub fn from_linear(sample: i16) -> Self {
+                $T(encode(sample))
+            }
+
+            /// Decode the sample to 16-bit linear PCM.
+            #[inline]
+            pub fn to_linear(self) -> i16 {
+                decode(self.0)
+            }
+        }
+
+        impl Default for $T {
+            #[inline]
+            fn default() -> Self {
+                EQUILIBRIUM
+            }
+        }
+
+        impl PartialEq for $T {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.to_linear() == other.to_linear()
+            }
+        }
+
+        impl Eq for $T {}
+
+        impl PartialOrd for $T {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<::core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for $T {
+            #[inline]
+            fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                self.to_linear().cmp(&other.to_linear())
+            }
+        }
+    };
+}
+
+macro_rules! fixed_point_sample_type {
+    ($T:ident: $Rep:ident, $Wide:ident, frac: $FRAC:expr) => {
+        pub const MIN: $T = $T($Rep::MIN);
+        pub const MAX: $T = $T($Rep::MAX);
+        pub const EQUILIBRIUM: $T = $T(0);
+
+        /// A signed fixed-point sample with a single integer (sign) bit, covering the range
+        /// `-1.0 <= s < 1.0`.
+        ///
+        /// All arithmetic saturates at `MIN` and `MAX` rather than wrapping. Multiplication rounds
+        /// to the nearest representable value, except that `MAX` is treated as exactly `1.0` so
+        /// that multiplying by it returns the other operand unchanged. Division by zero panics.
+        #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Default)]
+        pub struct $T($Rep);
+
+        impl $T {
+            /// Construct a sample from its raw two's complement representation, where `1 << FRAC`
+            /// represents `1.0`.
+            #[inline]
+            pub const fn from_bits(bits: $Rep) -> Self {
+                $T(bits)
+            }
+
+            /// Return the raw two's complement representation of the sample.
+            #[inline]
+            pub const fn to_bits(self) -> $Rep {
+                self.0
+            }
+
+            /// The square root of the sample, rounded down.
+            ///
+            /// Returns `EQUILIBRIUM` for negative samples.
+            #[inline]
+            // `u32::div_ceil` requires Rust 1.73.
+            #[allow(clippy::manual_div_ceil)]
+            pub fn sqrt(self) -> Self {
+                if self.0 <= 0 {
+                    return EQUILIBRIUM;
+                }
+                let x = (self.0 as u64) << $FRAC;
+                // Newton's method, starting from a value that is known to be too large.
+                let mut r = 1u64 << ((64 - x.leading_zeros() + 1) / 2);
+                loop {
+                    let next = (r + x / r) / 2;
+                    if next >= r {
+                        break;
+                    }
+                    r = next;
+                }
+                $T(r as $Rep)
+            }
+        }
+
+        /// Saturates a wide intermediate result to the sample's range.
+        #[inline]
+        fn saturate(wide: $Wide) -> $T {
+            $T(wide.clamp($Rep::MIN as $Wide, $Rep::MAX as $Wide) as $Rep)
+        }
+
+        impl ::core::ops::Add<$T> for $T {
+            type Output = $T;
+            #[inline]
+            fn add(self, other: Self) -> Self {
+                $T(self.0.saturating_add(other.0))
+            }
+        }
+
+        impl ::core::ops::Sub<$T> for $T {
+            type Output = $T;
+            #[inline]
+            fn sub(self, other: Self) -> Self {
+                $T(self.0.saturating_sub(other.0))
+            }
+        }
+
+        impl ::core::ops::Mul<$T> for $T {
+            type Output = $T;
+            #[inline]
+            fn mul(self, other: Self) -> Self {
+                // `MAX` is the multiplicative identity (see `FloatSample::IDENTITY`).
+                if self == MAX {
+                    return other;
+                }
+                if other == MAX {
+                    return self;
+                }
+                let product = self.0 as $Wide * other.0 as $Wide;
+                saturate((product + (1 << ($FRAC - 1))) >> $FRAC)
+            }
+        }
+
+        impl ::core::ops::Div<$T> for $T {
+            type Output = $T;
+            #[inline]
+            fn div(self, other: Self) -> Self {
+                saturate(((self.0 as $Wide) << $FRAC) / other.0 as $Wide)
+            }
+        }
+
+        impl ::core::ops::Neg for $T {
+            type Output = $T;
+            #[inline]
+            fn neg(self) -> $T {
+                $T(self.0.saturating_neg())
+            }
+        }
+    };
+}
+
+pub mod a_law;
+
 pub mod i11 {
     new_sample_type!(I11: i16, eq: 0, min: -1024, max: 1023, total: 2048,
                      from: i8, u8);
@@ -260,6 +447,16 @@ pub mod i48 {
     impl_neg!(I48);
 }
 
+pub mod mu_law;
+
+pub mod q15 {
+    fixed_point_sample_type!(Q15: i16, i32, frac: 15);
+}
+
+pub mod q31 {
+    fixed_point_sample_type!(Q31: i32, i64, frac: 31);
+}
+
 pub mod u11 {
     new_sample_type!(U11: i16, eq: 1024, min: 0, max: 2047, total: 2048,
                      from: u8);
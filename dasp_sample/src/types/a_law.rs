@@ -0,0 +1,60 @@
+//! The G.711 A-law companded sample type.
+
+companded_sample_type!(ALaw, "A-law", min: 0x2A, max: 0xAA, eq: 0xD5);
+
+const SEGMENT_ENDS: [i16; 8] = [0x1F, 0x3F, 0x7F, 0xFF, 0x1FF, 0x3FF, 0x7FF, 0xFFF];
+
+/// Decodes every A-law code to 16-bit linear PCM.
+static DECODE_TABLE: [i16; 256] = {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        table[i] = decode_uncached(i as u8);
+        i += 1;
+    }
+    table
+};
+
+/// Compress a 16-bit linear PCM sample to an A-law code.
+///
+/// The 3 least significant bits of the sample are discarded as A-law has 13 bits of range.
+#[inline]
+pub fn encode(sample: i16) -> u8 {
+    let sample = sample >> 3;
+    let (magnitude, mask) = if sample >= 0 {
+        (sample, 0xD5)
+    } else {
+        (-sample - 1, 0x55)
+    };
+    let segment = SEGMENT_ENDS
+        .iter()
+        .position(|&end| magnitude <= end)
+        .unwrap_or(8) as u8;
+    if segment >= 8 {
+        return 0x7F ^ mask;
+    }
+    let shift = if segment < 2 { 1 } else { segment };
+    let code = (segment << 4) | ((magnitude >> shift) & 0x0F) as u8;
+    code ^ mask
+}
+
+/// Expand an A-law code to a 16-bit linear PCM sample.
+#[inline]
+pub fn decode(code: u8) -> i16 {
+    DECODE_TABLE[code as usize]
+}
+
+const fn decode_uncached(code: u8) -> i16 {
+    let code = code ^ 0x55;
+    let t = ((code & 0x0F) as i16) << 4;
+    let t = match (code & 0x70) >> 4 {
+        0 => t + 8,
+        1 => t + 0x108,
+        segment => (t + 0x108) << (segment - 1),
+    };
+    if code & 0x80 != 0 {
+        t
+    } else {
+        -t
+    }
+}
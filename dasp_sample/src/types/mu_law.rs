@@ -0,0 +1,57 @@
+//! The G.711 μ-law companded sample type.
+
+companded_sample_type!(MuLaw, "μ-law", min: 0x00, max: 0x80, eq: 0xFF);
+
+const BIAS: i16 = 0x84;
+const CLIP: i16 = 8_159;
+const SEGMENT_ENDS: [i16; 8] = [0x3F, 0x7F, 0xFF, 0x1FF, 0x3FF, 0x7FF, 0xFFF, 0x1FFF];
+
+/// Decodes every μ-law code to 16-bit linear PCM.
+static DECODE_TABLE: [i16; 256] = {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        table[i] = decode_uncached(i as u8);
+        i += 1;
+    }
+    table
+};
+
+/// Compress a 16-bit linear PCM sample to a μ-law code.
+///
+/// The 2 least significant bits of the sample are discarded as μ-law has 14 bits of range.
+#[inline]
+pub fn encode(sample: i16) -> u8 {
+    let (magnitude, mask) = if sample < 0 {
+        (-(sample >> 2), 0x7F)
+    } else {
+        (sample >> 2, 0xFF)
+    };
+    let magnitude = magnitude.min(CLIP) + (BIAS >> 2);
+    let segment = SEGMENT_ENDS
+        .iter()
+        .position(|&end| magnitude <= end)
+        .unwrap_or(8) as u8;
+    if segment >= 8 {
+        return 0x7F ^ mask;
+    }
+    let code = (segment << 4) | ((magnitude >> (segment + 1)) & 0x0F) as u8;
+    code ^ mask
+}
+
+/// Expand a μ-law code to a 16-bit linear PCM sample.
+#[inline]
+pub fn decode(code: u8) -> i16 {
+    DECODE_TABLE[code as usize]
+}
+
+const fn decode_uncached(code: u8) -> i16 {
+    let code = !code;
+    let t = (((code & 0x0F) as i16) << 3) + BIAS;
+    let t = t << ((code & 0x70) >> 4);
+    if code & 0x80 != 0 {
+        BIAS - t
+    } else {
+        t - BIAS
+    }
+}
@@ -0,0 +1,120 @@
+use dasp_sample::bytes::{
+    self, BigEndian, Format, LittleEndian, Lsb20In24, Lsb20In32, Lsb24In32, Msb20In24, Msb20In32,
+    Msb24In32, Packed,
+};
+use dasp_sample::{I24, I48, U24, U48};
+
+// Encode `sample` with format `F`, check the resulting bytes and check that it decodes again.
+fn check<F>(sample: F::Sample, expected: &[u8])
+where
+    F: Format,
+    F::Sample: std::fmt::Debug,
+{
+    assert_eq!(F::SIZE, expected.len());
+    let mut bytes = vec![0u8; F::SIZE];
+    F::encode(sample, &mut bytes);
+    assert_eq!(&bytes[..], expected, "encoding {:?}", sample);
+    assert_eq!(F::decode(&bytes), sample);
+}
+
+macro_rules! test_packed {
+    ($($name:ident: $T:ty, $sample:expr, le: $le:expr;)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let le: &[u8] = &$le;
+                let be: Vec<u8> = le.iter().rev().cloned().collect();
+                check::<Packed<$T, LittleEndian>>($sample, le);
+                check::<Packed<$T, BigEndian>>($sample, &be);
+            }
+        )*
+    };
+}
+
+test_packed! {
+    test_packed_i8: i8, -2, le: [0xFE];
+    test_packed_u8: u8, 200, le: [0xC8];
+    test_packed_i16: i16, -2, le: [0xFE, 0xFF];
+    test_packed_u16: u16, 0x1234, le: [0x34, 0x12];
+    test_packed_i24: I24, I24::new_unchecked(-2), le: [0xFE, 0xFF, 0xFF];
+    test_packed_u24: U24, U24::new_unchecked(0x12_3456), le: [0x56, 0x34, 0x12];
+    test_packed_i32: i32, -2, le: [0xFE, 0xFF, 0xFF, 0xFF];
+    test_packed_u32: u32, 0x1234_5678, le: [0x78, 0x56, 0x34, 0x12];
+    test_packed_i48: I48, I48::new_unchecked(-2), le: [0xFE, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF];
+    test_packed_u48: U48, U48::new_unchecked(0x1234_5678_9ABC),
+        le: [0xBC, 0x9A, 0x78, 0x56, 0x34, 0x12];
+    test_packed_i64: i64, -2, le: [0xFE, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF];
+    test_packed_u64: u64, 0x0102_0304_0506_0708, le: [8, 7, 6, 5, 4, 3, 2, 1];
+    test_packed_f32: f32, -1.0, le: [0x00, 0x00, 0x80, 0xBF];
+    test_packed_f64: f64, 0.5, le: [0, 0, 0, 0, 0, 0, 0xE0, 0x3F];
+}
+
+#[test]
+fn test_24_in_32() {
+    let s = I24::new_unchecked(-2);
+    check::<Lsb24In32<I24, LittleEndian>>(s, &[0xFE, 0xFF, 0xFF, 0xFF]);
+    check::<Lsb24In32<I24, BigEndian>>(s, &[0xFF, 0xFF, 0xFF, 0xFE]);
+    check::<Msb24In32<I24, LittleEndian>>(s, &[0x00, 0xFE, 0xFF, 0xFF]);
+    check::<Msb24In32<I24, BigEndian>>(s, &[0xFF, 0xFF, 0xFE, 0x00]);
+    let s = U24::new_unchecked(0x80_0001);
+    check::<Lsb24In32<U24, LittleEndian>>(s, &[0x01, 0x00, 0x80, 0x00]);
+    check::<Msb24In32<U24, BigEndian>>(s, &[0x80, 0x00, 0x01, 0x00]);
+    // Padding is ignored when decoding.
+    let s = <Lsb24In32<I24, LittleEndian>>::decode(&[0x01, 0x00, 0x00, 0xAB]);
+    assert_eq!(s, I24::new_unchecked(1));
+    let s = <Msb24In32<I24, LittleEndian>>::decode(&[0xAB, 0x01, 0x00, 0x00]);
+    assert_eq!(s, I24::new_unchecked(1));
+}
+
+#[test]
+fn test_20_bit() {
+    // The smallest negative 20-bit step is decoded to the most significant 20 bits of an `I24`.
+    let s = I24::new_unchecked(-16);
+    check::<Lsb20In24<I24, LittleEndian>>(s, &[0xFF, 0xFF, 0xFF]);
+    check::<Msb20In24<I24, LittleEndian>>(s, &[0xF0, 0xFF, 0xFF]);
+    check::<Lsb20In32<I24, LittleEndian>>(s, &[0xFF, 0xFF, 0xFF, 0xFF]);
+    check::<Msb20In32<I24, BigEndian>>(s, &[0xFF, 0xFF, 0xF0, 0x00]);
+    let s = U24::new_unchecked(0x12_3450);
+    check::<Lsb20In24<U24, BigEndian>>(s, &[0x01, 0x23, 0x45]);
+    check::<Msb20In24<U24, BigEndian>>(s, &[0x12, 0x34, 0x50]);
+    check::<Lsb20In32<U24, LittleEndian>>(s, &[0x45, 0x23, 0x01, 0x00]);
+    check::<Msb20In32<U24, LittleEndian>>(s, &[0x00, 0x50, 0x34, 0x12]);
+    // Bits beyond 20-bit resolution are truncated when encoding.
+    let mut bytes = [0u8; 3];
+    <Lsb20In24<I24, LittleEndian>>::encode(I24::new_unchecked(0x7F_FFFF), &mut bytes);
+    assert_eq!(bytes, [0xFF, 0xFF, 0x07]);
+    assert_eq!(
+        <Lsb20In24<I24, LittleEndian>>::decode(&bytes),
+        I24::new_unchecked(0x7F_FFF0)
+    );
+    // Padding is ignored when decoding.
+    let s = <Lsb20In24<I24, LittleEndian>>::decode(&[0x01, 0x00, 0xF0]);
+    assert_eq!(s, I24::new_unchecked(16));
+}
+
+#[test]
+fn test_slice_round_trip() {
+    let samples: Vec<i32> = (-50..50).map(|i| i * 12_345_678).collect();
+    let mut bytes = vec![0u8; samples.len() * 4];
+    bytes::encode_slice::<Packed<i32, BigEndian>>(&samples, &mut bytes);
+    let mut decoded = vec![0i32; samples.len()];
+    bytes::decode_slice::<Packed<i32, BigEndian>>(&bytes, &mut decoded);
+    assert_eq!(samples, decoded);
+}
+
+#[test]
+#[should_panic]
+fn test_decode_slice_panic() {
+    let mut samples = [0i16; 2];
+    bytes::decode_slice::<Packed<i16, LittleEndian>>(&[0u8; 3], &mut samples);
+}
+
+#[test]
+fn test_decode_iter() {
+    let bytes = [0x01, 0x00, 0x02, 0x00, 0x03];
+    let mut iter = bytes::decode_iter::<Packed<u16, LittleEndian>, _>(bytes.iter().cloned());
+    assert_eq!(iter.size_hint(), (2, Some(2)));
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.next(), Some(2));
+    assert_eq!(iter.next(), None);
+}
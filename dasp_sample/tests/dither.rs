@@ -0,0 +1,193 @@
+use dasp_sample::dither::{self, Dither, Ditherer, NoiseShaping, Rng};
+use dasp_sample::{Sample, I24, U24};
+
+const DITHERS: [Dither; 4] = [
+    Dither::None,
+    Dither::Rpdf,
+    Dither::Tpdf,
+    Dither::HighPassTpdf,
+];
+const SHAPINGS: [NoiseShaping; 6] = [
+    NoiseShaping::None,
+    NoiseShaping::FirstOrder,
+    NoiseShaping::SecondOrder,
+    NoiseShaping::Lipshitz,
+    NoiseShaping::Wannamaker3,
+    NoiseShaping::Wannamaker9,
+];
+
+// A slowly varying, low level test signal in the range of a handful of 16-bit LSBs.
+fn test_signal(n: usize) -> impl Iterator<Item = f64> {
+    (0..n).map(|i| (i as f64 * 0.01).sin() * 8.0 / 32_768.0)
+}
+
+#[test]
+fn test_rng_is_deterministic() {
+    let mut a = Rng::new(42);
+    let mut b = Rng::new(42);
+    let mut c = Rng::new(43);
+    let a: Vec<u64> = (0..16).map(|_| a.next_u64()).collect();
+    let b: Vec<u64> = (0..16).map(|_| b.next_u64()).collect();
+    let c: Vec<u64> = (0..16).map(|_| c.next_u64()).collect();
+    assert_eq!(a, b);
+    assert_ne!(a, c);
+    let mut rng = Rng::new(0);
+    for _ in 0..10_000 {
+        let f = rng.next_f64();
+        assert!((0.0..1.0).contains(&f));
+    }
+}
+
+#[test]
+fn test_noise_range() {
+    for &dither in DITHERS.iter() {
+        let mut rng = Rng::new(7);
+        let mut prev = 0.0;
+        let mut sum = 0.0;
+        let n = 100_000;
+        for _ in 0..n {
+            let noise = dither.next_noise(&mut rng, &mut prev);
+            match dither {
+                Dither::None => assert_eq!(noise, 0.0),
+                Dither::Rpdf => assert!((-0.5..0.5).contains(&noise)),
+                _ => assert!(noise > -1.0 && noise < 1.0),
+            }
+            sum += noise;
+        }
+        assert!((sum / n as f64).abs() < 0.01, "{:?} is biased", dither);
+    }
+}
+
+#[test]
+fn test_quantize_rounds_and_clips() {
+    assert_eq!(dither::quantize::<i16>(0.0), 0);
+    assert_eq!(dither::quantize::<i16>(1.4 / 32_768.0), 1);
+    assert_eq!(dither::quantize::<i16>(-1.6 / 32_768.0), -2);
+    assert_eq!(dither::quantize::<i16>(1.0), i16::MAX);
+    assert_eq!(dither::quantize::<i16>(-1.5), i16::MIN);
+    assert_eq!(dither::quantize::<u16>(0.0), 32_768);
+    assert_eq!(dither::quantize::<I24>(0.5), I24::new_unchecked(4_194_304));
+    assert_eq!(dither::quantize::<U24>(-1.0), U24::new_unchecked(0));
+    assert_eq!(dither::quantize::<i64>(1.0), i64::MAX);
+    assert_eq!(dither::quantize::<i8>(f64::NAN), 0);
+    assert_eq!(dither::lsb::<i8>(), 1.0 / 128.0);
+}
+
+#[test]
+fn test_undithered_matches_conversion_of_exact_steps() {
+    let mut ditherer = Ditherer::new(Dither::None, NoiseShaping::None, 0);
+    for i in -128..128 {
+        let s = i as f32 / 128.0;
+        assert_eq!(ditherer.quantize::<_, i8>(s), s.to_sample::<i8>());
+        assert_eq!(
+            s.to_sample_dithered::<u8>(&mut ditherer),
+            s.to_sample::<u8>()
+        );
+    }
+}
+
+#[test]
+fn test_same_seed_same_output() {
+    for &dither in DITHERS.iter() {
+        for &shaping in SHAPINGS.iter() {
+            let mut a = Ditherer::new(dither, shaping, 1234);
+            let mut b = a.clone();
+            for x in test_signal(1_000) {
+                assert_eq!(a.quantize::<_, i16>(x), b.quantize::<_, i16>(x));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_dithered_error_is_bounded_and_unbiased() {
+    for &dither in DITHERS.iter() {
+        let mut ditherer = Ditherer::new(dither, NoiseShaping::None, 99);
+        let n = 100_000;
+        let mut sum = 0.0;
+        for x in test_signal(n) {
+            let y = ditherer.quantize::<_, i16>(x).to_sample::<f64>();
+            let error = (y - x) * 32_768.0;
+            assert!(error.abs() <= 1.5, "{:?}: error of {} LSB", dither, error);
+            sum += error;
+        }
+        assert!((sum / n as f64).abs() < 0.01, "{:?} is biased", dither);
+    }
+}
+
+#[test]
+fn test_tpdf_decorrelates_low_level_signal() {
+    // A constant signal of a quarter LSB is lost entirely without dither, but is preserved on
+    // average with it.
+    let x = 0.25 / 32_768.0;
+    let n = 100_000;
+    let mut undithered = Ditherer::new(Dither::None, NoiseShaping::None, 0);
+    let mut dithered = Ditherer::new(Dither::Tpdf, NoiseShaping::None, 0);
+    let mut sum = 0.0;
+    for _ in 0..n {
+        assert_eq!(undithered.quantize::<_, i16>(x), 0);
+        sum += dithered.quantize::<_, i16>(x) as f64;
+    }
+    assert!((sum / n as f64 - 0.25).abs() < 0.01);
+}
+
+#[test]
+fn test_noise_shaping_reduces_low_frequency_error() {
+    // Measure the power of the error after a low-pass filter (three cascaded moving averages).
+    fn low_frequency_error_power(shaping: NoiseShaping) -> f64 {
+        const WINDOW: usize = 32;
+        let mut ditherer = Ditherer::new(Dither::Tpdf, shaping, 5);
+        let mut errors: Vec<f64> = test_signal(50_000)
+            .map(|x| (ditherer.quantize::<_, i16>(x).to_sample::<f64>() - x) * 32_768.0)
+            .collect();
+        for _ in 0..3 {
+            errors = errors
+                .windows(WINDOW)
+                .map(|w| w.iter().sum::<f64>() / WINDOW as f64)
+                .collect();
+        }
+        errors.iter().map(|a| a * a).sum::<f64>() / errors.len() as f64
+    }
+    let flat = low_frequency_error_power(NoiseShaping::None);
+    for &shaping in SHAPINGS[1..].iter() {
+        let shaped = low_frequency_error_power(shaping);
+        assert!(shaped < flat, "{:?}: {} >= {}", shaping, shaped, flat);
+    }
+}
+
+#[test]
+fn test_noise_shaping_is_stable_when_clipping() {
+    let mut ditherer = Ditherer::new(Dither::Tpdf, NoiseShaping::Wannamaker9, 3);
+    for _ in 0..1_000 {
+        assert_eq!(ditherer.quantize::<_, i16>(2.0), i16::MAX);
+    }
+    for x in test_signal(1_000) {
+        let y = ditherer.quantize::<_, i16>(x).to_sample::<f64>();
+        assert!((y - x).abs() * 32_768.0 < 32.0);
+    }
+}
+
+#[test]
+fn test_reset_clears_error_history() {
+    let mut a = Ditherer::new(Dither::None, NoiseShaping::Lipshitz, 0);
+    let mut b = a.clone();
+    for x in test_signal(100) {
+        a.quantize::<_, i16>(x);
+    }
+    a.reset();
+    for x in test_signal(100) {
+        assert_eq!(a.quantize::<_, i16>(x), b.quantize::<_, i16>(x));
+    }
+}
+
+#[test]
+fn test_non_finite_input_does_not_poison_error_history() {
+    let mut a = Ditherer::new(Dither::None, NoiseShaping::Wannamaker9, 0);
+    let mut b = a.clone();
+    for &x in &[f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+        a.quantize::<_, i16>(x);
+    }
+    for x in test_signal(100) {
+        assert_eq!(a.quantize::<_, i16>(x), b.quantize::<_, i16>(x));
+    }
+}
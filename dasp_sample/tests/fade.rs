@@ -0,0 +1,76 @@
+use dasp_sample::fade::{Curve, Ramp};
+use dasp_sample::Decibels;
+
+const CURVES: [Curve; 4] = [
+    Curve::Linear,
+    Curve::Exponential,
+    Curve::SCurve,
+    Curve::EqualPower,
+];
+
+#[test]
+fn test_curve_endpoints_and_monotonicity() {
+    for &curve in CURVES.iter() {
+        assert_eq!(curve.gain(0.0), 0.0);
+        assert_eq!(curve.gain(1.0), 1.0);
+        assert_eq!(curve.gain(-1.0), 0.0);
+        let mut prev = 0.0;
+        for i in 1..=100 {
+            let gain = curve.gain(i as f64 / 100.0);
+            assert!(gain > prev, "{:?} is not rising at {}", curve, i);
+            prev = gain;
+        }
+    }
+}
+
+#[test]
+fn test_crossfade_sums() {
+    for i in 0..=10 {
+        let t = i as f64 / 10.0;
+        let linear = Curve::Linear.gain(t) + Curve::Linear.gain(1.0 - t);
+        let s_curve = Curve::SCurve.gain(t) + Curve::SCurve.gain(1.0 - t);
+        let power = Curve::EqualPower.gain(t).powi(2) + Curve::EqualPower.gain(1.0 - t).powi(2);
+        assert!((linear - 1.0).abs() < 1e-12);
+        assert!((s_curve - 1.0).abs() < 1e-12);
+        // The `no_std` square root is only accurate to around 1e-12.
+        assert!((power - 1.0).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn test_exponential_is_linear_in_decibels() {
+    // Away from the start of the curve, equal steps in time are roughly equal steps in decibels.
+    let a = Decibels::from(dasp_sample::Gain(Curve::Exponential.gain(0.5)));
+    let b = Decibels::from(dasp_sample::Gain(Curve::Exponential.gain(0.75)));
+    assert!((b.0 - a.0 - 15.0).abs() < 0.5);
+    assert!((a.0 + 30.0).abs() < 0.5);
+}
+
+#[test]
+fn test_ramp_mirrors_falling_curves() {
+    let len = 9;
+    for &curve in CURVES.iter() {
+        let fade_in = Ramp::fade_in(curve, len);
+        let fade_out = Ramp::fade_out(curve, len);
+        for idx in 0..len {
+            let mirrored = fade_in.gain_at(len - 1 - idx);
+            assert!((fade_out.gain_at(idx) - mirrored).abs() < 1e-12);
+        }
+    }
+}
+
+#[test]
+fn test_ramp_bounds() {
+    let ramp = Ramp::new(Curve::Linear, Decibels(-6.0), 0.25, 0);
+    assert!(ramp.is_empty());
+    assert_eq!(ramp.gain_at(0), 0.25);
+
+    let ramp = Ramp::new(Curve::SCurve, 0.25, 0.75, 1);
+    assert_eq!(ramp.gain_at(0), 0.25);
+    assert_eq!(ramp.gain_at(1), 0.75);
+
+    let ramp = Ramp::new(Curve::Exponential, 0.5, 1.0, 4);
+    assert_eq!(ramp.gain_at(0), 0.5);
+    assert_eq!(ramp.gain_at(3), 1.0);
+    assert_eq!(ramp.gain_at(usize::MAX), 1.0);
+}
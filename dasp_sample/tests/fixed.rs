@@ -0,0 +1,179 @@
+//! Tests for the fixed-point `Q15` and `Q31` sample types.
+
+/// Expands to a module of tests checking that conversions between the given fixed-point type and
+/// every other sample type match those of the integer type sharing its representation.
+macro_rules! fixed_tests {
+    ($Q:ident: $Rep:ident, $mod_name:ident { $($T:ty: $($s:expr),*;)* }) => {
+        mod $mod_name {
+            #[allow(unused_imports)]
+            use dasp_sample::{Sample, $Q, I24, I48, U24, U48};
+
+            #[test]
+            fn to_fixed() {
+                $(
+                    $(
+                        let s: $T = $s;
+                        assert_eq!(s.to_sample::<$Q>().to_bits(), s.to_sample::<$Rep>());
+                    )*
+                )*
+            }
+
+            #[test]
+            fn from_fixed() {
+                for &bits in [$Rep::MIN, $Rep::MIN / 2, -1, 0, 1, $Rep::MAX / 2, $Rep::MAX].iter() {
+                    let q = $Q::from_bits(bits);
+                    $(
+                        assert_eq!(q.to_sample::<$T>(), bits.to_sample::<$T>());
+                    )*
+                }
+            }
+        }
+    };
+}
+
+fixed_tests!(Q15: i16, q15_conv {
+    i8: i8::MIN, 0, 1, i8::MAX;
+    i16: i16::MIN, 0, 1, i16::MAX;
+    I24: I24::new_unchecked(-8_388_608), I24::new_unchecked(0), I24::new_unchecked(8_388_607);
+    i32: i32::MIN, 0, 65_536, i32::MAX;
+    I48: I48::new_unchecked(-140_737_488_355_328), I48::new_unchecked(0),
+        I48::new_unchecked(140_737_488_355_327);
+    i64: i64::MIN, 0, i64::MAX;
+    u8: 0, 128, 255;
+    u16: 0, 32_768, 65_535;
+    U24: U24::new_unchecked(0), U24::new_unchecked(8_388_608), U24::new_unchecked(16_777_215);
+    u32: 0, 2_147_483_648, u32::MAX;
+    U48: U48::new_unchecked(0), U48::new_unchecked(140_737_488_355_328),
+        U48::new_unchecked(281_474_976_710_655);
+    u64: 0, 9_223_372_036_854_775_808, u64::MAX;
+    f32: -1.0, -0.5, 0.0, 0.25, 1.0, 1.5;
+    f64: -1.0, -0.5, 0.0, 0.25, 1.0, 1.5;
+    dasp_sample::Q31: dasp_sample::Q31::from_bits(i32::MIN), dasp_sample::Q31::from_bits(0),
+        dasp_sample::Q31::from_bits(i32::MAX);
+});
+
+fixed_tests!(Q31: i32, q31_conv {
+    i8: i8::MIN, 0, 1, i8::MAX;
+    i16: i16::MIN, 0, 1, i16::MAX;
+    I24: I24::new_unchecked(-8_388_608), I24::new_unchecked(0), I24::new_unchecked(8_388_607);
+    i32: i32::MIN, 0, 65_536, i32::MAX;
+    I48: I48::new_unchecked(-140_737_488_355_328), I48::new_unchecked(0),
+        I48::new_unchecked(140_737_488_355_327);
+    i64: i64::MIN, 0, i64::MAX;
+    u8: 0, 128, 255;
+    u16: 0, 32_768, 65_535;
+    U24: U24::new_unchecked(0), U24::new_unchecked(8_388_608), U24::new_unchecked(16_777_215);
+    u32: 0, 2_147_483_648, u32::MAX;
+    U48: U48::new_unchecked(0), U48::new_unchecked(140_737_488_355_328),
+        U48::new_unchecked(281_474_976_710_655);
+    u64: 0, 9_223_372_036_854_775_808, u64::MAX;
+    f32: -1.0, -0.5, 0.0, 0.25, 1.0, 1.5;
+    f64: -1.0, -0.5, 0.0, 0.25, 1.0, 1.5;
+    dasp_sample::Q15: dasp_sample::Q15::from_bits(i16::MIN), dasp_sample::Q15::from_bits(0),
+        dasp_sample::Q15::from_bits(i16::MAX);
+});
+
+#[test]
+fn test_saturating_arithmetic() {
+    use dasp_sample::types::q15::{MAX, MIN};
+    use dasp_sample::Q15;
+    let half = Q15::from_bits(16_384);
+    assert_eq!(half + half, MAX);
+    assert_eq!(-half - half, MIN);
+    assert_eq!(MIN - half, MIN);
+    assert_eq!(-MIN, MAX);
+    assert_eq!(half * half, Q15::from_bits(8_192));
+    assert_eq!(MIN * MIN, MAX);
+    assert_eq!(MIN * half, -half);
+    // 3 * 3 / 32_768 = 0.000274..., which rounds to 0.
+    assert_eq!(Q15::from_bits(3) * Q15::from_bits(3), Q15::from_bits(0));
+    assert_eq!(Q15::from_bits(181) * Q15::from_bits(181), Q15::from_bits(1));
+    assert_eq!(Q15::from_bits(8_192) / half, half);
+    assert_eq!(half / Q15::from_bits(8_192), MAX);
+    assert_eq!(-half / Q15::from_bits(8_192), MIN);
+}
+
+#[test]
+fn test_q31_arithmetic() {
+    use dasp_sample::types::q31::{MAX, MIN};
+    use dasp_sample::Q31;
+    let half = Q31::from_bits(1 << 30);
+    let quarter = Q31::from_bits(1 << 29);
+    assert_eq!(half + half, MAX);
+    assert_eq!(MIN + -half, MIN);
+    assert_eq!(half * half, quarter);
+    assert_eq!(MIN * MIN, MAX);
+    assert_eq!(quarter / half, half);
+}
+
+#[test]
+fn test_sqrt() {
+    use dasp_sample::{FloatSample, Q15, Q31};
+    assert_eq!(Q15::from_bits(8_192).sample_sqrt(), Q15::from_bits(16_384));
+    assert_eq!(Q15::from_bits(-8_192).sample_sqrt(), Q15::from_bits(0));
+    assert_eq!(Q15::from_bits(1).sample_sqrt(), Q15::from_bits(181));
+    assert_eq!(
+        Q31::from_bits(1 << 29).sample_sqrt(),
+        Q31::from_bits(1 << 30)
+    );
+    assert_eq!(
+        Q31::from_bits(i32::MAX).sample_sqrt(),
+        Q31::from_bits(i32::MAX)
+    );
+}
+
+#[test]
+fn test_amplitude_ops() {
+    use dasp_sample::{FloatSample, Sample, Q15};
+    let s = Q15::from_bits(10_000);
+    assert_eq!(s.mul_amp(<Q15 as FloatSample>::IDENTITY), s);
+    assert_eq!(s.mul_amp(Q15::from_bits(16_384)), Q15::from_bits(5_000));
+    assert_eq!(s.add_amp(Q15::from_bits(-20_000)), Q15::from_bits(-10_000));
+    assert_eq!(s.add_amp(Q15::from_bits(30_000)), Q15::from_bits(i16::MAX));
+    assert_eq!(Q15::EQUILIBRIUM, Q15::from_bits(0));
+    assert_eq!(0.5f32.to_sample::<Q15>(), Q15::from_bits(16_384));
+}
+
+#[test]
+fn test_identity_is_exact() {
+    use dasp_sample::{FloatSample, Sample, Q15, Q31};
+    for bits in i16::MIN..=i16::MAX {
+        let s = Q15::from_bits(bits);
+        assert_eq!(s.mul_amp(<Q15 as FloatSample>::IDENTITY), s);
+        assert_eq!(<Q15 as FloatSample>::IDENTITY * s, s);
+    }
+    for &bits in &[
+        i32::MIN,
+        -1 << 30,
+        -1,
+        0,
+        1,
+        1 << 30,
+        i32::MAX - 1,
+        i32::MAX,
+    ] {
+        let s = Q31::from_bits(bits);
+        assert_eq!(s.mul_amp(<Q31 as FloatSample>::IDENTITY), s);
+        assert_eq!(<Q31 as FloatSample>::IDENTITY * s, s);
+    }
+}
+
+#[test]
+fn test_packed_bytes_and_dither() {
+    use dasp_sample::bytes::{self, BigEndian, Packed};
+    use dasp_sample::dither::{self, Dither, Ditherer, NoiseShaping};
+    use dasp_sample::{Q15, Q31};
+    let samples = [Q15::from_bits(-2), Q15::from_bits(0x1234)];
+    let mut encoded = [0; 4];
+    bytes::encode_slice::<Packed<Q15, BigEndian>>(&samples, &mut encoded);
+    assert_eq!(encoded, [0xFF, 0xFE, 0x12, 0x34]);
+    let mut decoded = [Q15::from_bits(0); 2];
+    bytes::decode_slice::<Packed<Q15, BigEndian>>(&encoded, &mut decoded);
+    assert_eq!(decoded, samples);
+    assert_eq!(dither::quantize::<Q31>(0.5), Q31::from_bits(1 << 30));
+    let mut ditherer = Ditherer::new(Dither::None, NoiseShaping::None, 0);
+    assert_eq!(
+        ditherer.quantize::<_, Q15>(-0.25f32),
+        Q15::from_bits(-8_192)
+    );
+}
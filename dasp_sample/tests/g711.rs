@@ -0,0 +1,124 @@
+use dasp_sample::bytes::{self, LittleEndian, Packed};
+use dasp_sample::conv::NominalRange;
+use dasp_sample::{ALaw, MuLaw, Sample, I24, U48};
+
+#[test]
+fn test_known_codes() {
+    assert_eq!(MuLaw::from_linear(0).to_bits(), 0xFF);
+    assert_eq!(MuLaw::from_linear(-1).to_bits(), 0x7E);
+    assert_eq!(MuLaw::from_linear(i16::MAX).to_bits(), 0x80);
+    assert_eq!(MuLaw::from_linear(i16::MIN).to_bits(), 0x00);
+    assert_eq!(MuLaw::from_bits(0x80).to_linear(), 32_124);
+    assert_eq!(MuLaw::from_bits(0x00).to_linear(), -32_124);
+    assert_eq!(MuLaw::from_bits(0x7F).to_linear(), 0);
+    assert_eq!(MuLaw::from_bits(0x7E).to_linear(), -8);
+
+    assert_eq!(ALaw::from_linear(0).to_bits(), 0xD5);
+    assert_eq!(ALaw::from_linear(-1).to_bits(), 0x55);
+    assert_eq!(ALaw::from_linear(i16::MAX).to_bits(), 0xAA);
+    assert_eq!(ALaw::from_linear(i16::MIN).to_bits(), 0x2A);
+    assert_eq!(ALaw::from_bits(0xAA).to_linear(), 32_256);
+    assert_eq!(ALaw::from_bits(0x2A).to_linear(), -32_256);
+    assert_eq!(ALaw::from_bits(0xD5).to_linear(), 8);
+    assert_eq!(ALaw::from_bits(0x55).to_linear(), -8);
+}
+
+#[test]
+fn test_every_code_round_trips() {
+    for bits in 0..=255u8 {
+        let mu = MuLaw::from_bits(bits);
+        assert_eq!(MuLaw::from_linear(mu.to_linear()), mu);
+        let a = ALaw::from_bits(bits);
+        assert_eq!(ALaw::from_linear(a.to_linear()).to_bits(), bits);
+    }
+    // Positive and negative zero are both μ-law codes and are equal.
+    assert_eq!(MuLaw::from_bits(0x7F), MuLaw::from_bits(0xFF));
+}
+
+#[test]
+fn test_encoding_is_monotonic() {
+    let mut prev_mu = MuLaw::from_linear(i16::MIN);
+    let mut prev_a = ALaw::from_linear(i16::MIN);
+    for s in i16::MIN..=i16::MAX {
+        let mu = MuLaw::from_linear(s);
+        let a = ALaw::from_linear(s);
+        assert!(mu >= prev_mu && a >= prev_a, "not monotonic at {}", s);
+        prev_mu = mu;
+        prev_a = a;
+    }
+}
+
+#[test]
+fn test_quantization_error_is_within_step() {
+    // The step size doubles with each of the 8 segments, up to 1024 for μ-law and A-law alike.
+    for s in (i16::MIN..=i16::MAX).step_by(7) {
+        let mu_error = (MuLaw::from_linear(s).to_linear() as i32 - s as i32).abs();
+        let a_error = (ALaw::from_linear(s).to_linear() as i32 - s as i32).abs();
+        let step = (s as i32).abs() / 16 + 16;
+        assert!(
+            mu_error <= step.max(644),
+            "μ-law error of {} at {}",
+            mu_error,
+            s
+        );
+        assert!(
+            a_error <= step.max(512),
+            "A-law error of {} at {}",
+            a_error,
+            s
+        );
+    }
+}
+
+#[test]
+fn test_sample_conversions() {
+    assert_eq!(MuLaw::EQUILIBRIUM.to_sample::<f32>(), 0.0);
+    assert_eq!(ALaw::EQUILIBRIUM.to_linear(), 8);
+    assert_eq!(
+        MuLaw::from_bits(0x80).to_sample::<f32>(),
+        32_124.0 / 32_768.0
+    );
+    assert_eq!(MuLaw::from_bits(0x80).to_sample::<u8>(), 253);
+    assert_eq!(
+        ALaw::from_bits(0xAA).to_sample::<I24>(),
+        I24::new_unchecked(32_256 << 8)
+    );
+    assert_eq!(
+        ALaw::from_bits(0x2A).to_sample::<U48>(),
+        U48::new_unchecked(512 << 32)
+    );
+    assert_eq!(0.0f64.to_sample::<MuLaw>().to_bits(), 0xFF);
+    assert_eq!(1.0f32.to_sample::<MuLaw>().to_bits(), 0x80);
+    assert_eq!((-1.0f32).to_sample::<ALaw>().to_bits(), 0x2A);
+    assert_eq!(
+        MuLaw::from_bits(0x80).to_sample::<ALaw>().to_linear(),
+        32_256
+    );
+    assert_eq!(MuLaw::from_bits(0x80).to_sample::<MuLaw>().to_bits(), 0x80);
+
+    let x = 0.3f32.to_sample::<ALaw>().to_sample::<f32>();
+    assert!((x - 0.3).abs() < 1.0 / 64.0);
+    let x = (-0.001f32).to_sample::<MuLaw>().to_sample::<f32>();
+    assert!((x + 0.001).abs() < 1.0 / 4096.0);
+    assert!(MuLaw::from_bits(0x12).is_in_range());
+}
+
+#[test]
+fn test_amplitude_ops() {
+    let quarter = 0.25f32.to_sample::<MuLaw>();
+    let sum = quarter.add_amp(quarter.to_signed_sample());
+    assert_eq!(sum, MuLaw::from_linear(quarter.to_linear() * 2));
+    let scaled = ALaw::from_bits(0xAA).mul_amp(0.5);
+    assert_eq!(scaled.to_linear(), 16_128);
+}
+
+#[test]
+fn test_packed_bytes() {
+    let codes = [0xFF, 0x80, 0x00, 0x12];
+    let mut samples = [MuLaw::EQUILIBRIUM; 4];
+    bytes::decode_slice::<Packed<MuLaw, LittleEndian>>(&codes, &mut samples);
+    assert_eq!(samples[1].to_linear(), 32_124);
+    let mut encoded = [0; 4];
+    bytes::encode_slice::<Packed<MuLaw, LittleEndian>>(&samples, &mut encoded);
+    assert_eq!(encoded, codes);
+}
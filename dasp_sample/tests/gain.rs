@@ -0,0 +1,70 @@
+use dasp_sample::{Decibels, Gain, IntoAmp, Sample, I24, Q15};
+
+fn assert_close(a: f64, b: f64) {
+    assert!((a - b).abs() < 1e-9, "{} != {}", a, b);
+}
+
+#[test]
+fn test_gain_decibels_round_trip() {
+    for &db in [-120.0, -60.0, -6.0, -0.5, 0.0, 3.0, 12.0, 48.0].iter() {
+        let gain = Gain::from(Decibels(db));
+        assert_close(Decibels::from(gain).0, db);
+    }
+    assert_close(Decibels(-20.0).to_gain().0, 0.1);
+    assert_close(Gain(2.0).to_decibels().0, 6.020_599_913_279_624);
+    assert_eq!(Gain::from_decibels(Decibels::UNITY), Gain::UNITY);
+    assert_eq!(Gain::from_decibels(Decibels::SILENCE), Gain::SILENCE);
+    assert_eq!(Decibels::from_gain(Gain::SILENCE), Decibels::SILENCE);
+    assert_eq!(Gain(-1.0).to_decibels(), Decibels::UNITY);
+}
+
+#[test]
+fn test_arithmetic() {
+    assert_close(
+        (Gain::from(Decibels(-6.0)) * Gain::from(Decibels(-14.0))).0,
+        0.1,
+    );
+    assert_close(
+        (Gain::from(Decibels(6.0)) / Gain::from(Decibels(6.0))).0,
+        1.0,
+    );
+    assert_eq!(Decibels(-6.0) - Decibels(6.0), Decibels(-12.0));
+    assert_eq!(-Decibels(6.0), Decibels(-6.0));
+}
+
+#[test]
+fn test_dbfs_of_samples() {
+    assert_eq!(Decibels::from_sample(i16::MIN), Decibels::UNITY);
+    assert_eq!(Decibels::from_sample(-1.0f64), Decibels::UNITY);
+    assert_eq!(Decibels::from_sample(0i16), Decibels::SILENCE);
+    assert_eq!(Decibels::from_sample(128u8), Decibels::SILENCE);
+    assert_close(Decibels::from_sample(16_384i16).0, -6.020_599_913_279_624);
+    assert_close(
+        Decibels::from_sample(0x40_0000u32 << 8).0,
+        -6.020_599_913_279_624,
+    );
+    assert_close(Decibels::from_sample(-0.1f64).0, -20.0);
+    assert_eq!(Gain::from_sample(I24::new_unchecked(-4_194_304)), Gain(0.5));
+    assert_eq!(Gain::from_sample(192u8), Gain(0.5));
+    assert_eq!(Gain::from_sample(Q15::from_bits(-8_192)), Gain(0.25));
+}
+
+#[test]
+fn test_samples_at_level() {
+    assert_eq!(Decibels(-20.0).to_sample::<i16>(), 3_276);
+    assert_eq!(Decibels::SILENCE.to_sample::<u8>(), 128);
+    assert_eq!(Decibels::UNITY.to_sample::<i16>(), i16::MAX);
+    assert_eq!(Decibels(6.0).to_sample::<i8>(), i8::MAX);
+    assert_close(Decibels(6.0).to_sample::<f64>(), 1.995_262_314_968_879_5);
+    assert_eq!(Gain(0.5).to_sample::<i16>(), 16_384);
+    assert_eq!(Gain(-0.5).to_sample::<f32>(), 0.5);
+}
+
+#[test]
+fn test_into_amp() {
+    assert_eq!(IntoAmp::<f32>::into_amp(0.25f32), 0.25);
+    assert_eq!(IntoAmp::<f32>::into_amp(Gain(0.25)), 0.25);
+    assert_eq!(IntoAmp::<f64>::into_amp(Decibels::UNITY), 1.0);
+    assert_eq!(IntoAmp::<Q15>::into_amp(Gain(0.5)), Q15::from_bits(16_384));
+    assert_eq!(1_000i16.mul_amp(Decibels(-20.0).into_amp()), 100);
+}
@@ -0,0 +1,142 @@
+//! Tests for conversions between the half precision `f16` and `bf16` types and every other sample
+//! type available within this crate.
+//!
+//! As in `conv.rs`, we assert that each sample type's minimum, maximum and centre are correctly
+//! converted to and from the min, max and centre of the half precision types.
+
+#![cfg(feature = "half")]
+
+/// Expands to a module of tests checking conversions between the given half precision type and
+/// every other sample type.
+///
+/// Each type is given as its type, its minimum, its equilibrium and its maximum.
+macro_rules! half_tests {
+    ($H:ident, $mod_name:ident { $($T:ty: $min:expr, $eq:expr, $max:expr;)* }) => {
+        mod $mod_name {
+            #[allow(unused_imports)]
+            use dasp_sample::{$H, FloatSample, Sample, I24, I48, U24, U48};
+
+            #[test]
+            fn to_half() {
+                $(
+                    let min: $T = $min;
+                    assert_eq!(min.to_sample::<$H>(), $H::from_f32(-1.0));
+                    let eq: $T = $eq;
+                    assert_eq!(eq.to_sample::<$H>(), $H::ZERO);
+                    let max: $T = $max;
+                    let max = max.to_sample::<$H>();
+                    assert!(max > $H::from_f32(0.99) && max <= $H::ONE);
+                )*
+            }
+
+            #[test]
+            fn from_half() {
+                $(
+                    assert_eq!($H::from_f32(-1.0).to_sample::<$T>(), $min);
+                    assert_eq!($H::ZERO.to_sample::<$T>(), $eq);
+                    assert_eq!($H::ONE.to_sample::<$T>(), $max);
+                )*
+            }
+
+            #[test]
+            fn round_trip() {
+                $(
+                    let min: $T = $min;
+                    assert_eq!(min.to_sample::<$H>().to_sample::<$T>(), $min);
+                    let eq: $T = $eq;
+                    assert_eq!(eq.to_sample::<$H>().to_sample::<$T>(), $eq);
+                    let max: $T = $max;
+                    assert_eq!(max.to_sample::<$H>().to_sample::<$T>(), $max);
+                )*
+            }
+
+            #[test]
+            fn float_sample() {
+                assert_eq!($H::EQUILIBRIUM, $H::ZERO);
+                assert_eq!(<$H as FloatSample>::IDENTITY, $H::ONE);
+                assert_eq!($H::from_f32(0.25).sample_sqrt(), $H::from_f32(0.5));
+                let half = $H::from_f32(0.5);
+                assert_eq!(half.add_amp(half), $H::ONE);
+                assert_eq!(half.mul_amp(half), $H::from_f32(0.25));
+                assert_eq!((-1.0f32).to_sample::<$H>().to_sample::<f64>(), -1.0);
+            }
+
+            #[test]
+            fn policies() {
+                assert_eq!($H::from_f32(1.5).to_sample_saturating::<i16>(), i16::MAX);
+                assert_eq!($H::from_f32(1.5).to_sample_wrapping::<f32>(), -0.5);
+                assert_eq!($H::from_f32(1.5).to_sample_checked::<u8>(), None);
+                assert_eq!($H::NAN.to_sample_saturating::<$H>(), $H::ZERO);
+                assert_eq!($H::from_f32(-0.5).to_sample_checked::<u8>(), Some(64));
+            }
+        }
+    };
+}
+
+half_tests!(f16, f16_tests {
+    i8: -128, 0, 127;
+    i16: -32_768, 0, 32_767;
+    I24: I24::new_unchecked(-8_388_608), I24::new_unchecked(0), I24::new_unchecked(8_388_607);
+    i32: -2_147_483_648, 0, 2_147_483_647;
+    I48: I48::new_unchecked(-140_737_488_355_328), I48::new_unchecked(0),
+        I48::new_unchecked(140_737_488_355_327);
+    i64: i64::MIN, 0, i64::MAX;
+    u8: 0, 128, 255;
+    u16: 0, 32_768, 65_535;
+    U24: U24::new_unchecked(0), U24::new_unchecked(8_388_608), U24::new_unchecked(16_777_215);
+    u32: 0, 2_147_483_648, u32::MAX;
+    U48: U48::new_unchecked(0), U48::new_unchecked(140_737_488_355_328),
+        U48::new_unchecked(281_474_976_710_655);
+    u64: 0, 9_223_372_036_854_775_808, u64::MAX;
+    f32: -1.0, 0.0, 1.0;
+    f64: -1.0, 0.0, 1.0;
+    dasp_sample::bf16: dasp_sample::bf16::from_f32(-1.0), dasp_sample::bf16::ZERO,
+        dasp_sample::bf16::ONE;
+});
+
+half_tests!(bf16, bf16_tests {
+    i8: -128, 0, 127;
+    i16: -32_768, 0, 32_767;
+    I24: I24::new_unchecked(-8_388_608), I24::new_unchecked(0), I24::new_unchecked(8_388_607);
+    i32: -2_147_483_648, 0, 2_147_483_647;
+    I48: I48::new_unchecked(-140_737_488_355_328), I48::new_unchecked(0),
+        I48::new_unchecked(140_737_488_355_327);
+    i64: i64::MIN, 0, i64::MAX;
+    u8: 0, 128, 255;
+    u16: 0, 32_768, 65_535;
+    U24: U24::new_unchecked(0), U24::new_unchecked(8_388_608), U24::new_unchecked(16_777_215);
+    u32: 0, 2_147_483_648, u32::MAX;
+    U48: U48::new_unchecked(0), U48::new_unchecked(140_737_488_355_328),
+        U48::new_unchecked(281_474_976_710_655);
+    u64: 0, 9_223_372_036_854_775_808, u64::MAX;
+    f32: -1.0, 0.0, 1.0;
+    f64: -1.0, 0.0, 1.0;
+    dasp_sample::f16: dasp_sample::f16::from_f32(-1.0), dasp_sample::f16::ZERO,
+        dasp_sample::f16::ONE;
+});
+
+#[test]
+fn test_conv_fns() {
+    use dasp_sample::conv;
+    use dasp_sample::{bf16, f16};
+    assert_eq!(conv::i16::to_f16(-16_384), f16::from_f32(-0.5));
+    assert_eq!(conv::u8::to_bf16(192), bf16::from_f32(0.5));
+    assert_eq!(conv::f64::to_f16(0.1), f16::from_f64(0.1));
+    assert_eq!(conv::f16::to_i16(f16::from_f32(0.5)), 16_384);
+    assert_eq!(conv::bf16::to_u16(bf16::from_f32(-0.5)), 16_384);
+    assert_eq!(
+        conv::f16::to_bf16(f16::from_f32(0.75)),
+        bf16::from_f32(0.75)
+    );
+    assert_eq!(
+        conv::bf16::to_f16(bf16::from_f32(0.75)),
+        f16::from_f32(0.75)
+    );
+}
+
+#[test]
+fn test_companded() {
+    use dasp_sample::{f16, MuLaw, Sample};
+    let x = f16::from_f32(0.5).to_sample::<MuLaw>();
+    assert!((x.to_sample::<f16>().to_f32() - 0.5).abs() < 1.0 / 64.0);
+}
@@ -0,0 +1,77 @@
+use dasp_sample::sanitize::{Counts, Fault, NonFinite, Sanitizer};
+use dasp_sample::{FloatSample, Q15};
+
+#[test]
+fn test_classify() {
+    assert_eq!(Fault::of(0.0f32), None);
+    assert_eq!(Fault::of(-0.0f64), None);
+    assert_eq!(Fault::of(f32::MIN_POSITIVE), None);
+    assert_eq!(Fault::of(f32::MIN_POSITIVE / 2.0), Some(Fault::Subnormal));
+    assert_eq!(Fault::of(-f64::MIN_POSITIVE / 2.0), Some(Fault::Subnormal));
+    assert_eq!(Fault::of(f32::NAN), Some(Fault::Nan));
+    assert_eq!(Fault::of(f64::NEG_INFINITY), Some(Fault::Infinite));
+    assert_eq!(Fault::of(Q15::from_bits(1)), None);
+    assert!(1.0f32.sample_is_finite());
+    assert!(!f64::INFINITY.sample_is_finite());
+    assert!(Q15::from_bits(i16::MIN).sample_is_finite());
+    assert!(!Q15::from_bits(1).sample_is_subnormal());
+}
+
+#[test]
+fn test_policies() {
+    let keep = Sanitizer::new(NonFinite::Keep, false);
+    assert!(keep.sanitize(f32::NAN).0.is_nan());
+    assert_eq!(
+        keep.sanitize(f32::INFINITY),
+        (f32::INFINITY, Some(Fault::Infinite))
+    );
+    assert_eq!(keep.sanitize(1e-40f32), (1e-40, Some(Fault::Subnormal)));
+
+    let equilibrium = Sanitizer::new(NonFinite::Equilibrium, true);
+    assert_eq!(equilibrium, Sanitizer::default());
+    assert_eq!(equilibrium.sanitize(f64::NAN), (0.0, Some(Fault::Nan)));
+    assert_eq!(
+        equilibrium.sanitize(f64::NEG_INFINITY),
+        (0.0, Some(Fault::Infinite))
+    );
+    assert_eq!(
+        equilibrium.sanitize(1e-310f64),
+        (0.0, Some(Fault::Subnormal))
+    );
+    assert_eq!(equilibrium.sanitize(-0.75f64), (-0.75, None));
+
+    let clamp = Sanitizer::new(NonFinite::Clamp, false);
+    assert_eq!(clamp.sanitize(f32::NAN), (0.0, Some(Fault::Nan)));
+    assert_eq!(clamp.sanitize(f32::INFINITY), (1.0, Some(Fault::Infinite)));
+    assert_eq!(
+        clamp.sanitize(f32::NEG_INFINITY),
+        (-1.0, Some(Fault::Infinite))
+    );
+    assert_eq!(clamp.sanitize(-1e-40f32), (-1e-40, Some(Fault::Subnormal)));
+}
+
+#[test]
+fn test_counts() {
+    let mut counts = Counts::default();
+    assert_eq!(counts.total(), 0);
+    assert!(!counts.has_non_finite());
+    counts.record(Fault::Subnormal);
+    assert!(!counts.has_non_finite());
+    counts.record(Fault::Nan);
+    counts.record(Fault::Nan);
+    assert!(counts.has_non_finite());
+    counts += Counts {
+        nan: 0,
+        infinite: 3,
+        subnormal: 1,
+    };
+    assert_eq!(
+        counts,
+        Counts {
+            nan: 2,
+            infinite: 3,
+            subnormal: 2,
+        }
+    );
+    assert_eq!(counts.total(), 7);
+}
@@ -0,0 +1,173 @@
+//! An extension to the **Signal** trait that requantizes a signal to a lower bit-depth with
+//! dither and optional noise shaping.
+//!
+//! ### Required Features
+//!
+//! - When using `dasp_signal`, this module requires the **dither** feature to be enabled.
+//! - When using `dasp`, this module requires the **signal-dither** feature to be enabled.
+
+use crate::Signal;
+use dasp_frame::Frame;
+use dasp_sample::dither::{self, Ditherer, Quantize, MAX_SHAPING_ORDER};
+use dasp_sample::Sample;
+
+#[cfg(not(feature = "std"))]
+type Vec<T> = alloc::vec::Vec<T>;
+#[cfg(feature = "std")]
+type Vec<T> = std::vec::Vec<T>;
+
+/// An extension to the **Signal** trait that requantizes a signal to a lower bit-depth with
+/// dither and optional noise shaping.
+///
+/// ### Required Features
+///
+/// - When using `dasp_signal`, this item requires the **dither** feature to be enabled.
+/// - When using `dasp`, this item requires the **signal-dither** feature to be enabled.
+pub trait SignalDither: Signal {
+    /// An adaptor that requantizes each frame of the signal to the frame type `F` using the given
+    /// **Ditherer**.
+    ///
+    /// The dither, noise shaping filter and random number generator of the given **Ditherer**
+    /// are used for all channels, while the quantization error of each channel is tracked
+    /// separately.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dasp_sample::dither::{Dither, Ditherer, NoiseShaping};
+    /// use dasp_signal::{self as signal, Signal};
+    /// use dasp_signal::dither::SignalDither;
+    ///
+    /// fn main() {
+    ///     let ditherer = Ditherer::new(Dither::Tpdf, NoiseShaping::Lipshitz, 0);
+    ///     let mono = signal::from_iter(vec![0.0, 0.5, -0.5, 0.0]);
+    ///     let dithered: Vec<i16> = mono.dither_to::<i16>(ditherer.clone()).take(4).collect();
+    ///     assert!((dithered[1] - 16_384).abs() < 16);
+    ///
+    ///     let stereo = signal::from_iter(vec![[0.25, -0.25], [0.5, -0.5]]);
+    ///     let dithered: Vec<[u8; 2]> = stereo.dither_to::<[u8; 2]>(ditherer).take(2).collect();
+    ///     assert!((dithered[0][0] as i16 - 160).abs() < 4);
+    ///     assert!((dithered[0][1] as i16 - 96).abs() < 4);
+    /// }
+    /// ```
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_signal`, this item requires the **dither** feature to be enabled.
+    /// - When using `dasp`, this item requires the **signal-dither** feature to be enabled.
+    fn dither_to<F>(self, ditherer: Ditherer) -> DitherTo<Self, F>
+    where
+        Self: Sized,
+        F: Frame<NumChannels = <Self::Frame as Frame>::NumChannels>,
+        F::Sample: Quantize,
+    {
+        let channels = <Self::Frame as Frame>::CHANNELS;
+        DitherTo {
+            signal: self,
+            ditherer,
+            errors: zeros(channels * MAX_SHAPING_ORDER),
+            prev_noise: zeros(channels),
+            frame: core::marker::PhantomData,
+        }
+    }
+}
+
+/// An adaptor that requantizes each frame of the signal to the frame type `F` with dither and
+/// optional noise shaping.
+///
+/// ### Required Features
+///
+/// - When using `dasp_signal`, this item requires the **dither** feature to be enabled.
+/// - When using `dasp`, this item requires the **signal-dither** feature to be enabled.
+#[derive(Clone)]
+pub struct DitherTo<S, F>
+where
+    S: Signal,
+{
+    signal: S,
+    ditherer: Ditherer,
+    // The quantization error history of each channel, most recent first, in consecutive runs of
+    // `MAX_SHAPING_ORDER` errors. Kept in `f64`, as in `Ditherer`, so that rounding the feedback
+    // does not colour the shaped noise.
+    errors: Vec<f64>,
+    // The previous random value of each channel, used by high-pass dither.
+    prev_noise: Vec<f64>,
+    frame: core::marker::PhantomData<F>,
+}
+
+impl<S, F> DitherTo<S, F>
+where
+    S: Signal,
+{
+    /// Borrows the **Ditherer** used by the adaptor.
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_signal`, this item requires the **dither** feature to be enabled.
+    /// - When using `dasp`, this item requires the **signal-dither** feature to be enabled.
+    pub fn ditherer(&self) -> &Ditherer {
+        &self.ditherer
+    }
+
+    /// Consumes the `DitherTo` adaptor and returns its inner signal `S` and **Ditherer**.
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_signal`, this item requires the **dither** feature to be enabled.
+    /// - When using `dasp`, this item requires the **signal-dither** feature to be enabled.
+    pub fn into_parts(self) -> (S, Ditherer) {
+        let DitherTo {
+            signal, ditherer, ..
+        } = self;
+        (signal, ditherer)
+    }
+}
+
+impl<S, F> Signal for DitherTo<S, F>
+where
+    S: Signal,
+    F: Frame<NumChannels = <S::Frame as Frame>::NumChannels>,
+    F::Sample: Quantize,
+{
+    type Frame = F;
+
+    fn next(&mut self) -> Self::Frame {
+        let frame = self.signal.next();
+        let shaping = self.ditherer.shaping();
+        let DitherTo {
+            ref mut ditherer,
+            ref mut errors,
+            ref mut prev_noise,
+            ..
+        } = *self;
+        let quantized = F::from_fn(|channel| {
+            let x = frame
+                .channel(channel)
+                .unwrap()
+                .to_float_sample()
+                .to_sample::<f64>();
+            let start = channel * MAX_SHAPING_ORDER;
+            let history = &mut errors[start..start + MAX_SHAPING_ORDER];
+            let feedback = shaping.feedback(history.iter().cloned());
+            let noise = ditherer.next_noise(&mut prev_noise[channel]);
+            let (quantized, error) = dither::quantize_shaped(x, noise, feedback);
+            history.rotate_right(1);
+            history[0] = error;
+            quantized
+        });
+        quantized
+    }
+
+    fn is_exhausted(&self) -> bool {
+        self.signal.is_exhausted()
+    }
+}
+
+impl<T> SignalDither for T where T: Signal {}
+
+// A `Vec` of `len` zeroes.
+fn zeros(len: usize) -> Vec<f64> {
+    let mut vec = Vec::with_capacity(len);
+    vec.resize(len, 0.0);
+    vec
+}
@@ -79,10 +79,13 @@ where
 
     /// Update the `source_to_target_ratio` internally given a new **playback rate** multiplier.
     ///
-    /// This method is useful for dynamically changing rates.
+    /// This method is useful for dynamically changing rates. The new multiplier is also passed to
+    /// `Interpolator::set_playback_hz_scale` so that interpolators that depend on the conversion
+    /// ratio remain in sync.
     #[inline]
     pub fn set_playback_hz_scale(&mut self, scale: f64) {
         self.source_to_target_ratio = scale;
+        self.interpolator.set_playback_hz_scale(scale);
     }
 
     /// Update the `source_to_target_ratio` internally given a new **sample rate** multiplier.
@@ -99,6 +102,18 @@ where
         &self.source
     }
 
+    /// Borrow the `Interpolator` from the `Converter`.
+    #[inline]
+    pub fn interpolator(&self) -> &I {
+        &self.interpolator
+    }
+
+    /// Mutably borrow the `Interpolator` from the `Converter`.
+    #[inline]
+    pub fn interpolator_mut(&mut self) -> &mut I {
+        &mut self.interpolator
+    }
+
     /// Mutably borrow the `source_frames` Iterator from the `Converter`.
     #[inline]
     pub fn source_mut(&mut self) -> &mut S {
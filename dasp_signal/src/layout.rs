@@ -0,0 +1,235 @@
+//! An extension to the **Signal** trait that attaches a **ChannelLayout** to a signal and
+//! reorders its channels between layouts.
+//!
+//! ### Required Features
+//!
+//! - When using `dasp_signal`, this module requires the **layout** feature to be enabled.
+//! - When using `dasp`, this module requires the **signal-layout** feature to be enabled.
+
+use crate::Signal;
+use dasp_frame::layout::{ChannelLayout, ChannelMap};
+use dasp_frame::Frame;
+
+/// An extension to the **Signal** trait that attaches a **ChannelLayout** to a signal and
+/// reorders its channels between layouts.
+///
+/// ### Required Features
+///
+/// - When using `dasp_signal`, this item requires the **layout** feature to be enabled.
+/// - When using `dasp`, this item requires the **signal-layout** feature to be enabled.
+pub trait SignalLayout: Signal {
+    /// Attach the given **ChannelLayout** to the signal, describing the speaker position of each
+    /// channel of its frames.
+    ///
+    /// The resulting signal yields the same frames as the original.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dasp_frame::layout::{ChannelLayout, Speaker, Standard};
+    /// use dasp_signal::{self as signal, Signal};
+    /// use dasp_signal::layout::SignalLayout;
+    ///
+    /// fn main() {
+    ///     let frames = vec![[0.1, 0.2], [0.3, 0.4]];
+    ///     let signal = signal::from_iter(frames).with_layout(Standard::Stereo.into());
+    ///     assert_eq!(signal.layout().index_of(Speaker::FrontRight), Some(1));
+    ///
+    ///     let mono = ChannelLayout::from(Standard::Mono);
+    ///     let mut mono = signal.reorder_to::<[f32; 1]>(mono);
+    ///     assert_eq!(mono.next(), [0.0]);
+    /// }
+    /// ```
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_signal`, this item requires the **layout** feature to be enabled.
+    /// - When using `dasp`, this item requires the **signal-layout** feature to be enabled.
+    fn with_layout(self, layout: ChannelLayout) -> WithLayout<Self>
+    where
+        Self: Sized,
+    {
+        WithLayout {
+            signal: self,
+            layout,
+        }
+    }
+
+    /// An adaptor that rearranges each frame of the signal from the `from` layout into the `to`
+    /// layout, yielding frames of type `F`.
+    ///
+    /// Channels of the `to` layout that are not present within the `from` layout are set to the
+    /// equilibrium.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dasp_frame::layout::{ChannelLayout, ChannelOrder, Standard};
+    /// use dasp_signal::{self as signal, Signal};
+    /// use dasp_signal::layout::SignalLayout;
+    ///
+    /// fn main() {
+    ///     let smpte = ChannelLayout::standard(Standard::Surround51, ChannelOrder::Smpte);
+    ///     let film = ChannelLayout::standard(Standard::Surround51, ChannelOrder::Film);
+    ///     let frames = vec![[1, 2, 3, 4, 5, 6]];
+    ///     let mut signal = signal::from_iter(frames).reorder_channels::<[i16; 6]>(smpte, film);
+    ///     assert_eq!(signal.next(), [1, 3, 2, 5, 6, 4]);
+    /// }
+    /// ```
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_signal`, this item requires the **layout** feature to be enabled.
+    /// - When using `dasp`, this item requires the **signal-layout** feature to be enabled.
+    fn reorder_channels<F>(self, from: ChannelLayout, to: ChannelLayout) -> Reorder<Self, F>
+    where
+        Self: Sized,
+        F: Frame<Sample = <Self::Frame as Frame>::Sample>,
+    {
+        Reorder {
+            signal: self,
+            map: from.map_to(&to),
+            layout: to,
+            frame: core::marker::PhantomData,
+        }
+    }
+}
+
+/// A signal with an attached **ChannelLayout**.
+///
+/// ### Required Features
+///
+/// - When using `dasp_signal`, this item requires the **layout** feature to be enabled.
+/// - When using `dasp`, this item requires the **signal-layout** feature to be enabled.
+#[derive(Clone)]
+pub struct WithLayout<S> {
+    signal: S,
+    layout: ChannelLayout,
+}
+
+/// An adaptor that rearranges the channels of each frame from one **ChannelLayout** into another.
+///
+/// ### Required Features
+///
+/// - When using `dasp_signal`, this item requires the **layout** feature to be enabled.
+/// - When using `dasp`, this item requires the **signal-layout** feature to be enabled.
+#[derive(Clone)]
+pub struct Reorder<S, F> {
+    signal: S,
+    map: ChannelMap,
+    layout: ChannelLayout,
+    frame: core::marker::PhantomData<F>,
+}
+
+impl<S> WithLayout<S> {
+    /// The layout of the signal's channels.
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_signal`, this item requires the **layout** feature to be enabled.
+    /// - When using `dasp`, this item requires the **signal-layout** feature to be enabled.
+    pub fn layout(&self) -> ChannelLayout {
+        self.layout
+    }
+
+    /// Rearrange each frame of the signal into the given layout, yielding frames of type `F`.
+    ///
+    /// The same as **reorder_channels** with the attached layout as the source.
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_signal`, this item requires the **layout** feature to be enabled.
+    /// - When using `dasp`, this item requires the **signal-layout** feature to be enabled.
+    pub fn reorder_to<F>(self, layout: ChannelLayout) -> Reorder<S, F>
+    where
+        S: Signal,
+        F: Frame<Sample = <S::Frame as Frame>::Sample>,
+    {
+        let WithLayout {
+            signal,
+            layout: from,
+        } = self;
+        signal.reorder_channels(from, layout)
+    }
+
+    /// Consumes the `WithLayout` and returns the inner signal `S` and its layout.
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_signal`, this item requires the **layout** feature to be enabled.
+    /// - When using `dasp`, this item requires the **signal-layout** feature to be enabled.
+    pub fn into_parts(self) -> (S, ChannelLayout) {
+        let WithLayout { signal, layout } = self;
+        (signal, layout)
+    }
+}
+
+impl<S, F> Reorder<S, F> {
+    /// The layout of the yielded frames.
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_signal`, this item requires the **layout** feature to be enabled.
+    /// - When using `dasp`, this item requires the **signal-layout** feature to be enabled.
+    pub fn layout(&self) -> ChannelLayout {
+        self.layout
+    }
+
+    /// The mapping applied to each frame.
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_signal`, this item requires the **layout** feature to be enabled.
+    /// - When using `dasp`, this item requires the **signal-layout** feature to be enabled.
+    pub fn map(&self) -> ChannelMap {
+        self.map
+    }
+
+    /// Consumes the `Reorder` adaptor and returns the inner signal `S` and the applied mapping.
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_signal`, this item requires the **layout** feature to be enabled.
+    /// - When using `dasp`, this item requires the **signal-layout** feature to be enabled.
+    pub fn into_parts(self) -> (S, ChannelMap) {
+        let Reorder { signal, map, .. } = self;
+        (signal, map)
+    }
+}
+
+impl<S> Signal for WithLayout<S>
+where
+    S: Signal,
+{
+    type Frame = S::Frame;
+
+    #[inline]
+    fn next(&mut self) -> Self::Frame {
+        self.signal.next()
+    }
+
+    #[inline]
+    fn is_exhausted(&self) -> bool {
+        self.signal.is_exhausted()
+    }
+}
+
+impl<S, F> Signal for Reorder<S, F>
+where
+    S: Signal,
+    F: Frame<Sample = <S::Frame as Frame>::Sample>,
+{
+    type Frame = F;
+
+    #[inline]
+    fn next(&mut self) -> Self::Frame {
+        self.map.apply(self.signal.next())
+    }
+
+    #[inline]
+    fn is_exhausted(&self) -> bool {
+        self.signal.is_exhausted()
+    }
+}
+
+impl<T> SignalLayout for T where T: Signal {}
@@ -26,10 +26,20 @@
 //!   implementation for `Box<dyn Signal>`.
 //! - The **bus** feature (or **signal-bus** feature if using `dasp`) provides the
 //!   [**SignalBus**](./bus/trait.SignalBus.html) trait.
+//! - The **dither** feature (or **signal-dither** feature if using `dasp`) provides the
+//!   [**SignalDither**](./dither/trait.SignalDither.html) trait.
 //! - The **envelope** feature (or **signal-envelope** feature if using `dasp`) provides the
 //!   [**SignalEnvelope**](./envelope/trait.SignalEnvelope.html) trait.
+//! - The **layout** feature (or **signal-layout** feature if using `dasp`) provides the
+//!   [**SignalLayout**](./layout/trait.SignalLayout.html) trait.
+//! - The **rational** feature (or **signal-rational** feature if using `dasp`) provides the
+//!   [**SignalRational**](./rational/trait.SignalRational.html) trait.
 //! - The **rms** feature (or **signal-rms** feature if using `dasp`) provides the
 //!   [**SignalRms**](./rms/trait.SignalRms.html) trait.
+//! - The **sanitize** feature (or **signal-sanitize** feature if using `dasp`) provides the
+//!   [**SignalSanitize**](./sanitize/trait.SignalSanitize.html) trait.
+//! - The **stereo** feature (or **signal-stereo** feature if using `dasp`) provides the
+//!   [**SignalStereo**](./stereo/trait.SignalStereo.html) trait.
 //! - The **window** feature (or **signal-window** feature if using `dasp`) provides the
 //!   [**window**](./window/index.html) module.
 //!
@@ -48,10 +58,12 @@ extern crate alloc;
 
 use core;
 use core::cell::RefCell;
-use dasp_frame::Frame;
+use dasp_frame::remix::Matrix;
+use dasp_frame::{DynFrame, FixedChannels, Frame, NChannels};
 use dasp_interpolate::Interpolator;
 use dasp_ring_buffer as ring_buffer;
-use dasp_sample::{Duplex, Sample};
+use dasp_sample::fade::{Curve, Ramp};
+use dasp_sample::{Duplex, Gain, IntoAmp, Sample};
 use interpolate::Converter;
 
 pub mod interpolate;
@@ -61,10 +73,20 @@ mod ops;
 mod boxed;
 #[cfg(feature = "bus")]
 pub mod bus;
+#[cfg(feature = "dither")]
+pub mod dither;
 #[cfg(feature = "envelope")]
 pub mod envelope;
+#[cfg(feature = "layout")]
+pub mod layout;
+#[cfg(feature = "rational")]
+pub mod rational;
 #[cfg(feature = "rms")]
 pub mod rms;
+#[cfg(feature = "sanitize")]
+pub mod sanitize;
+#[cfg(feature = "stereo")]
+pub mod stereo;
 #[cfg(feature = "window")]
 pub mod window;
 
@@ -351,14 +373,29 @@ pub trait Signal {
     ///     assert_eq!(scaled, vec![0.4, -1.0, -0.8, 0.6]);
     /// }
     /// ```
+    ///
+    /// The amplitude may also be given as a linear `Gain` or in `Decibels`.
+    ///
+    /// ```rust
+    /// use dasp_sample::Decibels;
+    /// use dasp_signal::{self as signal, Signal};
+    ///
+    /// fn main() {
+    ///     let frames = [[1_000i16, -1_000], [-2_000, 2_000]];
+    ///     let signal = signal::from_iter(frames.iter().cloned());
+    ///     let scaled: Vec<_> = signal.scale_amp(Decibels(-20.0)).take(2).collect();
+    ///     assert_eq!(scaled, vec![[100, -100], [-200, 200]]);
+    /// }
+    /// ```
     #[inline]
-    fn scale_amp(self, amp: <<Self::Frame as Frame>::Sample as Sample>::Float) -> ScaleAmp<Self>
+    fn scale_amp<A>(self, amp: A) -> ScaleAmp<Self>
     where
         Self: Sized,
+        A: IntoAmp<<<Self::Frame as Frame>::Sample as Sample>::Float>,
     {
         ScaleAmp {
             signal: self,
-            amp: amp,
+            amp: amp.into_amp(),
         }
     }
 
@@ -422,6 +459,171 @@ pub trait Signal {
         }
     }
 
+    /// Produces a new `Signal` that mixes every `Frame` in `self` into a frame of `O` channels
+    /// using the given matrix of mixing coefficients.
+    ///
+    /// See the `dasp_frame::remix` module for the standard downmix matrices and for deriving
+    /// matrices from channel layouts.
+    ///
+    /// `Self::Frame` must have exactly `I` channels, which is checked at compile time.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use dasp_frame::remix::Matrix;
+    /// use dasp_signal::{self as signal, Signal};
+    ///
+    /// fn main() {
+    ///     // Front left, front right, center, LFE, side left, side right.
+    ///     let frames = [[0.5f32, -0.5, 0.0, 1.0, 0.0, 0.0], [0.0, 0.0, 0.5, 1.0, 0.0, 0.0]];
+    ///     let signal = signal::from_iter(frames.iter().cloned());
+    ///     let mut stereo = signal.remix(Matrix::surround51_to_stereo());
+    ///     assert_eq!(stereo.next(), [0.5, -0.5]);
+    ///     let [l, r] = stereo.next();
+    ///     assert!((l - 0.35355339).abs() < 1e-6 && l == r);
+    /// }
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use dasp_frame::remix::Matrix;
+    /// use dasp_signal::{self as signal, Signal};
+    ///
+    /// fn main() {
+    ///     signal::equilibrium::<[f32; 2]>().remix(Matrix::surround51_to_stereo());
+    /// }
+    /// ```
+    #[inline]
+    fn remix<const I: usize, const O: usize>(self, matrix: Matrix<I, O>) -> Remix<Self, I, O>
+    where
+        Self: Sized,
+        Self::Frame: Frame<NumChannels = NChannels<I>>,
+    {
+        Remix {
+            signal: self,
+            matrix,
+        }
+    }
+
+    /// Produces a new `Signal` that fades `self` in from silence to unity gain over the given
+    /// number of frames following the given curve.
+    ///
+    /// The first frame is silenced and every frame from the end of the fade onwards is left
+    /// unchanged. See `dasp_sample::fade` for the available curves.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use dasp_sample::fade::Curve;
+    /// use dasp_signal::{self as signal, Signal};
+    ///
+    /// fn main() {
+    ///     let signal = signal::gen(|| [1.0]);
+    ///     let frames: Vec<_> = signal.fade_in(Curve::Linear, 3).take(4).collect();
+    ///     assert_eq!(frames, vec![[0.0], [0.5], [1.0], [1.0]]);
+    /// }
+    /// ```
+    #[inline]
+    fn fade_in(self, curve: Curve, frames: usize) -> GainRamp<Self>
+    where
+        Self: Sized,
+    {
+        self.gain_ramp(curve, 0.0, 1.0, frames)
+    }
+
+    /// Produces a new `Signal` that fades `self` out from unity gain to silence over the given
+    /// number of frames following the given curve.
+    ///
+    /// The first frame is left unchanged and every frame from the end of the fade onwards is
+    /// silenced. See `dasp_sample::fade` for the available curves.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use dasp_sample::fade::Curve;
+    /// use dasp_signal::{self as signal, Signal};
+    ///
+    /// fn main() {
+    ///     let signal = signal::gen(|| [1.0]);
+    ///     let frames: Vec<[f64; 1]> = signal.fade_out(Curve::EqualPower, 3).take(4).collect();
+    ///     assert_eq!(frames[0], [1.0]);
+    ///     assert!((frames[1][0] - 0.5f64.sqrt()).abs() < 1e-9);
+    ///     assert_eq!(&frames[2..], &[[0.0], [0.0]]);
+    /// }
+    /// ```
+    #[inline]
+    fn fade_out(self, curve: Curve, frames: usize) -> GainRamp<Self>
+    where
+        Self: Sized,
+    {
+        self.gain_ramp(curve, 1.0, 0.0, frames)
+    }
+
+    /// Produces a new `Signal` that scales the amplitude of `self` by a gain that moves from
+    /// `from` to `to` over the given number of frames following the given curve.
+    ///
+    /// The first frame is scaled by `from`, and every frame from the end of the ramp onwards is
+    /// scaled by `to`. Either gain may be given as a raw `f64`, a linear `Gain` or in `Decibels`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use dasp_sample::fade::Curve;
+    /// use dasp_signal::{self as signal, Signal};
+    ///
+    /// fn main() {
+    ///     let signal = signal::gen(|| [1.0, -1.0]);
+    ///     let frames: Vec<_> = signal.gain_ramp(Curve::SCurve, 1.0, 0.5, 3).take(3).collect();
+    ///     assert_eq!(frames, vec![[1.0, -1.0], [0.75, -0.75], [0.5, -0.5]]);
+    /// }
+    /// ```
+    #[inline]
+    fn gain_ramp<A, B>(self, curve: Curve, from: A, to: B, frames: usize) -> GainRamp<Self>
+    where
+        Self: Sized,
+        A: IntoAmp<f64>,
+        B: IntoAmp<f64>,
+    {
+        GainRamp {
+            signal: self,
+            ramp: Ramp::new(curve, from, to, frames),
+            idx: 0,
+        }
+    }
+
+    /// Produces a new `Signal` that crossfades from `self` to `other` over the given number of
+    /// frames following the given curve.
+    ///
+    /// Both signals are advanced in lock-step until the end of the crossfade, after which only the
+    /// frames of `other` are yielded. The resulting signal is exhausted once `other` is exhausted.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use dasp_sample::fade::Curve;
+    /// use dasp_signal::{self as signal, Signal};
+    ///
+    /// fn main() {
+    ///     let a = signal::gen(|| [1.0]);
+    ///     let b = signal::gen(|| [-1.0]);
+    ///     let frames: Vec<_> = a.crossfade(b, Curve::Linear, 3).take(4).collect();
+    ///     assert_eq!(frames, vec![[1.0], [0.0], [-1.0], [-1.0]]);
+    /// }
+    /// ```
+    #[inline]
+    fn crossfade<S>(self, other: S, curve: Curve, frames: usize) -> Crossfade<Self, S>
+    where
+        Self: Sized,
+        S: Signal<Frame = Self::Frame>,
+    {
+        Crossfade {
+            a: self,
+            b: other,
+            fade_out: Ramp::fade_out(curve, frames),
+            fade_in: Ramp::fade_in(curve, frames),
+            idx: 0,
+        }
+    }
+
     /// Multiplies the rate at which frames of `self` are yielded by the given `signal`.
     ///
     /// This happens by wrapping `self` in a `rate::Converter` and calling `set_playback_hz_scale`
@@ -904,6 +1106,19 @@ where
     next: Option<F>,
 }
 
+/// An iterator that converts an iterator of `Sample`s to an iterator of `DynFrame`s with a number
+/// of channels determined at runtime.
+#[derive(Clone)]
+pub struct FromInterleavedSamplesIteratorDyn<I, const N: usize>
+where
+    I: Iterator,
+    I::Item: Sample,
+{
+    samples: I,
+    channels: usize,
+    next: Option<DynFrame<I::Item, N>>,
+}
+
 /// The rate at which phrase a **Signal** is sampled.
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct Rate {
@@ -1013,6 +1228,33 @@ pub struct ScaleAmpPerChannel<S, F> {
     amp_frame: F,
 }
 
+/// An `Iterator` that mixes every `Frame` in `self` into a frame of `O` channels using a matrix of
+/// mixing coefficients.
+#[derive(Clone)]
+pub struct Remix<S, const I: usize, const O: usize> {
+    signal: S,
+    matrix: Matrix<I, O>,
+}
+
+/// An `Iterator` that scales the amplitude of every `Frame` in `self` by a gain that follows a
+/// `Ramp`.
+#[derive(Clone)]
+pub struct GainRamp<S> {
+    signal: S,
+    ramp: Ramp,
+    idx: usize,
+}
+
+/// An `Iterator` that crossfades from the frames of one signal to those of another.
+#[derive(Clone)]
+pub struct Crossfade<A, B> {
+    a: A,
+    b: B,
+    fade_out: Ramp,
+    fade_in: Ramp,
+    idx: usize,
+}
+
 /// Multiplies the rate at which frames of `self` are yielded by the given `signal`.
 ///
 /// This happens by wrapping `self` in a `rate::Converter` and calling `set_playback_hz_scale`
@@ -1387,6 +1629,9 @@ where
 ///
 /// When the `Iterator` is exhausted, the new `Signal` will yield `F::equilibrium`.
 ///
+/// `F` must have a number of channels fixed by its type. Use `from_interleaved_samples_iter_dyn`
+/// for channel counts that are only known at runtime.
+///
 /// # Example
 ///
 /// ```rust
@@ -1405,6 +1650,16 @@ where
 ///     assert_eq!(signal.next(), [0, 0]);
 /// }
 /// ```
+///
+/// ```compile_fail
+/// use dasp_frame::DynFrame;
+/// use dasp_signal as signal;
+///
+/// fn main() {
+///     let foo = [0, 1, 2, 3];
+///     signal::from_interleaved_samples_iter::<_, DynFrame<i32, 8>>(foo.iter().cloned());
+/// }
+/// ```
 pub fn from_interleaved_samples_iter<I, F>(
     samples: I,
 ) -> FromInterleavedSamplesIterator<I::IntoIter, F>
@@ -1412,6 +1667,7 @@ where
     I: IntoIterator,
     I::Item: Sample,
     F: Frame<Sample = I::Item>,
+    F::NumChannels: FixedChannels,
 {
     let mut samples = samples.into_iter();
     let next = Frame::from_samples(&mut samples);
@@ -1421,6 +1677,49 @@ where
     }
 }
 
+/// Create a new `Signal` of `DynFrame`s with the given number of channels from the given
+/// interleaved `Sample`-yielding `Iterator`.
+///
+/// This is the same as `from_interleaved_samples_iter`, but for channel counts that are only known
+/// at runtime, e.g. from the header of a file. Each frame has a capacity of `N` channels.
+///
+/// When the `Iterator` is exhausted, the new `Signal` will yield `DynFrame::EQUILIBRIUM`, an empty
+/// frame.
+///
+/// **Panics** if `channels` is greater than `N`.
+///
+/// # Example
+///
+/// ```rust
+/// use dasp_signal::{self as signal, Signal};
+///
+/// fn main() {
+///     let channels = 3;
+///     let foo = [0, 1, 2, 3, 4, 5, 6];
+///     let signal = signal::from_interleaved_samples_iter_dyn::<_, 8>(foo.iter().cloned(), channels);
+///     let mut frames = signal.until_exhausted();
+///     assert_eq!(frames.next().unwrap().as_slice(), &[0, 1, 2]);
+///     assert_eq!(frames.next().unwrap().as_slice(), &[3, 4, 5]);
+///     assert_eq!(frames.next(), None);
+/// }
+/// ```
+pub fn from_interleaved_samples_iter_dyn<I, const N: usize>(
+    samples: I,
+    channels: usize,
+) -> FromInterleavedSamplesIteratorDyn<I::IntoIter, N>
+where
+    I: IntoIterator,
+    I::Item: Sample,
+{
+    let mut samples = samples.into_iter();
+    let next = DynFrame::from_samples_with_channels(&mut samples, channels);
+    FromInterleavedSamplesIteratorDyn {
+        samples,
+        channels,
+        next,
+    }
+}
+
 /// Creates a `Phase` that continuously steps forward by the given `step` size yielder.
 ///
 /// # Example
@@ -1626,6 +1925,73 @@ where
     }
 }
 
+/// Yields the frames pushed by the paired `Producer`, or `Frame::EQUILIBRIUM` whenever the ring
+/// buffer is empty, e.g. when a decoding thread falls behind a real-time audio callback.
+///
+/// The signal is exhausted once the producer has been dropped and every pushed frame has been
+/// yielded.
+///
+/// # Example
+///
+/// ```rust
+/// use dasp_ring_buffer::spsc;
+/// use dasp_signal::Signal;
+///
+/// fn main() {
+///     let (mut producer, mut consumer) = spsc::channel([[0.0; 2]; 4]);
+///     producer.push_slice(&[[0.25, -0.25], [0.5, -0.5]]);
+///     assert_eq!(consumer.next(), [0.25, -0.25]);
+///     assert_eq!(consumer.next(), [0.5, -0.5]);
+///     assert_eq!(consumer.next(), [0.0, 0.0]);
+///     assert!(!consumer.is_exhausted());
+///     drop(producer);
+///     assert!(consumer.is_exhausted());
+/// }
+/// ```
+#[cfg(target_has_atomic = "ptr")]
+impl<S> Signal for ring_buffer::spsc::Consumer<S>
+where
+    S: ring_buffer::SliceMut,
+    S::Element: Frame,
+{
+    type Frame = S::Element;
+
+    #[inline]
+    fn next(&mut self) -> Self::Frame {
+        self.pop().unwrap_or(Frame::EQUILIBRIUM)
+    }
+
+    #[inline]
+    fn is_exhausted(&self) -> bool {
+        // Check for the producer first, so that a final push is not missed.
+        self.is_abandoned() && self.is_empty()
+    }
+}
+
+impl<I, const N: usize> Signal for FromInterleavedSamplesIteratorDyn<I, N>
+where
+    I: Iterator,
+    I::Item: Sample,
+{
+    type Frame = DynFrame<I::Item, N>;
+
+    #[inline]
+    fn next(&mut self) -> Self::Frame {
+        match self.next.take() {
+            Some(frame) => {
+                self.next = DynFrame::from_samples_with_channels(&mut self.samples, self.channels);
+                frame
+            }
+            None => DynFrame::EQUILIBRIUM,
+        }
+    }
+
+    #[inline]
+    fn is_exhausted(&self) -> bool {
+        self.next.is_none()
+    }
+}
+
 impl<F> Signal for Equilibrium<F>
 where
     F: Frame,
@@ -2179,6 +2545,77 @@ where
     }
 }
 
+impl<S, const I: usize, const O: usize> Signal for Remix<S, I, O>
+where
+    S: Signal,
+    S::Frame: Frame<NumChannels = NChannels<I>>,
+{
+    type Frame = [<S::Frame as Frame>::Sample; O];
+
+    #[inline]
+    fn next(&mut self) -> Self::Frame {
+        self.matrix.apply(self.signal.next())
+    }
+
+    #[inline]
+    fn is_exhausted(&self) -> bool {
+        self.signal.is_exhausted()
+    }
+}
+
+impl<S> Signal for GainRamp<S>
+where
+    S: Signal,
+{
+    type Frame = S::Frame;
+
+    #[inline]
+    fn next(&mut self) -> Self::Frame {
+        let gain = Gain(self.ramp.gain_at(self.idx));
+        self.idx = core::cmp::min(self.idx + 1, self.ramp.len());
+        self.signal.next().scale_amp(gain)
+    }
+
+    #[inline]
+    fn is_exhausted(&self) -> bool {
+        self.signal.is_exhausted()
+    }
+}
+
+impl<A, B> Signal for Crossfade<A, B>
+where
+    A: Signal,
+    B: Signal<Frame = A::Frame>,
+{
+    type Frame = A::Frame;
+
+    #[inline]
+    fn next(&mut self) -> Self::Frame {
+        if self.idx >= self.fade_in.len() {
+            return self.b.next();
+        }
+        let a = self
+            .a
+            .next()
+            .scale_amp(Gain(self.fade_out.gain_at(self.idx)));
+        let b = self
+            .b
+            .next()
+            .scale_amp(Gain(self.fade_in.gain_at(self.idx)));
+        self.idx += 1;
+        a.add_amp(b.to_signed_frame())
+    }
+
+    #[inline]
+    fn is_exhausted(&self) -> bool {
+        if self.idx >= self.fade_in.len() {
+            self.b.is_exhausted()
+        } else {
+            self.a.is_exhausted() && self.b.is_exhausted()
+        }
+    }
+}
+
 impl<S> Signal for OffsetAmp<S>
 where
     S: Signal,
@@ -0,0 +1,165 @@
+//! An extension to the **Signal** trait for converting between rates with a fixed rational ratio.
+//!
+//! ### Required Features
+//!
+//! - When using `dasp_signal`, this module requires the **rational** feature to be enabled.
+//! - When using `dasp`, this module requires the **signal-rational** feature to be enabled.
+
+use crate::Signal;
+use dasp_interpolate::rational::Rational;
+use dasp_interpolate::Interpolator;
+
+/// An extension to the **Signal** trait for converting between rates with a fixed rational ratio.
+///
+/// ### Required Features
+///
+/// - When using `dasp_signal`, this item requires the **rational** feature to be enabled.
+/// - When using `dasp`, this item requires the **signal-rational** feature to be enabled.
+pub trait SignalRational: Signal {
+    /// Converts the rate at which frames of the `Signal` are yielded from `source_hz` to
+    /// `target_hz` using interpolation.
+    ///
+    /// Unlike `Signal::from_hz_to_hz`, the position between source frames is tracked exactly, so
+    /// that every `source_hz` source frames always yield exactly `target_hz` target frames.
+    ///
+    /// **panic!**s if either rate is `0`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use dasp_interpolate::linear::Linear;
+    /// use dasp_signal::{self as signal, Signal};
+    /// use dasp_signal::rational::SignalRational;
+    ///
+    /// fn main() {
+    ///     let foo = [0.0, 1.0, 0.0, -1.0];
+    ///     let mut source = signal::from_iter(foo.iter().cloned());
+    ///     let a = source.next();
+    ///     let b = source.next();
+    ///     let interp = Linear::new(a, b);
+    ///     let frames: Vec<_> = source.rational_hz_to_hz(interp, 48_000, 96_000).take(8).collect();
+    ///     assert_eq!(&frames[..], &[0.0, 0.5, 1.0, 0.5, 0.0, -0.5, -1.0, -0.5][..]);
+    /// }
+    /// ```
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_signal`, this item requires the **rational** feature to be enabled.
+    /// - When using `dasp`, this item requires the **signal-rational** feature to be enabled.
+    fn rational_hz_to_hz<I>(
+        self,
+        interpolator: I,
+        source_hz: u32,
+        target_hz: u32,
+    ) -> RationalConverter<Self, I>
+    where
+        Self: Sized,
+        I: Interpolator,
+    {
+        RationalConverter {
+            source: self,
+            rational: Rational::new(interpolator, source_hz, target_hz),
+        }
+    }
+}
+
+/// A signal that converts the rate of some source signal by a fixed rational ratio.
+///
+/// ### Required Features
+///
+/// - When using `dasp_signal`, this item requires the **rational** feature to be enabled.
+/// - When using `dasp`, this item requires the **signal-rational** feature to be enabled.
+#[derive(Clone)]
+pub struct RationalConverter<S, I>
+where
+    S: Signal,
+    I: Interpolator,
+{
+    source: S,
+    rational: Rational<I>,
+}
+
+impl<S, I> RationalConverter<S, I>
+where
+    S: Signal,
+    I: Interpolator,
+{
+    /// Borrow the source signal.
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_signal`, this item requires the **rational** feature to be enabled.
+    /// - When using `dasp`, this item requires the **signal-rational** feature to be enabled.
+    #[inline]
+    pub fn source(&self) -> &S {
+        &self.source
+    }
+
+    /// Mutably borrow the source signal.
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_signal`, this item requires the **rational** feature to be enabled.
+    /// - When using `dasp`, this item requires the **signal-rational** feature to be enabled.
+    #[inline]
+    pub fn source_mut(&mut self) -> &mut S {
+        &mut self.source
+    }
+
+    /// Borrow the resampler driving the conversion.
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_signal`, this item requires the **rational** feature to be enabled.
+    /// - When using `dasp`, this item requires the **signal-rational** feature to be enabled.
+    #[inline]
+    pub fn rational(&self) -> &Rational<I> {
+        &self.rational
+    }
+
+    /// Mutably borrow the resampler driving the conversion.
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_signal`, this item requires the **rational** feature to be enabled.
+    /// - When using `dasp`, this item requires the **signal-rational** feature to be enabled.
+    #[inline]
+    pub fn rational_mut(&mut self) -> &mut Rational<I> {
+        &mut self.rational
+    }
+
+    /// Consumes the `RationalConverter` and returns its source signal and resampler.
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_signal`, this item requires the **rational** feature to be enabled.
+    /// - When using `dasp`, this item requires the **signal-rational** feature to be enabled.
+    #[inline]
+    pub fn into_parts(self) -> (S, Rational<I>) {
+        let RationalConverter { source, rational } = self;
+        (source, rational)
+    }
+}
+
+impl<S, I> Signal for RationalConverter<S, I>
+where
+    S: Signal,
+    I: Interpolator<Frame = S::Frame>,
+{
+    type Frame = S::Frame;
+
+    #[inline]
+    fn next(&mut self) -> Self::Frame {
+        let RationalConverter {
+            ref mut source,
+            ref mut rational,
+        } = *self;
+        rational.next_frame(|| source.next())
+    }
+
+    fn is_exhausted(&self) -> bool {
+        self.source.is_exhausted() && self.rational.phase() >= self.rational.up()
+    }
+}
+
+impl<T> SignalRational for T where T: Signal {}
@@ -0,0 +1,190 @@
+//! An extension to the **Signal** trait that detects, counts and replaces NaN, infinite and
+//! subnormal samples.
+//!
+//! ### Required Features
+//!
+//! - When using `dasp_signal`, this module requires the **sanitize** feature to be enabled.
+//! - When using `dasp`, this module requires the **signal-sanitize** feature to be enabled.
+
+use crate::Signal;
+use dasp_frame::Frame;
+use dasp_sample::sanitize::{Counts, Report, Sanitizer};
+use dasp_sample::FloatSample;
+
+/// An extension to the **Signal** trait that detects, counts and replaces NaN, infinite and
+/// subnormal samples.
+///
+/// ### Required Features
+///
+/// - When using `dasp_signal`, this item requires the **sanitize** feature to be enabled.
+/// - When using `dasp`, this item requires the **signal-sanitize** feature to be enabled.
+pub trait SignalSanitize: Signal {
+    /// An adaptor that replaces the faulty samples of each frame according to the policy of the
+    /// given **Sanitizer**, counting each kind of fault encountered.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dasp_sample::sanitize::Sanitizer;
+    /// use dasp_signal::{self as signal, Signal};
+    /// use dasp_signal::sanitize::SignalSanitize;
+    ///
+    /// fn main() {
+    ///     let frames = vec![[0.5, f32::NAN], [f32::INFINITY, -0.5]];
+    ///     let mut signal = signal::from_iter(frames).sanitize(Sanitizer::default());
+    ///     assert_eq!(signal.next(), [0.5, 0.0]);
+    ///     assert_eq!(signal.next(), [0.0, -0.5]);
+    ///     assert_eq!(signal.counts().nan, 1);
+    ///     assert_eq!(signal.counts().infinite, 1);
+    /// }
+    /// ```
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_signal`, this item requires the **sanitize** feature to be enabled.
+    /// - When using `dasp`, this item requires the **signal-sanitize** feature to be enabled.
+    fn sanitize(self, sanitizer: Sanitizer) -> Sanitize<Self, fn(Report)>
+    where
+        Self: Sized,
+        <Self::Frame as Frame>::Sample: FloatSample,
+    {
+        self.sanitize_with(sanitizer, ignore_report)
+    }
+
+    /// The same as **sanitize**, but calls `report` with the position and kind of each fault
+    /// encountered.
+    ///
+    /// The frame index of each **Report** is the number of frames yielded by the adaptor prior to
+    /// the faulty frame.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dasp_sample::sanitize::{Fault, Report, Sanitizer};
+    /// use dasp_signal::{self as signal, Signal};
+    /// use dasp_signal::sanitize::SignalSanitize;
+    ///
+    /// fn main() {
+    ///     let frames = vec![0.5, 0.25, f64::NAN];
+    ///     let mut reports = vec![];
+    ///     let signal = signal::from_iter(frames)
+    ///         .sanitize_with(Sanitizer::default(), |r| reports.push(r));
+    ///     let sanitized: Vec<f64> = signal.until_exhausted().collect();
+    ///     assert_eq!(sanitized, vec![0.5, 0.25, 0.0]);
+    ///     assert_eq!(reports, vec![Report { frame: 2, channel: 0, fault: Fault::Nan }]);
+    /// }
+    /// ```
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_signal`, this item requires the **sanitize** feature to be enabled.
+    /// - When using `dasp`, this item requires the **signal-sanitize** feature to be enabled.
+    fn sanitize_with<R>(self, sanitizer: Sanitizer, report: R) -> Sanitize<Self, R>
+    where
+        Self: Sized,
+        <Self::Frame as Frame>::Sample: FloatSample,
+        R: FnMut(Report),
+    {
+        Sanitize {
+            signal: self,
+            sanitizer,
+            report,
+            counts: Counts::default(),
+            frame: 0,
+        }
+    }
+}
+
+/// An adaptor that detects, counts and replaces NaN, infinite and subnormal samples.
+///
+/// ### Required Features
+///
+/// - When using `dasp_signal`, this item requires the **sanitize** feature to be enabled.
+/// - When using `dasp`, this item requires the **signal-sanitize** feature to be enabled.
+#[derive(Clone)]
+pub struct Sanitize<S, R> {
+    signal: S,
+    sanitizer: Sanitizer,
+    report: R,
+    counts: Counts,
+    // The index of the next frame.
+    frame: u64,
+}
+
+fn ignore_report(_: Report) {}
+
+impl<S, R> Sanitize<S, R> {
+    /// The number of each kind of fault encountered so far.
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_signal`, this item requires the **sanitize** feature to be enabled.
+    /// - When using `dasp`, this item requires the **signal-sanitize** feature to be enabled.
+    pub fn counts(&self) -> Counts {
+        self.counts
+    }
+
+    /// Reset the fault counts to zero.
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_signal`, this item requires the **sanitize** feature to be enabled.
+    /// - When using `dasp`, this item requires the **signal-sanitize** feature to be enabled.
+    pub fn reset_counts(&mut self) {
+        self.counts = Counts::default();
+    }
+
+    /// The **Sanitizer** used by the adaptor.
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_signal`, this item requires the **sanitize** feature to be enabled.
+    /// - When using `dasp`, this item requires the **signal-sanitize** feature to be enabled.
+    pub fn sanitizer(&self) -> Sanitizer {
+        self.sanitizer
+    }
+
+    /// Consumes the `Sanitize` adaptor and returns its inner signal `S` and reporting hook `R`.
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_signal`, this item requires the **sanitize** feature to be enabled.
+    /// - When using `dasp`, this item requires the **signal-sanitize** feature to be enabled.
+    pub fn into_parts(self) -> (S, R) {
+        let Sanitize { signal, report, .. } = self;
+        (signal, report)
+    }
+}
+
+impl<S, R> Signal for Sanitize<S, R>
+where
+    S: Signal,
+    <S::Frame as Frame>::Sample: FloatSample,
+    R: FnMut(Report),
+{
+    type Frame = S::Frame;
+
+    fn next(&mut self) -> Self::Frame {
+        let mut frame = self.signal.next();
+        for (channel, sample) in frame.channels_mut().enumerate() {
+            let (sanitized, fault) = self.sanitizer.sanitize(*sample);
+            if let Some(fault) = fault {
+                *sample = sanitized;
+                self.counts.record(fault);
+                (self.report)(Report {
+                    frame: self.frame,
+                    channel,
+                    fault,
+                });
+            }
+        }
+        self.frame += 1;
+        frame
+    }
+
+    fn is_exhausted(&self) -> bool {
+        self.signal.is_exhausted()
+    }
+}
+
+impl<T> SignalSanitize for T where T: Signal {}
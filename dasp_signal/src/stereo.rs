@@ -0,0 +1,526 @@
+//! An extension to the **Signal** trait for processing and metering the stereo field of
+//! two-channel signals.
+//!
+//! ### Required Features
+//!
+//! - When using `dasp_signal`, this module requires the **stereo** feature to be enabled.
+//! - When using `dasp`, this module requires the **signal-stereo** feature to be enabled.
+
+use crate::Signal;
+use dasp_frame::stereo::StereoFrame;
+use dasp_frame::Frame;
+use dasp_ring_buffer as ring_buffer;
+use dasp_sample::{FloatSample, Sample};
+
+/// The floating point sample type of the frames yielded by the signal `S`.
+type FloatOf<S> = <<<S as Signal>::Frame as Frame>::Sample as Sample>::Float;
+
+/// An extension to the **Signal** trait for processing and metering the stereo field of
+/// two-channel signals.
+///
+/// See the **StereoFrame** trait for the equivalent operations on individual frames.
+///
+/// ### Required Features
+///
+/// - When using `dasp_signal`, this item requires the **stereo** feature to be enabled.
+/// - When using `dasp`, this item requires the **signal-stereo** feature to be enabled.
+pub trait SignalStereo: Signal
+where
+    Self::Frame: StereoFrame,
+{
+    /// An adaptor that encodes each left/right frame as a mid/side frame.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dasp_signal::{self as signal, Signal};
+    /// use dasp_signal::stereo::SignalStereo;
+    ///
+    /// fn main() {
+    ///     let frames = [[0.5, 0.5], [0.5, -0.5]];
+    ///     let mut signal = signal::from_iter(frames.iter().cloned()).to_mid_side();
+    ///     assert_eq!(signal.next(), [0.5, 0.0]);
+    ///     assert_eq!(signal.next(), [0.0, 0.5]);
+    /// }
+    /// ```
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_signal`, this item requires the **stereo** feature to be enabled.
+    /// - When using `dasp`, this item requires the **signal-stereo** feature to be enabled.
+    fn to_mid_side(self) -> ToMidSide<Self>
+    where
+        Self: Sized,
+    {
+        ToMidSide { signal: self }
+    }
+
+    /// An adaptor that decodes each mid/side frame as a left/right frame.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dasp_signal::{self as signal, Signal};
+    /// use dasp_signal::stereo::SignalStereo;
+    ///
+    /// fn main() {
+    ///     let frames = [[0.25, 0.5], [-0.5, 0.25]];
+    ///     let source = signal::from_iter(frames.iter().cloned());
+    ///     let mut signal = source.to_mid_side().from_mid_side();
+    ///     assert_eq!(signal.next(), [0.25, 0.5]);
+    ///     assert_eq!(signal.next(), [-0.5, 0.25]);
+    /// }
+    /// ```
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_signal`, this item requires the **stereo** feature to be enabled.
+    /// - When using `dasp`, this item requires the **signal-stereo** feature to be enabled.
+    #[allow(clippy::wrong_self_convention)]
+    fn from_mid_side(self) -> FromMidSide<Self>
+    where
+        Self: Sized,
+    {
+        FromMidSide { signal: self }
+    }
+
+    /// An adaptor that scales the width of the stereo field of each frame.
+    ///
+    /// A `width` of `0.0` collapses the signal to mono, `1.0` leaves it unchanged and values
+    /// greater than `1.0` widen it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dasp_signal::{self as signal, Signal};
+    /// use dasp_signal::stereo::SignalStereo;
+    ///
+    /// fn main() {
+    ///     let frames = [[1.0, 0.0], [0.5, 0.25]];
+    ///     let mut signal = signal::from_iter(frames.iter().cloned()).stereo_width(0.0);
+    ///     assert_eq!(signal.next(), [0.5, 0.5]);
+    ///     assert_eq!(signal.next(), [0.375, 0.375]);
+    /// }
+    /// ```
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_signal`, this item requires the **stereo** feature to be enabled.
+    /// - When using `dasp`, this item requires the **signal-stereo** feature to be enabled.
+    fn stereo_width(self, width: FloatOf<Self>) -> StereoWidth<Self>
+    where
+        Self: Sized,
+    {
+        StereoWidth {
+            signal: self,
+            width,
+        }
+    }
+
+    /// An adaptor that attenuates one side of the stereo field of each frame.
+    ///
+    /// A `balance` of `-1.0` silences the right channel, `0.0` leaves the signal unchanged and
+    /// `1.0` silences the left channel.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dasp_signal::{self as signal, Signal};
+    /// use dasp_signal::stereo::SignalStereo;
+    ///
+    /// fn main() {
+    ///     let frames = [[0.5, 0.5]];
+    ///     let mut signal = signal::from_iter(frames.iter().cloned()).balance(0.5);
+    ///     assert_eq!(signal.next(), [0.25, 0.5]);
+    /// }
+    /// ```
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_signal`, this item requires the **stereo** feature to be enabled.
+    /// - When using `dasp`, this item requires the **signal-stereo** feature to be enabled.
+    fn balance(self, balance: FloatOf<Self>) -> Balance<Self>
+    where
+        Self: Sized,
+    {
+        Balance {
+            signal: self,
+            balance,
+        }
+    }
+
+    /// An adaptor that yields the correlation coefficient between the left and right channels
+    /// over a window of frames, as measured by a **CorrelationMeter**.
+    ///
+    /// The window size of the meter is equal to the given ring buffer length.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dasp_ring_buffer as ring_buffer;
+    /// use dasp_signal::{self as signal, Signal};
+    /// use dasp_signal::stereo::SignalStereo;
+    ///
+    /// fn main() {
+    ///     let frames = [[0.5, 0.5], [-0.5, -0.5], [0.5, -0.5], [-0.5, 0.5]];
+    ///     let signal = signal::from_iter(frames.iter().cloned());
+    ///     let ring_buffer = ring_buffer::Fixed::from([[0.0; 3]; 2]);
+    ///     let correlation: Vec<f64> = signal.correlation(ring_buffer).take(4).collect();
+    ///     assert_eq!(correlation, vec![1.0, 1.0, 0.0, -1.0]);
+    /// }
+    /// ```
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_signal`, this item requires the **stereo** feature to be enabled.
+    /// - When using `dasp`, this item requires the **signal-stereo** feature to be enabled.
+    fn correlation<D>(self, ring_buffer: ring_buffer::Fixed<D>) -> Correlation<Self, D>
+    where
+        Self: Sized,
+        D: ring_buffer::SliceMut<Element = [FloatOf<Self>; 3]>,
+    {
+        Correlation {
+            signal: self,
+            meter: CorrelationMeter::new(ring_buffer),
+        }
+    }
+}
+
+/// Measures the correlation coefficient between the left and right channels of a stereo signal
+/// over a sliding window of frames.
+///
+/// The coefficient ranges from `1.0`, where both channels are identical up to their level (i.e.
+/// mono), through `0.0`, where the channels are unrelated or either channel is silent, to `-1.0`,
+/// where the channels are inverted copies of one another. Negative values indicate material that
+/// will partially cancel when summed to mono.
+///
+/// The window stores the products `left * right`, `left * left` and `right * right` of each frame,
+/// so the ring buffer's element type is an array of three floating point samples.
+///
+/// ### Required Features
+///
+/// - When using `dasp_signal`, this item requires the **stereo** feature to be enabled.
+/// - When using `dasp`, this item requires the **signal-stereo** feature to be enabled.
+#[derive(Clone)]
+pub struct CorrelationMeter<F, D>
+where
+    F: FloatSample,
+    D: ring_buffer::Slice<Element = [F; 3]>,
+{
+    window: ring_buffer::Fixed<D>,
+    // The sum of each product within the window.
+    sums: [F; 3],
+}
+
+/// An adaptor that encodes each left/right frame as a mid/side frame.
+///
+/// ### Required Features
+///
+/// - When using `dasp_signal`, this item requires the **stereo** feature to be enabled.
+/// - When using `dasp`, this item requires the **signal-stereo** feature to be enabled.
+#[derive(Clone)]
+pub struct ToMidSide<S> {
+    signal: S,
+}
+
+/// An adaptor that decodes each mid/side frame as a left/right frame.
+///
+/// ### Required Features
+///
+/// - When using `dasp_signal`, this item requires the **stereo** feature to be enabled.
+/// - When using `dasp`, this item requires the **signal-stereo** feature to be enabled.
+#[derive(Clone)]
+pub struct FromMidSide<S> {
+    signal: S,
+}
+
+/// An adaptor that scales the width of the stereo field of each frame.
+///
+/// ### Required Features
+///
+/// - When using `dasp_signal`, this item requires the **stereo** feature to be enabled.
+/// - When using `dasp`, this item requires the **signal-stereo** feature to be enabled.
+#[derive(Clone)]
+pub struct StereoWidth<S>
+where
+    S: Signal,
+{
+    signal: S,
+    width: FloatOf<S>,
+}
+
+/// An adaptor that attenuates one side of the stereo field of each frame.
+///
+/// ### Required Features
+///
+/// - When using `dasp_signal`, this item requires the **stereo** feature to be enabled.
+/// - When using `dasp`, this item requires the **signal-stereo** feature to be enabled.
+#[derive(Clone)]
+pub struct Balance<S>
+where
+    S: Signal,
+{
+    signal: S,
+    balance: FloatOf<S>,
+}
+
+/// An adaptor that yields the correlation coefficient between the left and right channels of the
+/// signal.
+///
+/// ### Required Features
+///
+/// - When using `dasp_signal`, this item requires the **stereo** feature to be enabled.
+/// - When using `dasp`, this item requires the **signal-stereo** feature to be enabled.
+#[derive(Clone)]
+pub struct Correlation<S, D>
+where
+    S: Signal,
+    D: ring_buffer::Slice<Element = [FloatOf<S>; 3]>,
+{
+    signal: S,
+    meter: CorrelationMeter<FloatOf<S>, D>,
+}
+
+impl<F, D> CorrelationMeter<F, D>
+where
+    F: FloatSample,
+    D: ring_buffer::Slice<Element = [F; 3]>,
+{
+    /// Construct a new **CorrelationMeter** that uses the given ring buffer as its window.
+    ///
+    /// The window size of the meter is equal to the length of the given ring buffer, which
+    /// should be filled with equilibrium.
+    ///
+    /// ```
+    /// use dasp_ring_buffer as ring_buffer;
+    /// use dasp_signal::stereo::CorrelationMeter;
+    ///
+    /// fn main() {
+    ///     let window = ring_buffer::Fixed::from([[0.0f32; 3]; 4]);
+    ///     let mut meter = CorrelationMeter::new(window);
+    ///     assert_eq!(meter.next([0.5f32, 0.25]), 1.0);
+    ///     assert_eq!(meter.next([0.25f32, -0.5]), 0.0);
+    /// }
+    /// ```
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_signal`, this item requires the **stereo** feature to be enabled.
+    /// - When using `dasp`, this item requires the **signal-stereo** feature to be enabled.
+    pub fn new(ring_buffer: ring_buffer::Fixed<D>) -> Self {
+        CorrelationMeter {
+            window: ring_buffer,
+            sums: [F::EQUILIBRIUM; 3],
+        }
+    }
+
+    /// Zeroes the sums and the buffer of the window.
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_signal`, this item requires the **stereo** feature to be enabled.
+    /// - When using `dasp`, this item requires the **signal-stereo** feature to be enabled.
+    pub fn reset(&mut self)
+    where
+        D: ring_buffer::SliceMut,
+    {
+        for products in self.window.iter_mut() {
+            *products = [F::EQUILIBRIUM; 3];
+        }
+        self.sums = [F::EQUILIBRIUM; 3];
+    }
+
+    /// The length of the window as a number of frames.
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_signal`, this item requires the **stereo** feature to be enabled.
+    /// - When using `dasp`, this item requires the **signal-stereo** feature to be enabled.
+    #[inline]
+    pub fn window_frames(&self) -> usize {
+        self.window.len()
+    }
+
+    /// The correlation coefficient of the window after adding the given frame.
+    ///
+    /// The meter pops its front frame's products and adds those of the new frame to the back.
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_signal`, this item requires the **stereo** feature to be enabled.
+    /// - When using `dasp`, this item requires the **signal-stereo** feature to be enabled.
+    #[inline]
+    pub fn next<T>(&mut self, frame: T) -> F
+    where
+        T: StereoFrame,
+        T::Sample: Sample<Float = F>,
+        D: ring_buffer::SliceMut,
+    {
+        let (l, r) = (
+            frame.left().to_float_sample(),
+            frame.right().to_float_sample(),
+        );
+        let products = [l * r, l * l, r * r];
+        let removed = self.window.push(products);
+        for ((sum, new), old) in self.sums.iter_mut().zip(&products).zip(&removed) {
+            *sum = *sum + *new - *old;
+        }
+        // Don't let floating point rounding errors put the sums of squares below zero.
+        for sum in self.sums[1..].iter_mut() {
+            if *sum < F::EQUILIBRIUM {
+                *sum = F::EQUILIBRIUM;
+            }
+        }
+        self.current()
+    }
+
+    /// The correlation coefficient of the frames currently within the window.
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_signal`, this item requires the **stereo** feature to be enabled.
+    /// - When using `dasp`, this item requires the **signal-stereo** feature to be enabled.
+    pub fn current(&self) -> F {
+        let [lr, ll, rr] = self.sums;
+        let energy = (ll * rr).sample_sqrt();
+        if energy <= F::EQUILIBRIUM {
+            return F::EQUILIBRIUM;
+        }
+        let one = <F as FloatSample>::IDENTITY;
+        let coefficient = lr / energy;
+        if coefficient > one {
+            one
+        } else if coefficient < -one {
+            -one
+        } else {
+            coefficient
+        }
+    }
+
+    /// Consumes the **CorrelationMeter** and returns its inner ring buffer of products.
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_signal`, this item requires the **stereo** feature to be enabled.
+    /// - When using `dasp`, this item requires the **signal-stereo** feature to be enabled.
+    pub fn into_inner(self) -> ring_buffer::Fixed<D> {
+        self.window
+    }
+}
+
+impl<S, D> Correlation<S, D>
+where
+    S: Signal,
+    D: ring_buffer::Slice<Element = [FloatOf<S>; 3]>,
+{
+    /// Consumes the `Correlation` signal and returns its inner signal `S` and
+    /// `CorrelationMeter`.
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_signal`, this item requires the **stereo** feature to be enabled.
+    /// - When using `dasp`, this item requires the **signal-stereo** feature to be enabled.
+    pub fn into_parts(self) -> (S, CorrelationMeter<FloatOf<S>, D>) {
+        let Correlation { signal, meter } = self;
+        (signal, meter)
+    }
+}
+
+impl<S> Signal for ToMidSide<S>
+where
+    S: Signal,
+    S::Frame: StereoFrame,
+{
+    type Frame = S::Frame;
+
+    #[inline]
+    fn next(&mut self) -> Self::Frame {
+        self.signal.next().to_mid_side()
+    }
+
+    #[inline]
+    fn is_exhausted(&self) -> bool {
+        self.signal.is_exhausted()
+    }
+}
+
+impl<S> Signal for FromMidSide<S>
+where
+    S: Signal,
+    S::Frame: StereoFrame,
+{
+    type Frame = S::Frame;
+
+    #[inline]
+    fn next(&mut self) -> Self::Frame {
+        self.signal.next().from_mid_side()
+    }
+
+    #[inline]
+    fn is_exhausted(&self) -> bool {
+        self.signal.is_exhausted()
+    }
+}
+
+impl<S> Signal for StereoWidth<S>
+where
+    S: Signal,
+    S::Frame: StereoFrame,
+{
+    type Frame = S::Frame;
+
+    #[inline]
+    fn next(&mut self) -> Self::Frame {
+        self.signal.next().stereo_width(self.width)
+    }
+
+    #[inline]
+    fn is_exhausted(&self) -> bool {
+        self.signal.is_exhausted()
+    }
+}
+
+impl<S> Signal for Balance<S>
+where
+    S: Signal,
+    S::Frame: StereoFrame,
+{
+    type Frame = S::Frame;
+
+    #[inline]
+    fn next(&mut self) -> Self::Frame {
+        self.signal.next().balance(self.balance)
+    }
+
+    #[inline]
+    fn is_exhausted(&self) -> bool {
+        self.signal.is_exhausted()
+    }
+}
+
+impl<S, D> Signal for Correlation<S, D>
+where
+    S: Signal,
+    S::Frame: StereoFrame,
+    D: ring_buffer::SliceMut<Element = [FloatOf<S>; 3]>,
+    FloatOf<S>: Frame,
+{
+    type Frame = FloatOf<S>;
+
+    #[inline]
+    fn next(&mut self) -> Self::Frame {
+        self.meter.next(self.signal.next())
+    }
+
+    #[inline]
+    fn is_exhausted(&self) -> bool {
+        self.signal.is_exhausted()
+    }
+}
+
+impl<T> SignalStereo for T
+where
+    T: Signal,
+    T::Frame: StereoFrame,
+{
+}
@@ -0,0 +1,54 @@
+#![cfg(feature = "dither")]
+
+use dasp_sample::dither::{Dither, Ditherer, NoiseShaping};
+use dasp_signal::dither::SignalDither;
+use dasp_signal::{self as signal, Signal};
+
+#[test]
+fn test_channels_are_shaped_independently() {
+    // Without dither, each channel of the adaptor should match a single channel `Ditherer`.
+    let shaping = NoiseShaping::Lipshitz;
+    let frames: Vec<[f64; 2]> = (0..1_000)
+        .map(|i| {
+            let x = (i as f64 * 0.01).sin() * 8.0 / 32_768.0;
+            [x, -0.5 * x]
+        })
+        .collect();
+    let ditherer = Ditherer::new(Dither::None, shaping, 0);
+    let mut left = ditherer.clone();
+    let mut right = ditherer.clone();
+    let dithered = signal::from_iter(frames.iter().cloned()).dither_to::<[i16; 2]>(ditherer);
+    for (frame, dithered) in frames.iter().zip(dithered.until_exhausted()) {
+        assert_eq!(dithered[0], left.quantize::<_, i16>(frame[0]));
+        assert_eq!(dithered[1], right.quantize::<_, i16>(frame[1]));
+    }
+}
+
+#[test]
+fn test_error_history_is_not_rounded_to_source_precision() {
+    // The error fed back for `f32` frames should be as precise as that of a `Ditherer`.
+    let shaping = NoiseShaping::Wannamaker9;
+    let frames: Vec<f32> = (0..10_000).map(|i| (i as f32 * 0.01).sin() * 0.5).collect();
+    let ditherer = Ditherer::new(Dither::None, shaping, 0);
+    let mut mono = ditherer.clone();
+    let dithered = signal::from_iter(frames.iter().cloned()).dither_to::<i32>(ditherer);
+    for (&frame, dithered) in frames.iter().zip(dithered.until_exhausted()) {
+        assert_eq!(dithered, mono.quantize::<_, i32>(frame));
+    }
+}
+
+#[test]
+fn test_same_seed_same_output() {
+    let ditherer = Ditherer::new(Dither::HighPassTpdf, NoiseShaping::SecondOrder, 17);
+    let a = signal::rate(44_100.0)
+        .const_hz(440.0)
+        .sine()
+        .dither_to::<i8>(ditherer.clone());
+    let b = signal::rate(44_100.0)
+        .const_hz(440.0)
+        .sine()
+        .dither_to::<i8>(ditherer);
+    let a: Vec<i8> = a.take(1_000).collect();
+    let b: Vec<i8> = b.take(1_000).collect();
+    assert_eq!(a, b);
+}
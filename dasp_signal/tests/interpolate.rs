@@ -1,6 +1,6 @@
 //! Tests for the `Converter` and `Interpolator` traits
 
-use dasp_interpolate::{floor::Floor, linear::Linear, sinc::Sinc};
+use dasp_interpolate::{floor::Floor, linear::Linear, sinc::Sinc, Interpolator};
 use dasp_ring_buffer as ring_buffer;
 use dasp_signal::{self as signal, interpolate::Converter, Signal};
 
@@ -71,3 +71,135 @@ fn test_sinc() {
         None
     );
 }
+
+#[test]
+fn test_cubic_converters() {
+    use dasp_interpolate::{
+        catmull_rom::CatmullRom, cubic::Cubic, hermite::Hermite, lagrange::Lagrange,
+    };
+
+    // A straight line is reproduced exactly by each of the cubic interpolators.
+    let foo = [0.0, 1.0, 2.0, 3.0, 4.0, 5.0];
+    let expected = [1.0, 1.5, 2.0, 2.5, 3.0, 3.5];
+
+    let mut source = signal::from_iter(foo.iter().cloned());
+    let (a, b, c, d) = (source.next(), source.next(), source.next(), source.next());
+    let frames: Vec<_> = source
+        .scale_hz(Cubic::new(a, b, c, d), 0.5)
+        .take(6)
+        .collect();
+    assert_eq!(&frames[..], &expected[..]);
+
+    let mut source = signal::from_iter(foo.iter().cloned());
+    let (a, b, c, d) = (source.next(), source.next(), source.next(), source.next());
+    let frames: Vec<_> = source
+        .scale_hz(CatmullRom::new(a, b, c, d), 0.5)
+        .take(6)
+        .collect();
+    assert_eq!(&frames[..], &expected[..]);
+
+    let mut source = signal::from_iter(foo.iter().cloned());
+    let (a, b, c, d) = (source.next(), source.next(), source.next(), source.next());
+    let frames: Vec<_> = source
+        .scale_hz(Hermite::new(a, b, c, d, 0.0), 0.5)
+        .take(6)
+        .collect();
+    assert_eq!(&frames[..], &expected[..]);
+
+    let mut source = signal::from_iter(foo.iter().cloned());
+    let history = ring_buffer::Fixed::from([source.next(), source.next(), source.next()]);
+    let frames: Vec<_> = source
+        .scale_hz(Lagrange::new(history), 0.5)
+        .take(6)
+        .collect();
+    assert_eq!(&frames[..], &expected[..]);
+}
+
+#[test]
+fn test_cubic_from_hz_to_hz() {
+    use dasp_interpolate::cubic::Cubic;
+
+    let foo = [0f64, 1.0, 0.0, -1.0, 0.0, 1.0, 0.0, -1.0];
+    let mut source = signal::from_iter(foo.iter().cloned());
+    let (a, b, c) = (source.next(), source.next(), source.next());
+    let interp = Cubic::new(0.0, a, b, c);
+    let resampled: Vec<_> = source
+        .from_hz_to_hz(interp, 44100.0, 88200.0)
+        .take(8)
+        .collect();
+    assert_eq!(resampled[0], 0.0);
+    assert_eq!(resampled[2], 1.0);
+    assert_eq!(resampled[4], 0.0);
+    assert_eq!(resampled[6], -1.0);
+}
+
+// The level in decibels of a sine wave at `hz` after conversion from `source_hz` to `target_hz`.
+fn polyphase_gain_db(quality: dasp_interpolate::polyphase::Quality, hz: f64) -> f64 {
+    use dasp_interpolate::polyphase::Polyphase;
+
+    let (source_hz, target_hz) = (48_000.0, 22_050.0);
+    let interp = Polyphase::<f64>::new(quality, source_hz, target_hz);
+    // Skip the output frames produced before the filter history is full.
+    let skip = (interp.taps() as f64 * target_hz / source_hz) as usize + 1;
+    let sine = signal::rate(source_hz).const_hz(hz).sine();
+    let frames: Vec<f64> = sine
+        .from_hz_to_hz(interp, source_hz, target_hz)
+        .take(skip + 8192)
+        .skip(skip)
+        .collect();
+    let ms = frames.iter().map(|s| s * s).sum::<f64>() / frames.len() as f64;
+    // A full-scale sine has an RMS of `1 / √2`.
+    10.0 * (ms * 2.0).log10()
+}
+
+#[test]
+fn test_polyphase_stopband_attenuation() {
+    use dasp_interpolate::polyphase::Quality;
+
+    let presets = [
+        (Quality::Low, 60.0),
+        (Quality::Medium, 80.0),
+        (Quality::High, 100.0),
+    ];
+    for &(quality, attenuation_db) in presets.iter() {
+        // Tones above the target Nyquist frequency of 11_025hz would otherwise alias.
+        for &hz in &[14_000.0, 17_000.0, 23_000.0] {
+            let db = polyphase_gain_db(quality, hz);
+            assert!(db < -attenuation_db, "{:?} {}hz: {}dB", quality, hz, db);
+        }
+        // Tones well within the passband pass unchanged.
+        for &hz in &[100.0, 1_000.0, 5_000.0] {
+            let db = polyphase_gain_db(quality, hz);
+            assert!(db.abs() < 0.05, "{:?} {}hz: {}dB", quality, hz, db);
+        }
+    }
+}
+
+#[test]
+fn test_polyphase_cutoff_tracks_ratio() {
+    use dasp_interpolate::polyphase::{Polyphase, Quality};
+
+    let source = signal::equilibrium::<f32>();
+    let interp = Polyphase::<f32>::new(Quality::Medium, 22_050.0, 48_000.0);
+    let mut conv = Converter::from_hz_to_hz(source, interp, 22_050.0, 48_000.0);
+    assert_eq!(conv.interpolator().cutoff(), 0.9);
+
+    conv.set_hz_to_hz(48_000.0, 24_000.0);
+    assert_eq!(conv.interpolator().cutoff(), 0.45);
+    assert_eq!(conv.interpolator().taps(), 2 * 36);
+}
+
+#[test]
+#[should_panic]
+fn test_polyphase_zero_target_hz_panic() {
+    use dasp_interpolate::polyphase::{Polyphase, Quality};
+    let _ = Polyphase::<f32>::new(Quality::Low, 48_000.0, 0.0);
+}
+
+#[test]
+#[should_panic]
+fn test_polyphase_nan_source_hz_panic() {
+    use dasp_interpolate::polyphase::{Polyphase, Quality};
+    let mut interp = Polyphase::<f32>::new(Quality::Low, 48_000.0, 44_100.0);
+    interp.set_hz_to_hz(f64::NAN, 44_100.0);
+}
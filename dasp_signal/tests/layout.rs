@@ -0,0 +1,35 @@
+//! Tests for the `SignalLayout` trait.
+
+#![cfg(feature = "layout")]
+
+use dasp_frame::layout::{ChannelLayout, ChannelOrder, Standard};
+use dasp_signal::{self as signal, layout::SignalLayout, Signal};
+
+#[test]
+fn test_with_layout() {
+    let layout = ChannelLayout::from(Standard::Quad);
+    let frames = vec![[1, 2, 3, 4], [5, 6, 7, 8]];
+    let signal = signal::from_iter(frames.clone()).with_layout(layout);
+    assert_eq!(signal.layout(), layout);
+    assert_eq!(signal.until_exhausted().collect::<Vec<_>>(), frames);
+}
+
+#[test]
+fn test_reorder_channels() {
+    let smpte = ChannelLayout::standard(Standard::Surround71, ChannelOrder::Smpte);
+    let film = ChannelLayout::standard(Standard::Surround71, ChannelOrder::Film);
+    let frames = vec![[0.0, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7]; 3];
+    let signal = signal::from_iter(frames)
+        .with_layout(smpte)
+        .reorder_to::<[f32; 8]>(film);
+    assert_eq!(signal.layout(), film);
+    let reordered: Vec<_> = signal.until_exhausted().collect();
+    assert_eq!(reordered.len(), 3);
+    assert_eq!(reordered[0], [0.0, 0.2, 0.1, 0.6, 0.7, 0.4, 0.5, 0.3]);
+
+    let lcr = ChannelLayout::standard(Standard::Lcr, ChannelOrder::Film);
+    let mut down = signal::from_iter(reordered)
+        .reorder_channels::<[f32; 3]>(film, lcr)
+        .until_exhausted();
+    assert_eq!(down.next(), Some([0.0, 0.2, 0.1]));
+}
@@ -0,0 +1,50 @@
+#![cfg(feature = "rational")]
+
+use dasp_interpolate::linear::Linear;
+use dasp_interpolate::rational::Rational;
+use dasp_signal::rational::SignalRational;
+use dasp_signal::{self as signal, interpolate::Converter, Signal};
+
+#[test]
+fn test_matches_converter_for_exact_ratio() {
+    // A ratio of `0.5` is exact in `f64`, so the `Converter` does not drift.
+    let sine = signal::rate(48_000.0).const_hz(440.0).sine();
+    let interp = Linear::new(0.0, 0.0);
+    let expected: Vec<f64> = Converter::from_hz_to_hz(sine, interp, 48_000.0, 96_000.0)
+        .take(4_096)
+        .collect();
+    let sine = signal::rate(48_000.0).const_hz(440.0).sine();
+    let interp = Linear::new(0.0, 0.0);
+    let frames: Vec<f64> = sine
+        .rational_hz_to_hz(interp, 48_000, 96_000)
+        .take(4_096)
+        .collect();
+    assert_eq!(frames, expected);
+}
+
+#[test]
+fn test_matches_block_processing() {
+    let input: Vec<f32> = (0..4_410).map(|i| (i as f32 * 0.01).sin()).collect();
+    let mut rational = Rational::new(Linear::new(0.0, 0.0), 44_100, 48_000);
+    let mut expected = vec![0.0; rational.output_len(input.len())];
+    rational.process(&input, &mut expected);
+
+    let source = signal::from_iter(input.iter().cloned());
+    let frames: Vec<f32> = source
+        .rational_hz_to_hz(Linear::new(0.0, 0.0), 44_100, 48_000)
+        .until_exhausted()
+        .collect();
+    assert_eq!(frames, expected);
+}
+
+#[test]
+fn test_into_parts() {
+    let source = signal::from_iter(vec![1.0, 2.0, 3.0]);
+    let mut conv = source.rational_hz_to_hz(Linear::new(0.0, 0.0), 1, 2);
+    assert_eq!(conv.rational().up(), 2);
+    let frames: Vec<f64> = conv.by_ref().take(5).collect();
+    assert_eq!(frames, vec![0.0, 0.0, 0.0, 0.5, 1.0]);
+    let (mut source, rational) = conv.into_parts();
+    assert_eq!(rational.phase(), 1);
+    assert_eq!(source.next(), 3.0);
+}
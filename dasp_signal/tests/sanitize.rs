@@ -0,0 +1,52 @@
+#![cfg(feature = "sanitize")]
+
+use dasp_sample::sanitize::{Counts, Fault, NonFinite, Report, Sanitizer};
+use dasp_signal::sanitize::SignalSanitize;
+use dasp_signal::{self as signal, Signal};
+
+#[test]
+fn test_sanitize_counts_and_replaces() {
+    let frames = vec![[0.5, f32::NAN], [f32::NEG_INFINITY, 1e-40], [0.25, -0.25]];
+    let mut sanitized = signal::from_iter(frames).sanitize(Sanitizer::new(NonFinite::Clamp, true));
+    assert_eq!(sanitized.next(), [0.5, 0.0]);
+    assert_eq!(sanitized.next(), [-1.0, 0.0]);
+    assert_eq!(sanitized.next(), [0.25, -0.25]);
+    assert!(sanitized.is_exhausted());
+    assert_eq!(
+        sanitized.counts(),
+        Counts {
+            nan: 1,
+            infinite: 1,
+            subnormal: 1,
+        }
+    );
+    sanitized.reset_counts();
+    assert_eq!(sanitized.counts().total(), 0);
+}
+
+#[test]
+fn test_sanitize_with_reports_position() {
+    let frames = vec![[0.0, 0.0], [0.0, f64::INFINITY], [f64::NAN, 0.0]];
+    let mut reports = vec![];
+    {
+        let sanitized =
+            signal::from_iter(frames).sanitize_with(Sanitizer::default(), |r| reports.push(r));
+        let frames: Vec<_> = sanitized.until_exhausted().collect();
+        assert_eq!(frames, vec![[0.0; 2]; 3]);
+    }
+    assert_eq!(
+        reports,
+        vec![
+            Report {
+                frame: 1,
+                channel: 1,
+                fault: Fault::Infinite,
+            },
+            Report {
+                frame: 2,
+                channel: 0,
+                fault: Fault::Nan,
+            },
+        ]
+    );
+}
@@ -1,5 +1,6 @@
 //! Tests for the `Signal` trait.
 
+use dasp_sample::{Decibels, Gain};
 use dasp_signal::{self as signal, Signal};
 
 #[test]
@@ -19,6 +20,21 @@ fn test_scale_amp() {
     assert_eq!(amp_scaled, vec![0.25, 0.4, -0.2, -0.1]);
 }
 
+#[test]
+fn test_scale_amp_with_gain_and_decibels() {
+    let foo = [[0.5, -0.5], [0.8, -0.4]];
+    let gain_scaled: Vec<_> = signal::from_iter(foo.iter().cloned())
+        .scale_amp(Gain(0.5))
+        .take(2)
+        .collect();
+    assert_eq!(gain_scaled, vec![[0.25, -0.25], [0.4, -0.2]]);
+    let db_scaled: Vec<_> = signal::from_iter(foo.iter().cloned())
+        .scale_amp(Decibels::SILENCE)
+        .take(2)
+        .collect();
+    assert_eq!(db_scaled, vec![[0.0, -0.0], [0.0, -0.0]]);
+}
+
 #[test]
 fn test_offset_amp() {
     let foo = [0.5, 0.9, -0.4, -0.2];
@@ -29,3 +45,133 @@ fn test_offset_amp() {
         .collect();
     assert_eq!(amp_offset, vec![0.0, 0.4, -0.9, -0.7]);
 }
+
+#[test]
+fn test_from_interleaved_bytes() {
+    use dasp_sample::bytes::{self, LittleEndian, Packed};
+    let bytes = [0x00, 0x40, 0x00, 0xC0, 0xFF, 0x7F, 0x00, 0x80];
+    let samples = bytes::decode_iter::<Packed<i16, LittleEndian>, _>(bytes.iter().cloned());
+    let frames: Vec<[i16; 2]> = signal::from_interleaved_samples_iter(samples)
+        .until_exhausted()
+        .collect();
+    assert_eq!(frames, vec![[16_384, -16_384], [32_767, -32_768]]);
+}
+
+#[test]
+fn test_from_erased_slice() {
+    use dasp_slice::erased::ErasedSlice;
+    let samples = [0u16, 65_535, 32_768, 49_152];
+    let erased = ErasedSlice::from(&samples[..]);
+    let frames: Vec<[f32; 2]> = signal::from_interleaved_samples_iter(erased.samples())
+        .until_exhausted()
+        .collect();
+    assert_eq!(frames, vec![[-1.0, 32_767.0 / 32_768.0], [0.0, 0.5]]);
+}
+
+#[test]
+fn test_remix() {
+    use dasp_frame::remix::Matrix;
+
+    let frames = vec![[1, 2, 3], [4, 5, 6]];
+    let matrix = Matrix::new([[0.0, 0.0, 1.0], [1.0, 1.0, 0.0]]);
+    let remixed: Vec<[i32; 2]> = signal::from_iter(frames)
+        .remix(matrix)
+        .until_exhausted()
+        .collect();
+    assert_eq!(remixed, vec![[3, 3], [6, 9]]);
+}
+
+#[test]
+fn test_dyn_frames() {
+    use dasp_frame::{DynFrame, Frame};
+
+    let channels = 3;
+    let samples = [0.5, 0.25, -0.5, 0.0, 1.0, -1.0, 0.75];
+    let signal =
+        signal::from_interleaved_samples_iter_dyn::<_, 8>(samples.iter().cloned(), channels);
+    let offset = signal::gen(|| DynFrame::<f64, 8>::from_slice(&[0.25]).unwrap());
+    let processed = signal
+        .map(|f| f.map::<DynFrame<f64, 8>, _>(|s| s * 2.0))
+        .scale_amp(0.5)
+        .add_amp(offset);
+    let interleaved: Vec<f64> = processed.into_interleaved_samples().into_iter().collect();
+    assert_eq!(interleaved, vec![0.75, 0.25, -0.5, 0.25, 1.0, -1.0]);
+}
+
+#[test]
+fn test_audio_buffer() {
+    use dasp_slice::AudioBuffer;
+
+    let sine = signal::rate(8.0).const_hz(1.0).sine();
+    let buffer = AudioBuffer::from_frames_iter(sine.take(8).map(|s| [s]), 8.0);
+    assert_eq!(buffer.duration_secs(), 1.0);
+    let quarter: Vec<[f64; 1]> = signal::from_iter(buffer.slice_secs(0.25, 0.5).to_vec())
+        .until_exhausted()
+        .collect();
+    assert_eq!(quarter.len(), 2);
+    assert_eq!(quarter[0], [1.0]);
+
+    let frames: Vec<[f64; 1]> = signal::from_iter(buffer.clone())
+        .until_exhausted()
+        .collect();
+    assert_eq!(&frames[..], &buffer[..]);
+}
+
+#[test]
+fn test_fades_match_slices() {
+    use dasp_sample::fade::Curve;
+
+    let curves = [
+        Curve::Linear,
+        Curve::Exponential,
+        Curve::SCurve,
+        Curve::EqualPower,
+    ];
+    let a: Vec<[f32; 2]> = (0..16).map(|i| [i as f32 / 16.0, -0.5]).collect();
+    let b: Vec<[f32; 2]> = (0..16).map(|i| [0.25, i as f32 / -16.0]).collect();
+    for &curve in curves.iter() {
+        let mut expected = a.clone();
+        dasp_slice::gain_ramp_in_place(&mut expected, curve, 0.25, 2.0);
+        let ramped: Vec<_> = signal::from_iter(a.clone())
+            .gain_ramp(curve, 0.25, 2.0, a.len())
+            .until_exhausted()
+            .collect();
+        assert_eq!(ramped, expected);
+
+        let mut expected = a.clone();
+        dasp_slice::crossfade_in_place(&mut expected, &b, curve);
+        let crossfaded: Vec<_> = signal::from_iter(a.clone())
+            .crossfade(signal::from_iter(b.clone()), curve, a.len())
+            .until_exhausted()
+            .collect();
+        assert_eq!(crossfaded, expected);
+    }
+}
+
+#[test]
+fn test_crossfade_continues_with_other() {
+    use dasp_sample::fade::Curve;
+
+    let a = signal::from_iter(vec![[1.0]; 2]);
+    let b = signal::from_iter(vec![[-1.0]; 4]);
+    let frames: Vec<_> = a.crossfade(b, Curve::Linear, 2).until_exhausted().collect();
+    assert_eq!(frames, vec![[1.0], [-1.0], [-1.0], [-1.0]]);
+}
+
+#[test]
+fn test_spsc_consumer_signal() {
+    use dasp_ring_buffer::spsc;
+
+    let (mut producer, consumer) = spsc::channel([0.0f32; 8]);
+    let handle = std::thread::spawn(move || {
+        let frames: Vec<f32> = (0..32).map(|i| i as f32).collect();
+        let mut pushed = 0;
+        while pushed < frames.len() {
+            pushed += producer.push_slice(&frames[pushed..]);
+        }
+    });
+    // Underruns yield silence, so skip them while waiting for the producer.
+    let frames: Vec<f32> = consumer.until_exhausted().filter(|&f| f != 0.0).collect();
+    handle.join().unwrap();
+    assert_eq!(frames, (1..32).map(|i| i as f32).collect::<Vec<_>>());
+}
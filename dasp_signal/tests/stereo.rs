@@ -0,0 +1,68 @@
+//! Tests for the `SignalStereo` trait.
+
+#![cfg(feature = "stereo")]
+
+use dasp_ring_buffer as ring_buffer;
+use dasp_signal::stereo::{CorrelationMeter, SignalStereo};
+use dasp_signal::{self as signal, Signal};
+
+#[test]
+fn test_stereo_adaptors() {
+    let frames = vec![[0.5f32, 0.25], [-0.5, 1.0], [0.0, 0.0]];
+    let round_trip: Vec<_> = signal::from_iter(frames.clone())
+        .to_mid_side()
+        .from_mid_side()
+        .until_exhausted()
+        .collect();
+    assert_eq!(round_trip, frames);
+
+    let mono: Vec<_> = signal::from_iter(frames.clone())
+        .stereo_width(0.0)
+        .until_exhausted()
+        .collect();
+    assert_eq!(mono, vec![[0.375, 0.375], [0.25, 0.25], [0.0, 0.0]]);
+
+    let balanced: Vec<_> = signal::from_iter(frames)
+        .balance(-0.5)
+        .until_exhausted()
+        .collect();
+    assert_eq!(balanced, vec![[0.5, 0.125], [-0.5, 0.5], [0.0, 0.0]]);
+}
+
+#[test]
+fn test_correlation_of_sine() {
+    let hz = signal::rate(48_000.0).const_hz(1_000.0);
+    let sine = hz.clone().sine();
+    let cosine = hz.phase().map(|p| (p * std::f64::consts::PI * 2.0).cos());
+    let window = ring_buffer::Fixed::from(vec![[0.0; 3]; 480]);
+    let mut mono = sine
+        .clone()
+        .map(|s| [s, s * 0.5])
+        .correlation(window.clone());
+    let mut inverted = sine.clone().map(|s| [s, -s]).correlation(window.clone());
+    let mut quadrature = sine.zip_map(cosine, |s, c| [s, c]).correlation(window);
+    for _ in 0..480 {
+        mono.next();
+        inverted.next();
+        quadrature.next();
+    }
+    assert!((mono.next() - 1.0).abs() < 1e-9);
+    assert!((inverted.next() + 1.0).abs() < 1e-9);
+    assert!(quadrature.next().abs() < 1e-6);
+}
+
+#[test]
+fn test_correlation_meter() {
+    let mut meter = CorrelationMeter::new(ring_buffer::Fixed::from([[0.0f32; 3]; 2]));
+    assert_eq!(meter.window_frames(), 2);
+    assert_eq!(meter.current(), 0.0);
+    // A silent channel is uncorrelated.
+    assert_eq!(meter.next([0.5f32, 0.0]), 0.0);
+    let half_correlated = meter.next([0.5f32, 0.5]);
+    // The `no_std` square root is only accurate to around 1e-6.
+    assert!((half_correlated - std::f32::consts::FRAC_1_SQRT_2).abs() < 1e-5);
+    assert_eq!(meter.next([-0.5f32, -0.5]), 1.0);
+    meter.reset();
+    assert_eq!(meter.current(), 0.0);
+    assert_eq!(meter.next([8_192i16, 8_192]), 1.0);
+}
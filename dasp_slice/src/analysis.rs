@@ -0,0 +1,334 @@
+//! Analysis of whole slices of frames, e.g. for inspecting a finished buffer.
+//!
+//! Per-channel results are produced as frames by [**peak**](./fn.peak.html),
+//! [**rms**](./fn.rms.html) and [**dc_offset**](./fn.dc_offset.html), while
+//! [**analyze**](./fn.analyze.html) and [**analyze_channel**](./fn.analyze_channel.html) gather a
+//! complete set of [**Stats**](./struct.Stats.html) across all channels or for a single channel.
+//!
+//! Peaks are measured with the rectifiers of `dasp_peak` and RMS with the same arithmetic as
+//! `dasp_rms`, so that the RMS of a slice is exactly that produced by a `dasp_rms::Rms` whose
+//! window is the length of the slice.
+//!
+//! ### Required Features
+//!
+//! - When using `dasp_slice`, this module requires the **analysis** feature to be enabled.
+//! - When using `dasp`, this module requires the **slice-analysis** feature to be enabled.
+
+use core::fmt;
+use dasp_frame::Frame;
+use dasp_peak::Rectifier;
+use dasp_sample::{FloatSample, Sample};
+
+/// A summary of the level of a slice of samples.
+///
+/// ### Required Features
+///
+/// - When using `dasp_slice`, this item requires the **analysis** feature to be enabled.
+/// - When using `dasp`, this item requires the **slice-analysis** feature to be enabled.
+pub struct Stats<S>
+where
+    S: Sample,
+{
+    /// The number of samples analysed.
+    pub len: usize,
+    /// The magnitude of the sample furthest from the equilibrium.
+    pub peak: S::Float,
+    /// The root mean square of the samples.
+    pub rms: S::Float,
+    /// The mean of the samples, relative to the equilibrium.
+    pub dc_offset: S::Float,
+    /// The ratio of the peak to the RMS, or the equilibrium if the RMS is the equilibrium.
+    pub crest_factor: S::Float,
+    /// The number of samples at or beyond the minimum or maximum value of the sample type.
+    pub clipped: usize,
+}
+
+impl<S> Clone for Stats<S>
+where
+    S: Sample,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S> Copy for Stats<S> where S: Sample {}
+
+impl<S> PartialEq for Stats<S>
+where
+    S: Sample,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len
+            && self.peak == other.peak
+            && self.rms == other.rms
+            && self.dc_offset == other.dc_offset
+            && self.crest_factor == other.crest_factor
+            && self.clipped == other.clipped
+    }
+}
+
+impl<S> fmt::Debug for Stats<S>
+where
+    S: Sample,
+    S::Float: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Stats")
+            .field("len", &self.len)
+            .field("peak", &self.peak)
+            .field("rms", &self.rms)
+            .field("dc_offset", &self.dc_offset)
+            .field("crest_factor", &self.crest_factor)
+            .field("clipped", &self.clipped)
+            .finish()
+    }
+}
+
+// Accumulates the `Stats` of a sequence of samples.
+struct Accumulator<S>
+where
+    S: Sample,
+{
+    len: usize,
+    peak: S::Float,
+    square_sum: S::Float,
+    sum: S::Float,
+    clipped: usize,
+    min: S,
+    max: S,
+}
+
+impl<S> Accumulator<S>
+where
+    S: Sample,
+{
+    fn new() -> Self {
+        let one = <S::Float as FloatSample>::IDENTITY;
+        Accumulator {
+            len: 0,
+            peak: S::Float::EQUILIBRIUM,
+            square_sum: S::Float::EQUILIBRIUM,
+            sum: S::Float::EQUILIBRIUM,
+            clipped: 0,
+            // Conversions from float saturate at the bounds of the sample type.
+            min: (-one).to_sample(),
+            max: one.to_sample(),
+        }
+    }
+
+    #[inline]
+    fn push(&mut self, sample: S) {
+        // Rectify the float sample, as the magnitude of the minimum signed integer overflows.
+        let [rectified] = dasp_peak::full_wave([sample.to_float_sample()]);
+        if rectified > self.peak {
+            self.peak = rectified;
+        }
+        let [square] = dasp_rms::square([sample]);
+        self.square_sum = self.square_sum + square;
+        self.sum = self.sum + sample.to_float_sample();
+        if sample <= self.min || sample >= self.max {
+            self.clipped += 1;
+        }
+        self.len += 1;
+    }
+
+    fn stats(self) -> Stats<S> {
+        if self.len == 0 {
+            return Stats {
+                len: 0,
+                peak: self.peak,
+                rms: S::Float::EQUILIBRIUM,
+                dc_offset: S::Float::EQUILIBRIUM,
+                crest_factor: S::Float::EQUILIBRIUM,
+                clipped: 0,
+            };
+        }
+        let [mean_square] = dasp_rms::mean_square([self.square_sum], self.len);
+        let rms = mean_square.sample_sqrt();
+        let dc_offset = self.sum / (self.len as f32).to_sample();
+        let crest_factor = if rms == S::Float::EQUILIBRIUM {
+            S::Float::EQUILIBRIUM
+        } else {
+            self.peak / rms
+        };
+        Stats {
+            len: self.len,
+            peak: self.peak,
+            rms,
+            dc_offset,
+            crest_factor,
+            clipped: self.clipped,
+        }
+    }
+}
+
+/// The sample furthest from the equilibrium in each channel after rectifying each frame with the
+/// given rectifier.
+///
+/// # Example
+///
+/// ```
+/// use dasp_peak::{FullWave, NegativeHalfWave};
+/// use dasp_slice::analysis;
+///
+/// fn main() {
+///     let frames = [[0.25, -0.5], [-0.75, 0.5]];
+///     assert_eq!(analysis::peak(&frames, FullWave), [0.75, 0.5]);
+///     assert_eq!(analysis::peak(&frames, NegativeHalfWave), [-0.75, -0.5]);
+/// }
+/// ```
+///
+/// ### Required Features
+///
+/// - When using `dasp_slice`, this item requires the **analysis** feature to be enabled.
+/// - When using `dasp`, this item requires the **slice-analysis** feature to be enabled.
+pub fn peak<F, R>(a: &[F], mut rectifier: R) -> R::Output
+where
+    F: Frame,
+    R: Rectifier<F>,
+{
+    a.iter().fold(R::Output::EQUILIBRIUM, |peak, &frame| {
+        peak.zip_map(rectifier.rectify(frame), |p, r| {
+            if magnitude(r) > magnitude(p) {
+                r
+            } else {
+                p
+            }
+        })
+    })
+}
+
+/// The root mean square of each channel.
+///
+/// The result is exactly that produced by a `dasp_rms::Rms` with a window of `a.len()` frames
+/// after each frame of `a` has been pushed.
+///
+/// # Example
+///
+/// ```
+/// use dasp_slice::analysis;
+///
+/// fn main() {
+///     let frames = [[1.0, 0.5], [-1.0, -0.5], [1.0, 0.5], [-1.0, -0.5]];
+///     assert_eq!(analysis::rms(&frames), [1.0, 0.5]);
+/// }
+/// ```
+///
+/// ### Required Features
+///
+/// - When using `dasp_slice`, this item requires the **analysis** feature to be enabled.
+/// - When using `dasp`, this item requires the **slice-analysis** feature to be enabled.
+pub fn rms<F>(a: &[F]) -> F::Float
+where
+    F: Frame,
+{
+    if a.is_empty() {
+        return F::Float::EQUILIBRIUM;
+    }
+    let square_sum = a.iter().fold(F::Float::EQUILIBRIUM, |sum, &frame| {
+        sum.add_amp(dasp_rms::square(frame))
+    });
+    dasp_rms::mean_square(square_sum, a.len()).map(|s| s.sample_sqrt())
+}
+
+/// The mean of each channel relative to the equilibrium.
+///
+/// # Example
+///
+/// ```
+/// use dasp_slice::analysis;
+///
+/// fn main() {
+///     let frames = [[128u8, 192], [128, 160]];
+///     assert_eq!(analysis::dc_offset(&frames), [0.0, 0.375]);
+/// }
+/// ```
+///
+/// ### Required Features
+///
+/// - When using `dasp_slice`, this item requires the **analysis** feature to be enabled.
+/// - When using `dasp`, this item requires the **slice-analysis** feature to be enabled.
+pub fn dc_offset<F>(a: &[F]) -> F::Float
+where
+    F: Frame,
+{
+    if a.is_empty() {
+        return F::Float::EQUILIBRIUM;
+    }
+    let sum = a.iter().fold(F::Float::EQUILIBRIUM, |sum, &frame| {
+        sum.add_amp(frame.to_float_frame())
+    });
+    let len = (a.len() as f32).to_sample::<<F::Sample as Sample>::Float>();
+    sum.map(|s| s / len)
+}
+
+/// The **Stats** of every sample across all channels of the slice.
+///
+/// # Example
+///
+/// ```
+/// use dasp_slice::analysis;
+///
+/// fn main() {
+///     let frames = [[0i16, -32_768], [16_384, 0]];
+///     let stats = analysis::analyze(&frames);
+///     assert_eq!(stats.len, 4);
+///     assert_eq!(stats.peak, 1.0);
+///     assert_eq!(stats.clipped, 1);
+///     assert_eq!(stats.dc_offset, -0.125);
+///     assert_eq!(stats.rms, (1.25f32 / 4.0).sqrt());
+///     assert_eq!(stats.crest_factor, 1.0 / stats.rms);
+/// }
+/// ```
+///
+/// ### Required Features
+///
+/// - When using `dasp_slice`, this item requires the **analysis** feature to be enabled.
+/// - When using `dasp`, this item requires the **slice-analysis** feature to be enabled.
+pub fn analyze<F>(a: &[F]) -> Stats<F::Sample>
+where
+    F: Frame,
+{
+    let mut acc = Accumulator::new();
+    for frame in a {
+        for &sample in frame.channels_ref() {
+            acc.push(sample);
+        }
+    }
+    acc.stats()
+}
+
+/// The **Stats** of the channel at the given index, or `None` if the frames of the slice have no
+/// such channel.
+///
+/// The `rms` and `dc_offset` match those produced by **rms** and **dc_offset** for the same
+/// channel.
+///
+/// ### Required Features
+///
+/// - When using `dasp_slice`, this item requires the **analysis** feature to be enabled.
+/// - When using `dasp`, this item requires the **slice-analysis** feature to be enabled.
+pub fn analyze_channel<F>(a: &[F], channel: usize) -> Option<Stats<F::Sample>>
+where
+    F: Frame,
+{
+    if channel >= F::CHANNELS {
+        return None;
+    }
+    let mut acc = Accumulator::new();
+    for frame in a {
+        acc.push(*frame.channel(channel)?);
+    }
+    Some(acc.stats())
+}
+
+// The distance of the sample from the equilibrium.
+#[inline]
+fn magnitude<S>(sample: S) -> S::Float
+where
+    S: Sample,
+{
+    let [magnitude] = dasp_peak::full_wave([sample.to_float_sample()]);
+    magnitude
+}
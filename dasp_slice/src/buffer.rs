@@ -0,0 +1,386 @@
+//! An owned buffer of frames that carries its sample rate and, optionally, its channel layout.
+//!
+//! ### Required Features
+//!
+//! - When using `dasp_slice`, this module requires the **boxed** feature to be enabled.
+//! - When using `dasp`, this module requires the **slice-boxed** feature to be enabled.
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+use crate::boxed::Box;
+use core::ops::{Deref, DerefMut};
+use dasp_frame::layout::ChannelLayout;
+use dasp_frame::{FixedChannels, Frame};
+use dasp_sample::{Sample, ToSample};
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::{self, Vec};
+#[cfg(feature = "std")]
+use std::vec::{self, Vec};
+
+/// An owned buffer of frames along with their sample rate in hz and an optional channel layout.
+///
+/// **AudioBuffer** dereferences to a slice of its frames, so all of the slice functions within
+/// this crate may be applied to it directly.
+///
+/// A **Signal** may be produced from a buffer by passing it to `dasp_signal::from_iter`, while
+/// the frames of a **Signal** may be collected into a buffer with
+/// [**from_frames_iter**](./struct.AudioBuffer.html#method.from_frames_iter), e.g. via
+/// `Signal::until_exhausted` or `Signal::take`.
+///
+/// # Example
+///
+/// ```
+/// use dasp_slice::AudioBuffer;
+///
+/// fn main() {
+///     let frames = vec![[0.0, 0.5], [0.25, -0.25], [-0.5, 1.0], [0.0, 0.0]];
+///     let mut buffer = AudioBuffer::from_frames(frames, 4.0);
+///     assert_eq!(buffer.len(), 4);
+///     assert_eq!(buffer.duration_secs(), 1.0);
+///     assert_eq!(buffer.slice_secs(0.25, 0.75), &[[0.25, -0.25], [-0.5, 1.0]]);
+///
+///     buffer.resize(6);
+///     assert_eq!(buffer.duration_secs(), 1.5);
+///
+///     let converted = buffer.convert::<[i16; 2]>();
+///     assert_eq!(converted[0], [0, 16_384]);
+///     assert_eq!(converted.sample_rate(), 4.0);
+/// }
+/// ```
+///
+/// ### Required Features
+///
+/// - When using `dasp_slice`, this item requires the **boxed** feature to be enabled.
+/// - When using `dasp`, this item requires the **slice-boxed** feature to be enabled.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AudioBuffer<F> {
+    frames: Vec<F>,
+    sample_rate: f64,
+    layout: Option<ChannelLayout>,
+}
+
+impl<F> AudioBuffer<F>
+where
+    F: Frame,
+{
+    /// An empty buffer with the given sample rate in hz.
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_slice`, this item requires the **boxed** feature to be enabled.
+    /// - When using `dasp`, this item requires the **slice-boxed** feature to be enabled.
+    pub fn new(sample_rate: f64) -> Self {
+        Self::from_frames(Vec::new(), sample_rate)
+    }
+
+    /// A buffer of `len` frames of silence with the given sample rate in hz.
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_slice`, this item requires the **boxed** feature to be enabled.
+    /// - When using `dasp`, this item requires the **slice-boxed** feature to be enabled.
+    pub fn silence(len: usize, sample_rate: f64) -> Self {
+        let mut buffer = Self::new(sample_rate);
+        buffer.resize(len);
+        buffer
+    }
+
+    /// A buffer of the given frames with the given sample rate in hz.
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_slice`, this item requires the **boxed** feature to be enabled.
+    /// - When using `dasp`, this item requires the **slice-boxed** feature to be enabled.
+    pub fn from_frames(frames: Vec<F>, sample_rate: f64) -> Self {
+        AudioBuffer {
+            frames,
+            sample_rate,
+            layout: None,
+        }
+    }
+
+    /// A buffer of the frames yielded by the given iterator with the given sample rate in hz.
+    ///
+    /// ```
+    /// use dasp_slice::AudioBuffer;
+    ///
+    /// fn main() {
+    ///     let ramp = (0..441).map(|i| [i as f32 / 441.0]);
+    ///     let buffer = AudioBuffer::from_frames_iter(ramp, 44_100.0);
+    ///     assert_eq!(buffer.duration_secs(), 0.01);
+    ///     assert_eq!(buffer[0], [0.0]);
+    /// }
+    /// ```
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_slice`, this item requires the **boxed** feature to be enabled.
+    /// - When using `dasp`, this item requires the **slice-boxed** feature to be enabled.
+    pub fn from_frames_iter<I>(frames: I, sample_rate: f64) -> Self
+    where
+        I: IntoIterator<Item = F>,
+    {
+        Self::from_frames(frames.into_iter().collect(), sample_rate)
+    }
+
+    /// Attach the given channel layout to the buffer.
+    ///
+    /// **Panics** if the number of channels in `layout` is not equal to `F::CHANNELS`.
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_slice`, this item requires the **boxed** feature to be enabled.
+    /// - When using `dasp`, this item requires the **slice-boxed** feature to be enabled.
+    pub fn with_layout(mut self, layout: ChannelLayout) -> Self
+    where
+        F::NumChannels: FixedChannels,
+    {
+        self.set_layout(Some(layout));
+        self
+    }
+
+    /// The sample rate of the buffer in hz.
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_slice`, this item requires the **boxed** feature to be enabled.
+    /// - When using `dasp`, this item requires the **slice-boxed** feature to be enabled.
+    pub fn sample_rate(&self) -> f64 {
+        self.sample_rate
+    }
+
+    /// Set the sample rate of the buffer in hz.
+    ///
+    /// The frames are left unchanged and are simply interpreted at the new rate.
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_slice`, this item requires the **boxed** feature to be enabled.
+    /// - When using `dasp`, this item requires the **slice-boxed** feature to be enabled.
+    pub fn set_sample_rate(&mut self, sample_rate: f64) {
+        self.sample_rate = sample_rate;
+    }
+
+    /// The channel layout of the buffer, if one is attached.
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_slice`, this item requires the **boxed** feature to be enabled.
+    /// - When using `dasp`, this item requires the **slice-boxed** feature to be enabled.
+    pub fn layout(&self) -> Option<ChannelLayout> {
+        self.layout
+    }
+
+    /// Attach or remove the channel layout of the buffer.
+    ///
+    /// **Panics** if the number of channels in `layout` is not equal to `F::CHANNELS`.
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_slice`, this item requires the **boxed** feature to be enabled.
+    /// - When using `dasp`, this item requires the **slice-boxed** feature to be enabled.
+    pub fn set_layout(&mut self, layout: Option<ChannelLayout>)
+    where
+        F::NumChannels: FixedChannels,
+    {
+        if let Some(layout) = layout {
+            assert_eq!(
+                layout.len(),
+                F::CHANNELS,
+                "channel layout does not match the number of channels"
+            );
+        }
+        self.layout = layout;
+    }
+
+    /// The duration of the buffer in seconds.
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_slice`, this item requires the **boxed** feature to be enabled.
+    /// - When using `dasp`, this item requires the **slice-boxed** feature to be enabled.
+    pub fn duration_secs(&self) -> f64 {
+        self.frames.len() as f64 / self.sample_rate
+    }
+
+    /// The index of the frame at the given time in seconds, rounded to the nearest frame and
+    /// clamped to the length of the buffer.
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_slice`, this item requires the **boxed** feature to be enabled.
+    /// - When using `dasp`, this item requires the **slice-boxed** feature to be enabled.
+    pub fn frame_at_secs(&self, secs: f64) -> usize {
+        // Negative and NaN values saturate to `0` when cast.
+        let idx = (secs * self.sample_rate + 0.5) as usize;
+        core::cmp::min(idx, self.frames.len())
+    }
+
+    /// The frames between the given start and end times in seconds.
+    ///
+    /// Times are rounded to the nearest frame and clamped to the length of the buffer. If `end`
+    /// is before `start`, the slice is empty.
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_slice`, this item requires the **boxed** feature to be enabled.
+    /// - When using `dasp`, this item requires the **slice-boxed** feature to be enabled.
+    pub fn slice_secs(&self, start: f64, end: f64) -> &[F] {
+        let (start, end) = self.secs_range(start, end);
+        &self.frames[start..end]
+    }
+
+    /// Mutable access to the frames between the given start and end times in seconds.
+    ///
+    /// Times are rounded to the nearest frame and clamped to the length of the buffer. If `end`
+    /// is before `start`, the slice is empty.
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_slice`, this item requires the **boxed** feature to be enabled.
+    /// - When using `dasp`, this item requires the **slice-boxed** feature to be enabled.
+    pub fn slice_secs_mut(&mut self, start: f64, end: f64) -> &mut [F] {
+        let (start, end) = self.secs_range(start, end);
+        &mut self.frames[start..end]
+    }
+
+    /// Resize the buffer to the given number of frames, padding with silence or truncating the
+    /// end as necessary.
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_slice`, this item requires the **boxed** feature to be enabled.
+    /// - When using `dasp`, this item requires the **slice-boxed** feature to be enabled.
+    pub fn resize(&mut self, len: usize) {
+        self.frames.resize(len, F::EQUILIBRIUM);
+    }
+
+    /// Append a single frame to the end of the buffer.
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_slice`, this item requires the **boxed** feature to be enabled.
+    /// - When using `dasp`, this item requires the **slice-boxed** feature to be enabled.
+    pub fn push(&mut self, frame: F) {
+        self.frames.push(frame);
+    }
+
+    /// Append a copy of the given frames to the end of the buffer.
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_slice`, this item requires the **boxed** feature to be enabled.
+    /// - When using `dasp`, this item requires the **slice-boxed** feature to be enabled.
+    pub fn extend_from_slice(&mut self, frames: &[F]) {
+        self.frames.extend_from_slice(frames);
+    }
+
+    /// Append a copy of the frames of the given buffer to the end of this buffer.
+    ///
+    /// **Panics** if the sample rates of the two buffers differ, or if both buffers have a
+    /// channel layout and those layouts differ.
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_slice`, this item requires the **boxed** feature to be enabled.
+    /// - When using `dasp`, this item requires the **slice-boxed** feature to be enabled.
+    pub fn append(&mut self, other: &Self) {
+        assert_eq!(self.sample_rate, other.sample_rate);
+        if let (Some(a), Some(b)) = (self.layout, other.layout) {
+            assert_eq!(a, b);
+        }
+        self.layout = self.layout.or(other.layout);
+        self.frames.extend_from_slice(&other.frames);
+    }
+
+    /// A copy of the buffer with each sample converted to the sample type of `G`.
+    ///
+    /// The sample rate and channel layout are preserved.
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_slice`, this item requires the **boxed** feature to be enabled.
+    /// - When using `dasp`, this item requires the **slice-boxed** feature to be enabled.
+    pub fn convert<G>(&self) -> AudioBuffer<G>
+    where
+        G: Frame<NumChannels = F::NumChannels>,
+        F::Sample: ToSample<G::Sample>,
+    {
+        AudioBuffer {
+            frames: self
+                .frames
+                .iter()
+                .map(|f| f.map(Sample::to_sample))
+                .collect(),
+            sample_rate: self.sample_rate,
+            layout: self.layout,
+        }
+    }
+
+    /// Consumes the buffer and returns its frames.
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_slice`, this item requires the **boxed** feature to be enabled.
+    /// - When using `dasp`, this item requires the **slice-boxed** feature to be enabled.
+    pub fn into_frames(self) -> Vec<F> {
+        self.frames
+    }
+
+    /// Consumes the buffer and returns its frames as a boxed slice.
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_slice`, this item requires the **boxed** feature to be enabled.
+    /// - When using `dasp`, this item requires the **slice-boxed** feature to be enabled.
+    pub fn into_boxed_slice(self) -> Box<[F]> {
+        self.frames.into_boxed_slice()
+    }
+
+    // The range of frame indices between the given times.
+    fn secs_range(&self, start: f64, end: f64) -> (usize, usize) {
+        let start = self.frame_at_secs(start);
+        let end = core::cmp::max(start, self.frame_at_secs(end));
+        (start, end)
+    }
+}
+
+impl<F> Deref for AudioBuffer<F> {
+    type Target = [F];
+    fn deref(&self) -> &Self::Target {
+        &self.frames[..]
+    }
+}
+
+impl<F> DerefMut for AudioBuffer<F> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.frames[..]
+    }
+}
+
+impl<F> Extend<F> for AudioBuffer<F> {
+    fn extend<I>(&mut self, frames: I)
+    where
+        I: IntoIterator<Item = F>,
+    {
+        self.frames.extend(frames);
+    }
+}
+
+impl<F> IntoIterator for AudioBuffer<F> {
+    type Item = F;
+    type IntoIter = vec::IntoIter<F>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.frames.into_iter()
+    }
+}
+
+impl<'a, F> IntoIterator for &'a AudioBuffer<F> {
+    type Item = &'a F;
+    type IntoIter = core::slice::Iter<'a, F>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.frames.iter()
+    }
+}
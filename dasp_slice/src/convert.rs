@@ -0,0 +1,333 @@
+//! Bulk sample format conversion, vectorised for the most common pairs of sample types.
+//!
+//! Conversions between `f32` and each of `i16`, `I24` and `u8` use SSE2 on x86_64 and NEON on
+//! aarch64, both of which are always available on their respective targets. All other pairs of
+//! sample types, along with any samples that remain after the last full vector, are converted
+//! one at a time via **ToSample**.
+//!
+//! Each vectorised conversion produces results that are bit-identical to those of
+//! `dasp_sample::conv`:
+//!
+//! - Integer to float conversions are exact, as every integer sample is exactly representable as
+//!   an `f32` and the following scale is a power of two.
+//! - Float to integer conversions truncate towards zero, saturate at the bounds of the target
+//!   type and map NaN to zero, matching the semantics of `as`.
+
+use dasp_sample::{Sample, ToSample};
+#[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+use {core::any::TypeId, dasp_sample::I24};
+
+/// Convert each sample in `src` to the sample type of `dst`.
+///
+/// `src` and `dst` must be the same length.
+pub fn samples<S, T>(src: &[S], dst: &mut [T])
+where
+    S: Sample + ToSample<T> + 'static,
+    T: Sample + 'static,
+{
+    debug_assert_eq!(src.len(), dst.len());
+    #[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+    {
+        if let (Some(src), Some(dst)) = (cast::<S, i16>(src), cast_mut::<T, f32>(dst)) {
+            return i16_to_f32(src, dst);
+        }
+        if let (Some(src), Some(dst)) = (cast::<S, f32>(src), cast_mut::<T, i16>(dst)) {
+            return f32_to_i16(src, dst);
+        }
+        if let (Some(src), Some(dst)) = (cast::<S, I24>(src), cast_mut::<T, f32>(dst)) {
+            return i24_to_f32(src, dst);
+        }
+        if let (Some(src), Some(dst)) = (cast::<S, f32>(src), cast_mut::<T, I24>(dst)) {
+            return f32_to_i24(src, dst);
+        }
+        if let (Some(src), Some(dst)) = (cast::<S, u8>(src), cast_mut::<T, f32>(dst)) {
+            return u8_to_f32(src, dst);
+        }
+        if let (Some(src), Some(dst)) = (cast::<S, f32>(src), cast_mut::<T, u8>(dst)) {
+            return f32_to_u8(src, dst);
+        }
+    }
+    scalar(src, dst);
+}
+
+// Convert the samples one at a time.
+#[inline]
+fn scalar<S, T>(src: &[S], dst: &mut [T])
+where
+    S: Sample + ToSample<T>,
+{
+    for (d, &s) in dst.iter_mut().zip(src) {
+        *d = s.to_sample_();
+    }
+}
+
+// Reinterpret the slice as a slice of `U` if `T` is `U`.
+#[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+#[inline]
+fn cast<T: 'static, U: 'static>(slice: &[T]) -> Option<&[U]> {
+    if TypeId::of::<T>() == TypeId::of::<U>() {
+        // `T` and `U` are the same type.
+        unsafe {
+            Some(core::slice::from_raw_parts(
+                slice.as_ptr() as *const U,
+                slice.len(),
+            ))
+        }
+    } else {
+        None
+    }
+}
+
+// Reinterpret the slice as a mutable slice of `U` if `T` is `U`.
+#[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+#[inline]
+fn cast_mut<T: 'static, U: 'static>(slice: &mut [T]) -> Option<&mut [U]> {
+    if TypeId::of::<T>() == TypeId::of::<U>() {
+        // `T` and `U` are the same type.
+        unsafe {
+            Some(core::slice::from_raw_parts_mut(
+                slice.as_mut_ptr() as *mut U,
+                slice.len(),
+            ))
+        }
+    } else {
+        None
+    }
+}
+
+// `I24` is a `#[repr(transparent)]` wrapper around an `i32`.
+#[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+#[inline]
+fn i24_as_i32(slice: &[I24]) -> &[i32] {
+    unsafe { core::slice::from_raw_parts(slice.as_ptr() as *const i32, slice.len()) }
+}
+
+#[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+#[inline]
+fn i24_as_i32_mut(slice: &mut [I24]) -> &mut [i32] {
+    unsafe { core::slice::from_raw_parts_mut(slice.as_mut_ptr() as *mut i32, slice.len()) }
+}
+
+// The number of samples converted by each iteration of the vectorised loops.
+#[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+const LANES: usize = 8;
+
+// The magnitude of the minimum value of each integer format, i.e. the scale of `1.0`.
+#[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+const I16_SCALE: f32 = 32_768.0;
+#[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+const I24_SCALE: f32 = 8_388_608.0;
+#[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+const I8_SCALE: f32 = 128.0;
+
+#[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+fn i16_to_f32(src: &[i16], dst: &mut [f32]) {
+    let n = src.len() - src.len() % LANES;
+    let (src, src_tail) = src.split_at(n);
+    let (dst, dst_tail) = dst.split_at_mut(n);
+    for (s, d) in src.chunks_exact(LANES).zip(dst.chunks_exact_mut(LANES)) {
+        unsafe { arch::i16_to_f32(s.as_ptr(), d.as_mut_ptr()) }
+    }
+    scalar(src_tail, dst_tail);
+}
+
+#[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+fn f32_to_i16(src: &[f32], dst: &mut [i16]) {
+    let n = src.len() - src.len() % LANES;
+    let (src, src_tail) = src.split_at(n);
+    let (dst, dst_tail) = dst.split_at_mut(n);
+    for (s, d) in src.chunks_exact(LANES).zip(dst.chunks_exact_mut(LANES)) {
+        unsafe { arch::f32_to_i16(s.as_ptr(), d.as_mut_ptr()) }
+    }
+    scalar(src_tail, dst_tail);
+}
+
+#[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+fn i24_to_f32(src: &[I24], dst: &mut [f32]) {
+    let n = src.len() - src.len() % LANES;
+    let (src, src_tail) = src.split_at(n);
+    let (dst, dst_tail) = dst.split_at_mut(n);
+    let src_i32 = i24_as_i32(src);
+    for (s, d) in src_i32.chunks_exact(LANES).zip(dst.chunks_exact_mut(LANES)) {
+        unsafe { arch::i32_to_f32(s.as_ptr(), d.as_mut_ptr(), 1.0 / I24_SCALE) }
+    }
+    scalar(src_tail, dst_tail);
+}
+
+#[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+fn f32_to_i24(src: &[f32], dst: &mut [I24]) {
+    let n = src.len() - src.len() % LANES;
+    let (src, src_tail) = src.split_at(n);
+    let (dst, dst_tail) = dst.split_at_mut(n);
+    let dst_i32 = i24_as_i32_mut(dst);
+    for (s, d) in src.chunks_exact(LANES).zip(dst_i32.chunks_exact_mut(LANES)) {
+        unsafe { arch::f32_to_i24(s.as_ptr(), d.as_mut_ptr()) }
+    }
+    scalar(src_tail, dst_tail);
+}
+
+#[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+fn u8_to_f32(src: &[u8], dst: &mut [f32]) {
+    let n = src.len() - src.len() % LANES;
+    let (src, src_tail) = src.split_at(n);
+    let (dst, dst_tail) = dst.split_at_mut(n);
+    for (s, d) in src.chunks_exact(LANES).zip(dst.chunks_exact_mut(LANES)) {
+        unsafe { arch::u8_to_f32(s.as_ptr(), d.as_mut_ptr()) }
+    }
+    scalar(src_tail, dst_tail);
+}
+
+#[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+fn f32_to_u8(src: &[f32], dst: &mut [u8]) {
+    let n = src.len() - src.len() % LANES;
+    let (src, src_tail) = src.split_at(n);
+    let (dst, dst_tail) = dst.split_at_mut(n);
+    for (s, d) in src.chunks_exact(LANES).zip(dst.chunks_exact_mut(LANES)) {
+        unsafe { arch::f32_to_u8(s.as_ptr(), d.as_mut_ptr()) }
+    }
+    scalar(src_tail, dst_tail);
+}
+
+// Each function converts `LANES` samples from `src` to `dst`. Neither pointer need be aligned.
+#[cfg(target_arch = "x86_64")]
+mod arch {
+    use super::{I16_SCALE, I24_SCALE, I8_SCALE};
+    use core::arch::x86_64::*;
+
+    // Zero any NaN lanes, then clamp to `[min, max]`. `cvttps` maps NaN and out of range values
+    // to `i32::MIN`, whereas `as` saturates and maps NaN to zero.
+    #[inline(always)]
+    unsafe fn sanitize(x: __m128, min: f32, max: f32) -> __m128 {
+        let x = _mm_and_ps(x, _mm_cmpord_ps(x, x));
+        _mm_min_ps(_mm_max_ps(x, _mm_set1_ps(min)), _mm_set1_ps(max))
+    }
+
+    #[inline(always)]
+    unsafe fn to_i32(src: *const f32, scale: f32, min: f32, max: f32) -> (__m128i, __m128i) {
+        let scale = _mm_set1_ps(scale);
+        let lo = _mm_mul_ps(_mm_loadu_ps(src), scale);
+        let hi = _mm_mul_ps(_mm_loadu_ps(src.add(4)), scale);
+        (
+            _mm_cvttps_epi32(sanitize(lo, min, max)),
+            _mm_cvttps_epi32(sanitize(hi, min, max)),
+        )
+    }
+
+    #[inline(always)]
+    unsafe fn store_f32(lo: __m128i, hi: __m128i, dst: *mut f32, scale: f32) {
+        let scale = _mm_set1_ps(scale);
+        _mm_storeu_ps(dst, _mm_mul_ps(_mm_cvtepi32_ps(lo), scale));
+        _mm_storeu_ps(dst.add(4), _mm_mul_ps(_mm_cvtepi32_ps(hi), scale));
+    }
+
+    #[inline]
+    pub unsafe fn i16_to_f32(src: *const i16, dst: *mut f32) {
+        let x = _mm_loadu_si128(src as *const __m128i);
+        // Sign extend each `i16` to an `i32`.
+        let lo = _mm_srai_epi32(_mm_unpacklo_epi16(x, x), 16);
+        let hi = _mm_srai_epi32(_mm_unpackhi_epi16(x, x), 16);
+        store_f32(lo, hi, dst, 1.0 / I16_SCALE);
+    }
+
+    #[inline]
+    pub unsafe fn f32_to_i16(src: *const f32, dst: *mut i16) {
+        let (lo, hi) = to_i32(src, I16_SCALE, -32_768.0, 32_767.0);
+        _mm_storeu_si128(dst as *mut __m128i, _mm_packs_epi32(lo, hi));
+    }
+
+    #[inline]
+    pub unsafe fn i32_to_f32(src: *const i32, dst: *mut f32, scale: f32) {
+        let lo = _mm_loadu_si128(src as *const __m128i);
+        let hi = _mm_loadu_si128(src.add(4) as *const __m128i);
+        store_f32(lo, hi, dst, scale);
+    }
+
+    #[inline]
+    pub unsafe fn f32_to_i24(src: *const f32, dst: *mut i32) {
+        let (lo, hi) = to_i32(src, I24_SCALE, -8_388_608.0, 8_388_607.0);
+        _mm_storeu_si128(dst as *mut __m128i, lo);
+        _mm_storeu_si128(dst.add(4) as *mut __m128i, hi);
+    }
+
+    #[inline]
+    pub unsafe fn u8_to_f32(src: *const u8, dst: *mut f32) {
+        let zero = _mm_setzero_si128();
+        let x = _mm_unpacklo_epi8(_mm_loadl_epi64(src as *const __m128i), zero);
+        let x = _mm_sub_epi16(x, _mm_set1_epi16(128));
+        let lo = _mm_srai_epi32(_mm_unpacklo_epi16(x, x), 16);
+        let hi = _mm_srai_epi32(_mm_unpackhi_epi16(x, x), 16);
+        store_f32(lo, hi, dst, 1.0 / I8_SCALE);
+    }
+
+    #[inline]
+    pub unsafe fn f32_to_u8(src: *const f32, dst: *mut u8) {
+        let (lo, hi) = to_i32(src, I8_SCALE, -128.0, 127.0);
+        let x = _mm_add_epi16(_mm_packs_epi32(lo, hi), _mm_set1_epi16(128));
+        _mm_storel_epi64(dst as *mut __m128i, _mm_packus_epi16(x, x));
+    }
+}
+
+// Each function converts `LANES` samples from `src` to `dst`. Neither pointer need be aligned.
+#[cfg(target_arch = "aarch64")]
+mod arch {
+    use super::{I16_SCALE, I24_SCALE, I8_SCALE};
+    use core::arch::aarch64::*;
+
+    // `fcvtzs` truncates, saturates and maps NaN to zero, matching the semantics of `as`.
+    #[inline(always)]
+    unsafe fn to_i32(src: *const f32, scale: f32) -> (int32x4_t, int32x4_t) {
+        let lo = vmulq_n_f32(vld1q_f32(src), scale);
+        let hi = vmulq_n_f32(vld1q_f32(src.add(4)), scale);
+        (vcvtq_s32_f32(lo), vcvtq_s32_f32(hi))
+    }
+
+    #[inline(always)]
+    unsafe fn store_f32(lo: int32x4_t, hi: int32x4_t, dst: *mut f32, scale: f32) {
+        vst1q_f32(dst, vmulq_n_f32(vcvtq_f32_s32(lo), scale));
+        vst1q_f32(dst.add(4), vmulq_n_f32(vcvtq_f32_s32(hi), scale));
+    }
+
+    #[inline]
+    pub unsafe fn i16_to_f32(src: *const i16, dst: *mut f32) {
+        let x = vld1q_s16(src);
+        let lo = vmovl_s16(vget_low_s16(x));
+        let hi = vmovl_s16(vget_high_s16(x));
+        store_f32(lo, hi, dst, 1.0 / I16_SCALE);
+    }
+
+    #[inline]
+    pub unsafe fn f32_to_i16(src: *const f32, dst: *mut i16) {
+        let (lo, hi) = to_i32(src, I16_SCALE);
+        vst1q_s16(dst, vcombine_s16(vqmovn_s32(lo), vqmovn_s32(hi)));
+    }
+
+    #[inline]
+    pub unsafe fn i32_to_f32(src: *const i32, dst: *mut f32, scale: f32) {
+        store_f32(vld1q_s32(src), vld1q_s32(src.add(4)), dst, scale);
+    }
+
+    #[inline]
+    pub unsafe fn f32_to_i24(src: *const f32, dst: *mut i32) {
+        let (lo, hi) = to_i32(src, I24_SCALE);
+        let min = vdupq_n_s32(-8_388_608);
+        let max = vdupq_n_s32(8_388_607);
+        vst1q_s32(dst, vminq_s32(vmaxq_s32(lo, min), max));
+        vst1q_s32(dst.add(4), vminq_s32(vmaxq_s32(hi, min), max));
+    }
+
+    #[inline]
+    pub unsafe fn u8_to_f32(src: *const u8, dst: *mut f32) {
+        let x = vreinterpretq_s16_u16(vmovl_u8(vld1_u8(src)));
+        let x = vsubq_s16(x, vdupq_n_s16(128));
+        let lo = vmovl_s16(vget_low_s16(x));
+        let hi = vmovl_s16(vget_high_s16(x));
+        store_f32(lo, hi, dst, 1.0 / I8_SCALE);
+    }
+
+    #[inline]
+    pub unsafe fn f32_to_u8(src: *const f32, dst: *mut u8) {
+        let (lo, hi) = to_i32(src, I8_SCALE);
+        let x = vqmovn_s16(vcombine_s16(vqmovn_s32(lo), vqmovn_s32(hi)));
+        vst1_u8(dst, veor_u8(vreinterpret_u8_s8(x), vdup_n_u8(0x80)));
+    }
+}
@@ -0,0 +1,410 @@
+//! Slices of interleaved samples whose **SampleFormat** is only known at runtime.
+//!
+//! [**ErasedSlice**](./enum.ErasedSlice.html) borrows a slice of any sample type provided by
+//! `dasp_sample`, while [**ErasedBoxedSlice**](./enum.ErasedBoxedSlice.html) owns one. Both may
+//! be downcast to a slice of their concrete sample type, or converted to a slice of any other
+//! sample or frame type.
+//!
+//! To build a **Signal** of some frame type from an **ErasedSlice**, pass its
+//! [**samples**](./enum.ErasedSlice.html#method.samples) to
+//! `dasp_signal::from_interleaved_samples_iter`.
+
+use crate::ToSampleSliceMut;
+use dasp_sample::format::{FromAnyFormat, KnownFormat};
+use dasp_sample::types::{ALaw, MuLaw, I24, I48, Q15, Q31, U24, U48};
+use dasp_sample::SampleFormat;
+
+#[cfg(feature = "boxed")]
+use crate::boxed::Box;
+
+/// Sample types that may be stored within an **ErasedSlice** or **ErasedBoxedSlice**.
+///
+/// This trait is implemented for every sample type provided by `dasp_sample`.
+pub trait ErasedSample: KnownFormat {
+    /// Erase the sample type of the given slice.
+    fn erase_slice(slice: &[Self]) -> ErasedSlice<'_>;
+
+    /// Recover the slice if it is of this sample type.
+    fn downcast_slice(slice: ErasedSlice<'_>) -> Option<&[Self]>;
+
+    /// Erase the sample type of the given boxed slice.
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_slice`, this item requires the **boxed** feature to be enabled.
+    /// - When using `dasp`, this item requires the **slice-boxed** feature to be enabled.
+    #[cfg(feature = "boxed")]
+    fn erase_boxed_slice(slice: Box<[Self]>) -> ErasedBoxedSlice;
+
+    /// Recover the boxed slice if it is of this sample type, otherwise return it unchanged.
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_slice`, this item requires the **boxed** feature to be enabled.
+    /// - When using `dasp`, this item requires the **slice-boxed** feature to be enabled.
+    #[cfg(feature = "boxed")]
+    fn downcast_boxed_slice(slice: ErasedBoxedSlice) -> Result<Box<[Self]>, ErasedBoxedSlice>;
+}
+
+/// An iterator that converts each sample of an **ErasedSlice** to the sample type `S`.
+#[derive(Clone)]
+pub struct Samples<'a, S> {
+    slice: ErasedSlice<'a>,
+    index: usize,
+    sample: core::marker::PhantomData<S>,
+}
+
+// Converts a sample of any format to a **FromAnyFormat** sample type.
+trait ToAnyFormat: Copy {
+    fn to_any_format<S>(self) -> S
+    where
+        S: FromAnyFormat;
+}
+
+macro_rules! impl_to_any_format {
+    (@via_f32 $($T:ty),*) => {
+        $(
+            impl ToAnyFormat for $T {
+                #[inline]
+                fn to_any_format<S>(self) -> S
+                where
+                    S: FromAnyFormat,
+                {
+                    S::from_sample_(<f32 as dasp_sample::FromSample<$T>>::from_sample_(self))
+                }
+            }
+        )*
+    };
+    ($($T:ty),*) => {
+        $(
+            impl ToAnyFormat for $T {
+                #[inline]
+                fn to_any_format<S>(self) -> S
+                where
+                    S: FromAnyFormat,
+                {
+                    S::from_sample_(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_to_any_format!(
+    i8, i16, I24, i32, I48, i64, u8, u16, U24, u32, U48, u64, f32, f64, MuLaw, ALaw, Q15, Q31
+);
+
+// `FromAnyFormat` does not depend on the `half` feature, so the half precision formats are
+// converted via `f32`, which represents them exactly.
+#[cfg(feature = "half")]
+impl_to_any_format!(@via_f32 dasp_sample::f16, dasp_sample::bf16);
+
+macro_rules! erased_slices {
+    ($(
+        $(#[cfg($cfg:meta)])*
+        $Variant:ident: $T:ty;
+    )*) => {
+        /// A borrowed slice of interleaved samples of any sample type provided by `dasp_sample`.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use dasp_sample::SampleFormat;
+        /// use dasp_slice::erased::ErasedSlice;
+        ///
+        /// fn main() {
+        ///     let samples = [0i16, 16_384, -16_384, 32_767];
+        ///     let erased = ErasedSlice::from(&samples[..]);
+        ///     assert_eq!(erased.format(), SampleFormat::I16);
+        ///     assert_eq!(erased.downcast::<i16>(), Some(&samples[..]));
+        ///     assert_eq!(erased.downcast::<f32>(), None);
+        ///
+        ///     let mut frames = [[0.0f32; 2]; 2];
+        ///     erased.write_to(&mut frames[..]);
+        ///     assert_eq!(frames, [[0.0, 0.5], [-0.5, 32_767.0 / 32_768.0]]);
+        /// }
+        /// ```
+        #[derive(Copy, Clone, Debug, PartialEq)]
+        pub enum ErasedSlice<'a> {
+            $(
+                $(#[cfg($cfg)])*
+                $Variant(&'a [$T]),
+            )*
+        }
+
+        /// An owned, boxed slice of interleaved samples of any sample type provided by
+        /// `dasp_sample`.
+        ///
+        /// ### Required Features
+        ///
+        /// - When using `dasp_slice`, this item requires the **boxed** feature to be enabled.
+        /// - When using `dasp`, this item requires the **slice-boxed** feature to be enabled.
+        #[cfg(feature = "boxed")]
+        #[derive(Clone, Debug, PartialEq)]
+        pub enum ErasedBoxedSlice {
+            $(
+                $(#[cfg($cfg)])*
+                $Variant(Box<[$T]>),
+            )*
+        }
+
+        impl<'a> ErasedSlice<'a> {
+            /// The format of the samples within the slice.
+            pub fn format(&self) -> SampleFormat {
+                match *self {
+                    $(
+                        $(#[cfg($cfg)])*
+                        ErasedSlice::$Variant(_) => SampleFormat::$Variant,
+                    )*
+                }
+            }
+
+            /// The number of samples within the slice.
+            pub fn len(&self) -> usize {
+                match *self {
+                    $(
+                        $(#[cfg($cfg)])*
+                        ErasedSlice::$Variant(slice) => slice.len(),
+                    )*
+                }
+            }
+
+            // Convert the sample at the given index. Returns `None` if out of bounds.
+            #[inline]
+            fn sample<S>(&self, index: usize) -> Option<S>
+            where
+                S: FromAnyFormat,
+            {
+                match *self {
+                    $(
+                        $(#[cfg($cfg)])*
+                        ErasedSlice::$Variant(slice) => slice.get(index).map(|&s| s.to_any_format()),
+                    )*
+                }
+            }
+        }
+
+        #[cfg(feature = "boxed")]
+        impl ErasedBoxedSlice {
+            /// Borrow the boxed slice as an **ErasedSlice**.
+            pub fn as_erased_slice(&self) -> ErasedSlice<'_> {
+                match *self {
+                    $(
+                        $(#[cfg($cfg)])*
+                        ErasedBoxedSlice::$Variant(ref slice) => ErasedSlice::$Variant(slice),
+                    )*
+                }
+            }
+
+            /// Copy the samples of the given **ErasedSlice** to a new **ErasedBoxedSlice** of the
+            /// same format.
+            pub fn from_erased_slice(slice: ErasedSlice) -> Self {
+                match slice {
+                    $(
+                        $(#[cfg($cfg)])*
+                        ErasedSlice::$Variant(slice) => ErasedBoxedSlice::$Variant(slice.into()),
+                    )*
+                }
+            }
+        }
+
+        $(
+            $(#[cfg($cfg)])*
+            impl ErasedSample for $T {
+                fn erase_slice(slice: &[Self]) -> ErasedSlice<'_> {
+                    ErasedSlice::$Variant(slice)
+                }
+
+                fn downcast_slice(slice: ErasedSlice<'_>) -> Option<&[Self]> {
+                    match slice {
+                        ErasedSlice::$Variant(slice) => Some(slice),
+                        _ => None,
+                    }
+                }
+
+                #[cfg(feature = "boxed")]
+                fn erase_boxed_slice(slice: Box<[Self]>) -> ErasedBoxedSlice {
+                    ErasedBoxedSlice::$Variant(slice)
+                }
+
+                #[cfg(feature = "boxed")]
+                fn downcast_boxed_slice(
+                    slice: ErasedBoxedSlice,
+                ) -> Result<Box<[Self]>, ErasedBoxedSlice> {
+                    match slice {
+                        ErasedBoxedSlice::$Variant(slice) => Ok(slice),
+                        slice => Err(slice),
+                    }
+                }
+            }
+
+            $(#[cfg($cfg)])*
+            impl<'a> From<&'a [$T]> for ErasedSlice<'a> {
+                fn from(slice: &'a [$T]) -> Self {
+                    ErasedSlice::$Variant(slice)
+                }
+            }
+
+            $(#[cfg($cfg)])*
+            #[cfg(feature = "boxed")]
+            impl From<Box<[$T]>> for ErasedBoxedSlice {
+                fn from(slice: Box<[$T]>) -> Self {
+                    ErasedBoxedSlice::$Variant(slice)
+                }
+            }
+        )*
+    };
+}
+
+erased_slices! {
+    I8: i8;
+    I16: i16;
+    I24: I24;
+    I32: i32;
+    I48: I48;
+    I64: i64;
+    U8: u8;
+    U16: u16;
+    U24: U24;
+    U32: u32;
+    U48: U48;
+    U64: u64;
+    F32: f32;
+    F64: f64;
+    MuLaw: MuLaw;
+    ALaw: ALaw;
+    Q15: Q15;
+    Q31: Q31;
+    #[cfg(feature = "half")]
+    F16: dasp_sample::f16;
+    #[cfg(feature = "half")]
+    BF16: dasp_sample::bf16;
+}
+
+impl<'a> ErasedSlice<'a> {
+    /// Erase the sample type of the given slice.
+    pub fn new<S>(slice: &'a [S]) -> Self
+    where
+        S: ErasedSample,
+    {
+        S::erase_slice(slice)
+    }
+
+    /// Whether or not the slice contains no samples.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Recover the slice if its samples are of type `S`.
+    pub fn downcast<S>(self) -> Option<&'a [S]>
+    where
+        S: ErasedSample,
+    {
+        S::downcast_slice(self)
+    }
+
+    /// An iterator yielding each sample of the slice converted to the sample type `S`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dasp_slice::erased::ErasedSlice;
+    ///
+    /// fn main() {
+    ///     let samples = [0u8, 64, 255];
+    ///     let erased = ErasedSlice::from(&samples[..]);
+    ///     let converted: Vec<i16> = erased.samples().collect();
+    ///     assert_eq!(converted, vec![-32_768, -16_384, 32_512]);
+    /// }
+    /// ```
+    pub fn samples<S>(self) -> Samples<'a, S>
+    where
+        S: FromAnyFormat,
+    {
+        Samples {
+            slice: self,
+            index: 0,
+            sample: core::marker::PhantomData,
+        }
+    }
+
+    /// Convert each sample of the slice to the sample type of `dst`, writing the result to `dst`.
+    ///
+    /// `dst` may be a slice of samples or a slice of frames with interleaved channels.
+    ///
+    /// **Panics** if the number of samples in `dst` is not equal to the length of the slice.
+    pub fn write_to<'b, T, S>(self, dst: T)
+    where
+        T: ToSampleSliceMut<'b, S>,
+        S: FromAnyFormat + 'b,
+    {
+        let dst = dst.to_sample_slice_mut();
+        assert_eq!(dst.len(), self.len());
+        for (d, s) in dst.iter_mut().zip(self.samples()) {
+            *d = s;
+        }
+    }
+
+    /// Convert each sample of the slice to the sample type `S`, collecting the result into a new
+    /// boxed slice.
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_slice`, this item requires the **boxed** feature to be enabled.
+    /// - When using `dasp`, this item requires the **slice-boxed** feature to be enabled.
+    #[cfg(feature = "boxed")]
+    pub fn to_boxed_samples<S>(self) -> Box<[S]>
+    where
+        S: FromAnyFormat,
+    {
+        self.samples().collect()
+    }
+}
+
+#[cfg(feature = "boxed")]
+impl ErasedBoxedSlice {
+    /// The format of the samples within the slice.
+    pub fn format(&self) -> SampleFormat {
+        self.as_erased_slice().format()
+    }
+
+    /// The number of samples within the slice.
+    pub fn len(&self) -> usize {
+        self.as_erased_slice().len()
+    }
+
+    /// Whether or not the slice contains no samples.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Recover the boxed slice if its samples are of type `S`, otherwise return it unchanged.
+    pub fn downcast<S>(self) -> Result<Box<[S]>, Self>
+    where
+        S: ErasedSample,
+    {
+        S::downcast_boxed_slice(self)
+    }
+}
+
+impl<'a, S> Iterator for Samples<'a, S>
+where
+    S: FromAnyFormat,
+{
+    type Item = S;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let sample = self.slice.sample(self.index)?;
+        self.index += 1;
+        Some(sample)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.slice.len() - self.index;
+        (len, Some(len))
+    }
+}
+
+impl<'a, S> ExactSizeIterator for Samples<'a, S> where S: FromAnyFormat {}
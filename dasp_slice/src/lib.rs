@@ -3,10 +3,23 @@
 //! Items related to conversion between slices of frames and slices of samples, particularly useful
 //! for working with interleaved data.
 //!
+//! The [**planar**](./planar/index.html) module provides planar buffers, where each channel is
+//! stored within its own slice, along with interleaving and deinterleaving between planar buffers
+//! and slices of frames.
+//!
 //! ### Optional Features
 //!
+//! - The **analysis** feature (or **slice-analysis** feature if using `dasp`) provides peak, RMS,
+//!   DC offset, crest factor and clip count analysis of whole slices under the
+//!   [**analysis**](./analysis/index.html) module.
 //! - The **boxed** feature (or **slice-boxed** feature if using `dasp`) provides a suite of boxed
-//!   slice conversion traits and functions under the [**boxed**](./boxed/index.html) module.
+//!   slice conversion traits and functions under the [**boxed**](./boxed/index.html) module, as
+//!   well as the owned [**ErasedBoxedSlice**](./erased/enum.ErasedBoxedSlice.html),
+//!   [**PlanarBuffer**](./planar/struct.PlanarBuffer.html) and
+//!   [**AudioBuffer**](./buffer/struct.AudioBuffer.html).
+//! - The **half** feature (or **sample-half** feature if using `dasp`) allows for storing the half
+//!   precision `f16` and `bf16` sample types within an
+//!   [**ErasedSlice**](./erased/enum.ErasedSlice.html).
 //!
 //! ### no_std
 //!
@@ -16,7 +29,10 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use dasp_frame::Frame;
-use dasp_sample::Sample;
+use dasp_sample::conv::NominalRange;
+use dasp_sample::fade::{Curve, Ramp};
+use dasp_sample::sanitize::{Counts, Fault, Report, Sanitizer};
+use dasp_sample::{bytes, FloatSample, Gain, IntoAmp, Sample, ToSample};
 
 #[cfg(feature = "boxed")]
 pub use boxed::{
@@ -25,13 +41,24 @@ pub use boxed::{
     FromBoxedSampleSlice, ToBoxedFrameSlice, ToBoxedSampleSlice,
 };
 
+#[cfg(feature = "boxed")]
+pub use buffer::AudioBuffer;
+
 pub use frame::{
     from_frame_slice, from_frame_slice_mut, to_frame_slice, to_frame_slice_mut, DuplexFrameSlice,
     DuplexFrameSliceMut, FromFrameSlice, FromFrameSliceMut, ToFrameSlice, ToFrameSliceMut,
 };
 
+#[cfg(feature = "analysis")]
+pub mod analysis;
 #[cfg(feature = "boxed")]
 pub mod boxed;
+#[cfg(feature = "boxed")]
+pub mod buffer;
+pub mod erased;
+pub mod planar;
+
+mod convert;
 
 mod frame;
 
@@ -322,6 +349,137 @@ where
     zip_map_in_place(a, b, |_, b| b);
 }
 
+/// Converts every frame in slice `b` to the frame type of slice `a`, first clamping each sample to
+/// its nominal range, and writes the result to `a`.
+///
+/// See `Sample::to_sample_saturating` for details.
+///
+/// **Panics** if the slice lengths differ.
+///
+/// # Example
+///
+/// ```
+/// fn main() {
+///     let mut a = [[0i16; 2]; 2];
+///     dasp_slice::write_saturating(&mut a, &[[1.3, -0.5], [0.5, -1.3]]);
+///     assert_eq!(a, [[32_767, -16_384], [16_384, -32_768]]);
+/// }
+/// ```
+#[inline]
+pub fn write_saturating<FA, FB>(a: &mut [FA], b: &[FB])
+where
+    FA: Frame,
+    FB: Frame<NumChannels = FA::NumChannels>,
+    FB::Sample: ToSample<FA::Sample> + NominalRange,
+{
+    zip_map_in_place(a, b, |_, b| b.to_frame_saturating());
+}
+
+/// Converts every frame in slice `b` to the frame type of slice `a`, first wrapping each sample
+/// around its nominal range, and writes the result to `a`.
+///
+/// See `Sample::to_sample_wrapping` for details.
+///
+/// **Panics** if the slice lengths differ.
+#[inline]
+pub fn write_wrapping<FA, FB>(a: &mut [FA], b: &[FB])
+where
+    FA: Frame,
+    FB: Frame<NumChannels = FA::NumChannels>,
+    FB::Sample: ToSample<FA::Sample> + NominalRange,
+{
+    zip_map_in_place(a, b, |_, b| b.to_frame_wrapping());
+}
+
+/// Converts every frame in slice `b` to the frame type of slice `a` and writes the result to `a`
+/// if all samples in `b` lie within their nominal range.
+///
+/// Otherwise, returns the index of the first frame in `b` containing an out-of-range sample and
+/// leaves `a` unmodified. See `Sample::to_sample_checked` for details.
+///
+/// **Panics** if the slice lengths differ.
+///
+/// # Example
+///
+/// ```
+/// fn main() {
+///     let mut a = [[0i16; 2]; 2];
+///     assert_eq!(dasp_slice::write_checked(&mut a, &[[0.5, -0.5], [1.3, 0.0]]), Err(1));
+///     assert_eq!(a, [[0, 0], [0, 0]]);
+///     assert_eq!(dasp_slice::write_checked(&mut a, &[[0.5, -0.5], [1.0, 0.0]]), Ok(()));
+///     assert_eq!(a, [[16_384, -16_384], [32_767, 0]]);
+/// }
+/// ```
+#[inline]
+pub fn write_checked<FA, FB>(a: &mut [FA], b: &[FB]) -> Result<(), usize>
+where
+    FA: Frame,
+    FB: Frame<NumChannels = FA::NumChannels>,
+    FB::Sample: ToSample<FA::Sample> + NominalRange,
+{
+    assert_eq!(a.len(), b.len());
+    if let Some(idx) = b
+        .iter()
+        .position(|f| !f.channels().all(NominalRange::is_in_range))
+    {
+        return Err(idx);
+    }
+    zip_map_in_place(a, b, |_, b| b.map(Sample::to_sample));
+    Ok(())
+}
+
+/// Converts every frame in slice `b` to the frame type of slice `a` and writes the result to `a`.
+///
+/// **Panics** in debug builds if any sample in `b` lies outside of its nominal range. See
+/// `Sample::to_sample_debug_checked` for details.
+///
+/// **Panics** if the slice lengths differ.
+#[inline]
+pub fn write_debug_checked<FA, FB>(a: &mut [FA], b: &[FB])
+where
+    FA: Frame,
+    FB: Frame<NumChannels = FA::NumChannels>,
+    FB::Sample: ToSample<FA::Sample> + NominalRange,
+{
+    zip_map_in_place(a, b, |_, b| b.to_frame_debug_checked());
+}
+
+/// Converts every sample in slice `b` to the sample type of slice `a`, writing the results to `a`.
+///
+/// Either slice may be a slice of samples or a slice of frames with interleaved channels. Each
+/// sample is converted exactly as it would be by `Sample::to_sample`, however conversions between
+/// `f32` and each of `i16`, `I24` and `u8` are vectorised on x86_64 and aarch64.
+///
+/// **Panics** if the number of samples in `a` is not equal to the number of samples in `b`.
+///
+/// # Example
+///
+/// ```
+/// fn main() {
+///     let interleaved = [0i16, 16_384, -16_384, -32_768];
+///     let mut frames = [[0.0f32; 2]; 2];
+///     dasp_slice::convert(&mut frames[..], &interleaved[..]);
+///     assert_eq!(frames, [[0.0, 0.5], [-0.5, -1.0]]);
+///
+///     let mut samples = [0i16; 4];
+///     dasp_slice::convert(&mut samples[..], &frames[..]);
+///     assert_eq!(samples, interleaved);
+/// }
+/// ```
+#[inline]
+pub fn convert<'a, 'b, A, B, S, T>(a: A, b: B)
+where
+    A: ToSampleSliceMut<'a, T>,
+    B: ToSampleSlice<'b, S>,
+    S: Sample + ToSample<T> + 'static,
+    T: Sample + 'static,
+{
+    let a = a.to_sample_slice_mut();
+    let b = b.to_sample_slice();
+    assert_eq!(a.len(), b.len());
+    convert::samples(b, a);
+}
+
 /// Adds every sample in slice `b` to every sample in slice `a` respectively.
 #[inline]
 pub fn add_in_place<FA, FB>(a: &mut [FA], b: &[FB])
@@ -332,6 +490,45 @@ where
     zip_map_in_place(a, b, |a, b| a.add_amp(b));
 }
 
+/// Scale the amplitude of every sample in slice `a` by `amp`.
+///
+/// The amplitude may be given as a raw **FloatSample**, a linear `Gain` or in `Decibels`.
+///
+/// # Example
+///
+/// ```
+/// use dasp_sample::Decibels;
+///
+/// fn main() {
+///     let mut frames = [[1_000i16, -1_000], [2_000, -2_000]];
+///     dasp_slice::scale_amp_in_place(&mut frames[..], Decibels(-20.0));
+///     assert_eq!(frames, [[100, -100], [200, -200]]);
+/// }
+/// ```
+#[inline]
+pub fn scale_amp_in_place<F, A>(a: &mut [F], amp: A)
+where
+    F: Frame,
+    A: IntoAmp<<F::Sample as Sample>::Float>,
+{
+    let amp = amp.into_amp();
+    map_in_place(a, |f| f.scale_amp(amp));
+}
+
+/// Scale the amplitude of each frame in `b` by `amp` before summing it onto `a`.
+///
+/// The amplitude may be given as a raw **FloatSample**, a linear `Gain` or in `Decibels`.
+#[inline]
+pub fn add_in_place_with_amp<FA, FB, A>(a: &mut [FA], b: &[FB], amp: A)
+where
+    FA: Frame,
+    FB: Frame<Sample = <FA::Sample as Sample>::Signed, NumChannels = FA::NumChannels>,
+    A: IntoAmp<<FB::Sample as Sample>::Float>,
+{
+    let amp = amp.into_amp();
+    zip_map_in_place(a, b, |af, bf| af.add_amp(bf.scale_amp(amp)));
+}
+
 /// Scale the amplitude of each frame in `b` by `amp_per_channel` before summing it onto `a`.
 #[inline]
 pub fn add_in_place_with_amp_per_channel<FA, FB, A>(a: &mut [FA], b: &[FB], amp_per_channel: A)
@@ -343,6 +540,266 @@ where
     zip_map_in_place(a, b, |af, bf| af.add_amp(bf.mul_amp(amp_per_channel)));
 }
 
+/// Fade the slice in from silence to unity gain following the given curve.
+///
+/// The first frame is silenced and the last frame is left unchanged.
+///
+/// # Example
+///
+/// ```
+/// use dasp_sample::fade::Curve;
+///
+/// fn main() {
+///     let mut frames = [[1.0, -1.0]; 5];
+///     dasp_slice::fade_in_in_place(&mut frames[..], Curve::Linear);
+///     assert_eq!(frames, [[0.0, 0.0], [0.25, -0.25], [0.5, -0.5], [0.75, -0.75], [1.0, -1.0]]);
+/// }
+/// ```
+#[inline]
+pub fn fade_in_in_place<F>(a: &mut [F], curve: Curve)
+where
+    F: Frame,
+{
+    ramp_in_place(a, Ramp::fade_in(curve, a.len()));
+}
+
+/// Fade the slice out from unity gain to silence following the given curve.
+///
+/// The first frame is left unchanged and the last frame is silenced. The fade is the time
+/// reversal of the equivalent fade-in.
+#[inline]
+pub fn fade_out_in_place<F>(a: &mut [F], curve: Curve)
+where
+    F: Frame,
+{
+    ramp_in_place(a, Ramp::fade_out(curve, a.len()));
+}
+
+/// Scale the amplitude of the slice by a gain that moves from `from` to `to` following the given
+/// curve, where the first frame is scaled by `from` and the last frame by `to`.
+///
+/// Either gain may be given as a raw `f64`, a linear `Gain` or in `Decibels`.
+///
+/// # Example
+///
+/// ```
+/// use dasp_sample::fade::Curve;
+/// use dasp_sample::Decibels;
+///
+/// fn main() {
+///     let mut frames = [[1_000i16]; 3];
+///     dasp_slice::gain_ramp_in_place(&mut frames[..], Curve::Linear, 1.0, Decibels(-20.0));
+///     assert_eq!(frames, [[1_000], [550], [100]]);
+/// }
+/// ```
+#[inline]
+pub fn gain_ramp_in_place<F, A, B>(a: &mut [F], curve: Curve, from: A, to: B)
+where
+    F: Frame,
+    A: IntoAmp<f64>,
+    B: IntoAmp<f64>,
+{
+    ramp_in_place(a, Ramp::new(curve, from, to, a.len()));
+}
+
+/// Crossfade from slice `a` to slice `b` following the given curve, writing the mix to `a`.
+///
+/// `a` is faded out and `b` faded in over the length of the slices, so that the first frame is
+/// that of `a` and the last frame is that of `b`.
+///
+/// **Panics** if the length of `b` is not equal to the length of `a`.
+///
+/// # Example
+///
+/// ```
+/// use dasp_sample::fade::Curve;
+///
+/// fn main() {
+///     let mut a = [[1.0]; 3];
+///     let b = [[-1.0]; 3];
+///     dasp_slice::crossfade_in_place(&mut a[..], &b[..], Curve::SCurve);
+///     assert_eq!(a, [[1.0], [0.0], [-1.0]]);
+/// }
+/// ```
+#[inline]
+pub fn crossfade_in_place<FA, FB>(a: &mut [FA], b: &[FB], curve: Curve)
+where
+    FA: Frame,
+    FB: Frame<Sample = <FA::Sample as Sample>::Signed, NumChannels = FA::NumChannels>,
+{
+    let fade_out = Ramp::fade_out(curve, a.len());
+    let fade_in = Ramp::fade_in(curve, a.len());
+    let mut idx = 0;
+    zip_map_in_place(a, b, |af, bf| {
+        let af = af.scale_amp(Gain(fade_out.gain_at(idx)));
+        let bf = bf.scale_amp(Gain(fade_in.gain_at(idx)));
+        idx += 1;
+        af.add_amp(bf)
+    });
+}
+
+// Scale the amplitude of each frame by the gain of the ramp at its index.
+#[inline]
+fn ramp_in_place<F>(a: &mut [F], ramp: Ramp)
+where
+    F: Frame,
+{
+    for (idx, f) in a.iter_mut().enumerate() {
+        *f = f.scale_amp(Gain(ramp.gain_at(idx)));
+    }
+}
+
+/// Count the NaN, infinite and subnormal samples within the slice.
+///
+/// # Example
+///
+/// ```
+/// fn main() {
+///     let frames = [[0.5, f32::NAN], [f32::INFINITY, 1e-40]];
+///     let counts = dasp_slice::count_faults(&frames[..]);
+///     assert_eq!((counts.nan, counts.infinite, counts.subnormal), (1, 1, 1));
+/// }
+/// ```
+#[inline]
+pub fn count_faults<F>(a: &[F]) -> Counts
+where
+    F: Frame,
+    F::Sample: FloatSample,
+{
+    let mut counts = Counts::default();
+    for frame in a {
+        for &sample in frame.channels_ref() {
+            if let Some(fault) = Fault::of(sample) {
+                counts.record(fault);
+            }
+        }
+    }
+    counts
+}
+
+/// Replace the NaN, infinite and subnormal samples within the slice according to the policy of
+/// the given **Sanitizer**, returning the number of each kind of fault encountered.
+///
+/// # Example
+///
+/// ```
+/// use dasp_sample::sanitize::Sanitizer;
+///
+/// fn main() {
+///     let mut frames = [[0.5, f32::NAN], [f32::INFINITY, 1e-40]];
+///     let counts = dasp_slice::sanitize_in_place(&mut frames[..], Sanitizer::default());
+///     assert_eq!(counts.total(), 3);
+///     assert_eq!(frames, [[0.5, 0.0], [0.0, 0.0]]);
+/// }
+/// ```
+#[inline]
+pub fn sanitize_in_place<F>(a: &mut [F], sanitizer: Sanitizer) -> Counts
+where
+    F: Frame,
+    F::Sample: FloatSample,
+{
+    sanitize_in_place_with(a, sanitizer, |_| ())
+}
+
+/// The same as **sanitize_in_place**, but calls `report` with the position and kind of each fault
+/// encountered.
+///
+/// # Example
+///
+/// ```
+/// use dasp_sample::sanitize::{Fault, Report, Sanitizer};
+///
+/// fn main() {
+///     let mut frames = [[0.5, 0.25], [-0.5, f32::NAN]];
+///     let mut reports = vec![];
+///     dasp_slice::sanitize_in_place_with(&mut frames[..], Sanitizer::default(), |r| reports.push(r));
+///     assert_eq!(reports, vec![Report { frame: 1, channel: 1, fault: Fault::Nan }]);
+/// }
+/// ```
+#[inline]
+pub fn sanitize_in_place_with<F, R>(a: &mut [F], sanitizer: Sanitizer, mut report: R) -> Counts
+where
+    F: Frame,
+    F::Sample: FloatSample,
+    R: FnMut(Report),
+{
+    let mut counts = Counts::default();
+    for (i, frame) in a.iter_mut().enumerate() {
+        for (channel, sample) in frame.channels_mut().enumerate() {
+            let (sanitized, fault) = sanitizer.sanitize(*sample);
+            if let Some(fault) = fault {
+                *sample = sanitized;
+                counts.record(fault);
+                report(Report {
+                    frame: i as u64,
+                    channel,
+                    fault,
+                });
+            }
+        }
+    }
+    counts
+}
+
+/// Decode the interleaved samples of every frame in `frames` from the given slice of bytes.
+///
+/// This is a convenience function that wraps `dasp_sample::bytes::decode_slice`.
+///
+/// **Panics** if the length of `bytes` is not equal to the number of samples in `frames`
+/// multiplied by `B::SIZE`.
+///
+/// # Example
+///
+/// ```
+/// use dasp_sample::bytes::{LittleEndian, Packed};
+///
+/// fn main() {
+///     let bytes = [0x01, 0x00, 0xFF, 0xFF, 0x00, 0x80, 0xFF, 0x7F];
+///     let mut frames = [[0i16; 2]; 2];
+///     dasp_slice::decode_bytes::<Packed<i16, LittleEndian>, _>(&bytes, &mut frames[..]);
+///     assert_eq!(frames, [[1, -1], [-32_768, 32_767]]);
+/// }
+/// ```
+#[inline]
+pub fn decode_bytes<'a, B, T>(bytes: &[u8], frames: T)
+where
+    B: bytes::Format,
+    B::Sample: 'a,
+    T: ToSampleSliceMut<'a, B::Sample>,
+{
+    bytes::decode_slice::<B>(bytes, frames.to_sample_slice_mut());
+}
+
+/// Encode the interleaved samples of every frame in `frames` to the given slice of bytes.
+///
+/// This is a convenience function that wraps `dasp_sample::bytes::encode_slice`.
+///
+/// **Panics** if the length of `bytes` is not equal to the number of samples in `frames`
+/// multiplied by `B::SIZE`.
+///
+/// # Example
+///
+/// ```
+/// use dasp_sample::bytes::{BigEndian, Packed};
+/// use dasp_sample::I24;
+///
+/// fn main() {
+///     let frames = [[I24::new_unchecked(1), I24::new_unchecked(-2)]];
+///     let mut bytes = [0u8; 6];
+///     dasp_slice::encode_bytes::<Packed<I24, BigEndian>, _>(&frames[..], &mut bytes);
+///     assert_eq!(bytes, [0x00, 0x00, 0x01, 0xFF, 0xFF, 0xFE]);
+/// }
+/// ```
+#[inline]
+pub fn encode_bytes<'a, B, T>(frames: T, bytes: &mut [u8])
+where
+    B: bytes::Format,
+    B::Sample: 'a,
+    T: ToSampleSlice<'a, B::Sample>,
+{
+    bytes::encode_slice::<B>(frames.to_sample_slice(), bytes);
+}
+
 /// Mutate every element in slice `a` while reading from each element from slice `b` in lock-step
 /// using the given function.
 ///
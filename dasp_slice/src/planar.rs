@@ -0,0 +1,612 @@
+//! Planar (non-interleaved) buffers, where the samples of each channel are stored in their own
+//! slice.
+//!
+//! [**Planar**](./struct.Planar.html) and [**PlanarMut**](./struct.PlanarMut.html) borrow either a
+//! list of channel slices (e.g. `&[&[S]]`) or a single slice holding each channel one after the
+//! other, while [**PlanarBuffer**](./struct.PlanarBuffer.html) owns its channels.
+//!
+//! Frames may be read from and written to a planar buffer directly without first interleaving it.
+//! To convert a whole buffer at once, [**interleave**](./fn.interleave.html) and
+//! [**deinterleave**](./fn.deinterleave.html) copy between a planar buffer and a slice of frames,
+//! converting the sample type in the same pass.
+
+use dasp_frame::{FixedChannels, Frame};
+use dasp_sample::{Sample, ToSample};
+
+#[cfg(feature = "boxed")]
+use crate::boxed::Box;
+
+/// A borrowed planar buffer, where the samples of each channel are stored in their own slice.
+///
+/// Every channel has the same length, i.e. the number of frames within the buffer.
+///
+/// # Example
+///
+/// ```
+/// use dasp_slice::planar::Planar;
+///
+/// fn main() {
+///     let left = [0.0, 0.25, 0.5];
+///     let right = [1.0, 0.75, 0.5];
+///     let channels = [&left[..], &right[..]];
+///     let planar = Planar::new(&channels).unwrap();
+///     assert_eq!(planar.num_channels(), 2);
+///     assert_eq!(planar.len(), 3);
+///     assert_eq!(planar.frame(1), Some([0.25, 0.75]));
+///
+///     let frames: Vec<[f64; 2]> = planar.frames().collect();
+///     assert_eq!(frames, vec![[0.0, 1.0], [0.25, 0.75], [0.5, 0.5]]);
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Planar<'a, S> {
+    channels: Channels<'a, S>,
+    num_channels: usize,
+    frames: usize,
+}
+
+/// A mutably borrowed planar buffer, where the samples of each channel are stored in their own
+/// slice.
+///
+/// Every channel has the same length, i.e. the number of frames within the buffer.
+#[derive(Debug)]
+pub struct PlanarMut<'a, 'b, S> {
+    channels: ChannelsMut<'a, 'b, S>,
+    num_channels: usize,
+    frames: usize,
+}
+
+/// An owned planar buffer, storing each channel one after the other within a single boxed slice.
+///
+/// ### Required Features
+///
+/// - When using `dasp_slice`, this item requires the **boxed** feature to be enabled.
+/// - When using `dasp`, this item requires the **slice-boxed** feature to be enabled.
+#[cfg(feature = "boxed")]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PlanarBuffer<S> {
+    data: Box<[S]>,
+    channels: usize,
+    frames: usize,
+}
+
+/// An iterator yielding each frame of a **Planar** buffer.
+#[derive(Clone)]
+pub struct Frames<'a, S, F> {
+    planar: Planar<'a, S>,
+    next_idx: usize,
+    frame: core::marker::PhantomData<F>,
+}
+
+// The storage of a borrowed planar buffer.
+#[derive(Debug)]
+enum Channels<'a, S> {
+    List(&'a [&'a [S]]),
+    Contiguous(&'a [S]),
+}
+
+// The storage of a mutably borrowed planar buffer.
+#[derive(Debug)]
+enum ChannelsMut<'a, 'b, S> {
+    List(&'a mut [&'b mut [S]]),
+    Contiguous(&'a mut [S]),
+}
+
+impl<'a, S> Planar<'a, S> {
+    /// A planar buffer over the given list of channels.
+    ///
+    /// Returns `None` if the channels differ in length.
+    pub fn new(channels: &'a [&'a [S]]) -> Option<Self> {
+        let frames = channels.first().map(|c| c.len()).unwrap_or(0);
+        if channels.iter().any(|c| c.len() != frames) {
+            return None;
+        }
+        Some(Planar {
+            num_channels: channels.len(),
+            channels: Channels::List(channels),
+            frames,
+        })
+    }
+
+    /// A planar buffer over a single slice holding `channels` channels one after the other.
+    ///
+    /// Returns `None` if the length of the slice is not a multiple of `channels`.
+    ///
+    /// ```
+    /// use dasp_slice::planar::Planar;
+    ///
+    /// fn main() {
+    ///     let samples = [0, 1, 2, 10, 11, 12];
+    ///     let planar = Planar::from_contiguous(&samples, 2).unwrap();
+    ///     assert_eq!(planar.channel(1), Some(&[10, 11, 12][..]));
+    ///     assert_eq!(planar.frame(2), Some([2, 12]));
+    ///     assert!(Planar::from_contiguous(&samples, 4).is_none());
+    /// }
+    /// ```
+    pub fn from_contiguous(samples: &'a [S], channels: usize) -> Option<Self> {
+        let frames = contiguous_frames(samples.len(), channels)?;
+        Some(Planar {
+            channels: Channels::Contiguous(samples),
+            num_channels: channels,
+            frames,
+        })
+    }
+
+    /// The number of channels within the buffer.
+    pub fn num_channels(&self) -> usize {
+        self.num_channels
+    }
+
+    /// The number of frames within the buffer, i.e. the length of each channel.
+    pub fn len(&self) -> usize {
+        self.frames
+    }
+
+    /// Whether or not the buffer contains no frames.
+    pub fn is_empty(&self) -> bool {
+        self.frames == 0
+    }
+
+    /// The samples of the channel at the given index.
+    pub fn channel(&self, idx: usize) -> Option<&'a [S]> {
+        match self.channels {
+            Channels::List(list) => list.get(idx).copied(),
+            Channels::Contiguous(samples) => {
+                if idx >= self.num_channels {
+                    return None;
+                }
+                let start = idx * self.frames;
+                Some(&samples[start..start + self.frames])
+            }
+        }
+    }
+
+    /// An iterator yielding the samples of each channel.
+    pub fn channels(&self) -> impl Iterator<Item = &'a [S]> {
+        let planar = *self;
+        (0..self.num_channels()).filter_map(move |idx| planar.channel(idx))
+    }
+
+    /// Read the frame at the given index, copying one sample from each channel.
+    ///
+    /// Returns `None` if `idx` is out of range or if `F::CHANNELS` is not equal to the number of
+    /// channels within the buffer.
+    pub fn frame<F>(&self, idx: usize) -> Option<F>
+    where
+        S: Copy,
+        F: Frame<Sample = S>,
+        F::NumChannels: FixedChannels,
+    {
+        if idx >= self.frames || F::CHANNELS != self.num_channels() {
+            return None;
+        }
+        Some(F::from_fn(|ch| match self.channels {
+            Channels::List(list) => list[ch][idx],
+            Channels::Contiguous(samples) => samples[ch * self.frames + idx],
+        }))
+    }
+
+    /// An iterator yielding each frame within the buffer.
+    ///
+    /// The iterator yields nothing if `F::CHANNELS` is not equal to the number of channels within
+    /// the buffer.
+    pub fn frames<F>(&self) -> Frames<'a, S, F> {
+        Frames {
+            planar: *self,
+            next_idx: 0,
+            frame: core::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, 'b, S> PlanarMut<'a, 'b, S> {
+    /// A mutable planar buffer over the given list of channels.
+    ///
+    /// Returns `None` if the channels differ in length.
+    ///
+    /// ```
+    /// use dasp_slice::planar::PlanarMut;
+    ///
+    /// fn main() {
+    ///     let mut left = [0.0; 2];
+    ///     let mut right = [0.0; 2];
+    ///     let mut channels = [&mut left[..], &mut right[..]];
+    ///     let mut planar = PlanarMut::new(&mut channels).unwrap();
+    ///     assert!(planar.set_frame(1, [0.5, -0.5]));
+    ///     assert_eq!(left, [0.0, 0.5]);
+    ///     assert_eq!(right, [0.0, -0.5]);
+    /// }
+    /// ```
+    pub fn new(channels: &'a mut [&'b mut [S]]) -> Option<Self> {
+        let frames = channels.first().map(|c| c.len()).unwrap_or(0);
+        if channels.iter().any(|c| c.len() != frames) {
+            return None;
+        }
+        Some(PlanarMut {
+            num_channels: channels.len(),
+            channels: ChannelsMut::List(channels),
+            frames,
+        })
+    }
+
+    /// A mutable planar buffer over a single slice holding `channels` channels one after the
+    /// other.
+    ///
+    /// Returns `None` if the length of the slice is not a multiple of `channels`.
+    pub fn from_contiguous(samples: &'a mut [S], channels: usize) -> Option<Self> {
+        let frames = contiguous_frames(samples.len(), channels)?;
+        Some(PlanarMut {
+            channels: ChannelsMut::Contiguous(samples),
+            num_channels: channels,
+            frames,
+        })
+    }
+
+    /// The number of channels within the buffer.
+    pub fn num_channels(&self) -> usize {
+        self.num_channels
+    }
+
+    /// The number of frames within the buffer, i.e. the length of each channel.
+    pub fn len(&self) -> usize {
+        self.frames
+    }
+
+    /// Whether or not the buffer contains no frames.
+    pub fn is_empty(&self) -> bool {
+        self.frames == 0
+    }
+
+    /// The samples of the channel at the given index.
+    pub fn channel(&self, idx: usize) -> Option<&[S]> {
+        match self.channels {
+            ChannelsMut::List(ref list) => list.get(idx).map(|c| &c[..]),
+            ChannelsMut::Contiguous(ref samples) => {
+                if idx >= self.num_channels {
+                    return None;
+                }
+                let start = idx * self.frames;
+                Some(&samples[start..start + self.frames])
+            }
+        }
+    }
+
+    /// Mutable access to the samples of the channel at the given index.
+    pub fn channel_mut(&mut self, idx: usize) -> Option<&mut [S]> {
+        match self.channels {
+            ChannelsMut::List(ref mut list) => list.get_mut(idx).map(|c| &mut c[..]),
+            ChannelsMut::Contiguous(ref mut samples) => {
+                if idx >= self.num_channels {
+                    return None;
+                }
+                let start = idx * self.frames;
+                Some(&mut samples[start..start + self.frames])
+            }
+        }
+    }
+
+    /// Read the frame at the given index, copying one sample from each channel.
+    ///
+    /// Returns `None` if `idx` is out of range or if `F::CHANNELS` is not equal to the number of
+    /// channels within the buffer.
+    pub fn frame<F>(&self, idx: usize) -> Option<F>
+    where
+        S: Copy,
+        F: Frame<Sample = S>,
+        F::NumChannels: FixedChannels,
+    {
+        if idx >= self.frames || F::CHANNELS != self.num_channels() {
+            return None;
+        }
+        Some(F::from_fn(|ch| self.channel(ch).unwrap()[idx]))
+    }
+
+    /// Write the given frame to the given index, copying one sample to each channel.
+    ///
+    /// Returns `false` and leaves the buffer unchanged if `idx` is out of range or if
+    /// `F::CHANNELS` is not equal to the number of channels within the buffer.
+    pub fn set_frame<F>(&mut self, idx: usize, frame: F) -> bool
+    where
+        F: Frame<Sample = S>,
+        F::NumChannels: FixedChannels,
+    {
+        if idx >= self.frames || F::CHANNELS != self.num_channels() {
+            return false;
+        }
+        for (ch, sample) in frame.channels().enumerate() {
+            self.channel_mut(ch).unwrap()[idx] = sample;
+        }
+        true
+    }
+}
+
+#[cfg(feature = "boxed")]
+impl<S> PlanarBuffer<S>
+where
+    S: Sample,
+{
+    /// A planar buffer with the given number of channels and frames, where every sample is at
+    /// equilibrium.
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_slice`, this item requires the **boxed** feature to be enabled.
+    /// - When using `dasp`, this item requires the **slice-boxed** feature to be enabled.
+    pub fn new(channels: usize, frames: usize) -> Self {
+        let data = (0..channels * frames).map(|_| S::EQUILIBRIUM).collect();
+        PlanarBuffer {
+            data,
+            channels,
+            frames,
+        }
+    }
+
+    /// A planar buffer holding the deinterleaved channels of the given frames, converting each
+    /// sample to `S`.
+    ///
+    /// ```
+    /// use dasp_slice::planar::PlanarBuffer;
+    ///
+    /// fn main() {
+    ///     let frames = [[0i16, 16_384], [-16_384, -32_768]];
+    ///     let buffer = PlanarBuffer::<f32>::from_frames(&frames);
+    ///     assert_eq!(buffer.channel(0), Some(&[0.0, -0.5][..]));
+    ///     assert_eq!(buffer.channel(1), Some(&[0.5, -1.0][..]));
+    ///
+    ///     let mut interleaved = [[0i16; 2]; 2];
+    ///     dasp_slice::planar::interleave(&buffer.as_planar(), &mut interleaved);
+    ///     assert_eq!(interleaved, frames);
+    /// }
+    /// ```
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_slice`, this item requires the **boxed** feature to be enabled.
+    /// - When using `dasp`, this item requires the **slice-boxed** feature to be enabled.
+    pub fn from_frames<F>(frames: &[F]) -> Self
+    where
+        F: Frame,
+        F::NumChannels: FixedChannels,
+        F::Sample: ToSample<S>,
+    {
+        let mut buffer = Self::new(F::CHANNELS, frames.len());
+        deinterleave(frames, &mut buffer.as_planar_mut());
+        buffer
+    }
+
+    /// The number of channels within the buffer.
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_slice`, this item requires the **boxed** feature to be enabled.
+    /// - When using `dasp`, this item requires the **slice-boxed** feature to be enabled.
+    pub fn num_channels(&self) -> usize {
+        self.channels
+    }
+
+    /// The number of frames within the buffer, i.e. the length of each channel.
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_slice`, this item requires the **boxed** feature to be enabled.
+    /// - When using `dasp`, this item requires the **slice-boxed** feature to be enabled.
+    pub fn len(&self) -> usize {
+        self.frames
+    }
+
+    /// Whether or not the buffer contains no frames.
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_slice`, this item requires the **boxed** feature to be enabled.
+    /// - When using `dasp`, this item requires the **slice-boxed** feature to be enabled.
+    pub fn is_empty(&self) -> bool {
+        self.frames == 0
+    }
+
+    /// The samples of the channel at the given index.
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_slice`, this item requires the **boxed** feature to be enabled.
+    /// - When using `dasp`, this item requires the **slice-boxed** feature to be enabled.
+    pub fn channel(&self, idx: usize) -> Option<&[S]> {
+        self.as_planar().channel(idx)
+    }
+
+    /// Mutable access to the samples of the channel at the given index.
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_slice`, this item requires the **boxed** feature to be enabled.
+    /// - When using `dasp`, this item requires the **slice-boxed** feature to be enabled.
+    pub fn channel_mut(&mut self, idx: usize) -> Option<&mut [S]> {
+        let PlanarBuffer {
+            ref mut data,
+            channels,
+            frames,
+        } = *self;
+        if idx >= channels {
+            return None;
+        }
+        let start = idx * frames;
+        Some(&mut data[start..start + frames])
+    }
+
+    /// Borrow the buffer as a **Planar** buffer.
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_slice`, this item requires the **boxed** feature to be enabled.
+    /// - When using `dasp`, this item requires the **slice-boxed** feature to be enabled.
+    pub fn as_planar(&self) -> Planar<'_, S> {
+        Planar {
+            channels: Channels::Contiguous(&self.data),
+            num_channels: self.channels,
+            frames: self.frames,
+        }
+    }
+
+    /// Mutably borrow the buffer as a **PlanarMut** buffer.
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_slice`, this item requires the **boxed** feature to be enabled.
+    /// - When using `dasp`, this item requires the **slice-boxed** feature to be enabled.
+    pub fn as_planar_mut(&mut self) -> PlanarMut<'_, '_, S> {
+        PlanarMut {
+            channels: ChannelsMut::Contiguous(&mut self.data),
+            num_channels: self.channels,
+            frames: self.frames,
+        }
+    }
+
+    /// Consumes the buffer and returns its samples, with each channel stored one after the other.
+    ///
+    /// ### Required Features
+    ///
+    /// - When using `dasp_slice`, this item requires the **boxed** feature to be enabled.
+    /// - When using `dasp`, this item requires the **slice-boxed** feature to be enabled.
+    pub fn into_boxed_slice(self) -> Box<[S]> {
+        self.data
+    }
+}
+
+impl<'a, S> Clone for Planar<'a, S> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, S> Copy for Planar<'a, S> {}
+
+impl<'a, S> Clone for Channels<'a, S> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, S> Copy for Channels<'a, S> {}
+
+impl<'a, S, F> Iterator for Frames<'a, S, F>
+where
+    S: Copy,
+    F: Frame<Sample = S>,
+    F::NumChannels: FixedChannels,
+{
+    type Item = F;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let frame = self.planar.frame(self.next_idx)?;
+        self.next_idx += 1;
+        Some(frame)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a, S, F> ExactSizeIterator for Frames<'a, S, F>
+where
+    S: Copy,
+    F: Frame<Sample = S>,
+    F::NumChannels: FixedChannels,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        if F::CHANNELS == self.planar.num_channels() {
+            self.planar.len() - self.next_idx
+        } else {
+            0
+        }
+    }
+}
+
+/// Interleave the channels of the planar buffer into the given slice of frames, converting each
+/// sample to the sample type of `F`.
+///
+/// **Panics** if `F::CHANNELS` is not equal to the number of channels within `planar` or if the
+/// length of `frames` is not equal to the length of `planar`.
+///
+/// # Example
+///
+/// ```
+/// use dasp_slice::planar::{self, Planar};
+///
+/// fn main() {
+///     let left = [0.0f32, 0.5];
+///     let right = [-0.5f32, -1.0];
+///     let channels = [&left[..], &right[..]];
+///     let mut frames = [[0i16; 2]; 2];
+///     planar::interleave(&Planar::new(&channels).unwrap(), &mut frames);
+///     assert_eq!(frames, [[0, -16_384], [16_384, -32_768]]);
+/// }
+/// ```
+pub fn interleave<S, F>(planar: &Planar<'_, S>, frames: &mut [F])
+where
+    S: Sample + ToSample<F::Sample>,
+    F: Frame,
+    F::NumChannels: FixedChannels,
+{
+    assert_eq!(planar.num_channels(), F::CHANNELS);
+    assert_eq!(planar.len(), frames.len());
+    for (ch, channel) in planar.channels().enumerate() {
+        for (frame, &sample) in frames.iter_mut().zip(channel) {
+            // `ch` is less than `F::CHANNELS`, as checked above.
+            unsafe { *frame.channel_unchecked_mut(ch) = sample.to_sample_() };
+        }
+    }
+}
+
+/// Deinterleave the given slice of frames into the channels of the planar buffer, converting each
+/// sample to `S`.
+///
+/// **Panics** if `F::CHANNELS` is not equal to the number of channels within `planar` or if the
+/// length of `frames` is not equal to the length of `planar`.
+///
+/// # Example
+///
+/// ```
+/// use dasp_slice::planar::{self, PlanarMut};
+///
+/// fn main() {
+///     let frames = [[0i16, -16_384], [16_384, -32_768]];
+///     let mut samples = [0.0f32; 4];
+///     planar::deinterleave(&frames, &mut PlanarMut::from_contiguous(&mut samples, 2).unwrap());
+///     assert_eq!(samples, [0.0, 0.5, -0.5, -1.0]);
+/// }
+/// ```
+pub fn deinterleave<F, S>(frames: &[F], planar: &mut PlanarMut<'_, '_, S>)
+where
+    F: Frame,
+    F::NumChannels: FixedChannels,
+    F::Sample: ToSample<S>,
+    S: Sample,
+{
+    assert_eq!(planar.num_channels(), F::CHANNELS);
+    assert_eq!(planar.len(), frames.len());
+    for ch in 0..F::CHANNELS {
+        let channel = planar.channel_mut(ch).unwrap();
+        for (sample, frame) in channel.iter_mut().zip(frames) {
+            // `ch` is less than `F::CHANNELS`.
+            *sample = unsafe { (*frame.channel_unchecked(ch)).to_sample_() };
+        }
+    }
+}
+
+// The number of frames within a contiguous planar buffer of `len` samples.
+fn contiguous_frames(len: usize, channels: usize) -> Option<usize> {
+    if channels == 0 {
+        return if len == 0 { Some(0) } else { None };
+    }
+    let frames = len / channels;
+    if frames * channels == len {
+        Some(frames)
+    } else {
+        None
+    }
+}
@@ -0,0 +1,85 @@
+#![cfg(feature = "analysis")]
+
+use dasp_peak::{FullWave, PositiveHalfWave};
+use dasp_ring_buffer as ring_buffer;
+use dasp_rms::Rms;
+use dasp_slice::analysis;
+
+fn frames() -> Vec<[f32; 2]> {
+    (0..64)
+        .map(|i| {
+            let t = i as f32 / 64.0;
+            [(t * 7.0).sin() * 0.8, (t * 3.0).cos() * 0.3 + 0.1]
+        })
+        .collect()
+}
+
+#[test]
+fn test_rms_matches_streaming() {
+    let frames = frames();
+    let mut rms = Rms::new(ring_buffer::Fixed::from(vec![[0.0f32; 2]; frames.len()]));
+    let mut streamed = [0.0; 2];
+    for &frame in &frames {
+        streamed = rms.next(frame);
+    }
+    assert_eq!(analysis::rms(&frames), streamed);
+    for channel in 0..2 {
+        let stats = analysis::analyze_channel(&frames, channel).unwrap();
+        assert_eq!(stats.rms, streamed[channel]);
+    }
+}
+
+#[test]
+fn test_peak_and_dc_offset() {
+    let frames = frames();
+    let peak = analysis::peak(&frames, FullWave);
+    let positive = analysis::peak(&frames, PositiveHalfWave);
+    let dc_offset = analysis::dc_offset(&frames);
+    for channel in 0..2 {
+        let stats = analysis::analyze_channel(&frames, channel).unwrap();
+        assert_eq!(stats.peak, peak[channel]);
+        assert_eq!(stats.dc_offset, dc_offset[channel]);
+        assert_eq!(stats.crest_factor, stats.peak / stats.rms);
+        assert!(positive[channel] <= peak[channel]);
+    }
+    assert!((dc_offset[1] - 0.1).abs() < 0.05);
+    assert_eq!(analysis::analyze_channel(&frames, 2), None);
+}
+
+#[test]
+fn test_analyze_summed() {
+    let frames = [[0.5f32, -1.0], [1.5, 0.0]];
+    let stats = analysis::analyze(&frames);
+    assert_eq!(stats.len, 4);
+    assert_eq!(stats.peak, 1.5);
+    assert_eq!(stats.clipped, 2);
+    assert_eq!(stats.dc_offset, 0.25);
+    // The `no_std` square root is only accurate to around 1e-6.
+    assert!((stats.rms - (3.5f32 / 4.0).sqrt()).abs() < 1e-5);
+
+    let left = analysis::analyze_channel(&frames, 0).unwrap();
+    assert_eq!(left.len, 2);
+    assert_eq!(left.clipped, 1);
+}
+
+#[test]
+fn test_analyze_integers() {
+    let frames = [[i16::MIN, i16::MAX], [0, 0]];
+    let stats = analysis::analyze(&frames);
+    assert_eq!(stats.peak, 1.0);
+    assert_eq!(stats.clipped, 2);
+
+    let frames = [[0u8], [255], [128]];
+    assert_eq!(analysis::analyze(&frames).clipped, 2);
+}
+
+#[test]
+fn test_analyze_empty() {
+    let frames: [[f32; 2]; 0] = [];
+    let stats = analysis::analyze(&frames);
+    assert_eq!(stats.len, 0);
+    assert_eq!(stats.rms, 0.0);
+    assert_eq!(stats.crest_factor, 0.0);
+    assert_eq!(analysis::rms(&frames), [0.0; 2]);
+    assert_eq!(analysis::dc_offset(&frames), [0.0; 2]);
+}
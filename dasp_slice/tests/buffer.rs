@@ -0,0 +1,57 @@
+#![cfg(feature = "boxed")]
+
+use dasp_frame::layout::{ChannelLayout, Standard};
+use dasp_slice::AudioBuffer;
+
+#[test]
+fn test_audio_buffer_time() {
+    let buffer = AudioBuffer::<[f32; 1]>::silence(100, 100.0);
+    assert_eq!(buffer.duration_secs(), 1.0);
+    assert_eq!(buffer.frame_at_secs(0.254), 25);
+    assert_eq!(buffer.frame_at_secs(0.256), 26);
+    assert_eq!(buffer.frame_at_secs(-1.0), 0);
+    assert_eq!(buffer.frame_at_secs(2.0), 100);
+    assert_eq!(buffer.slice_secs(0.5, 2.0).len(), 50);
+    assert!(buffer.slice_secs(0.5, 0.25).is_empty());
+}
+
+#[test]
+fn test_audio_buffer_resize_and_append() {
+    let stereo = ChannelLayout::from(Standard::Stereo);
+    let mut a = AudioBuffer::from_frames(vec![[0.5, -0.5]], 48_000.0).with_layout(stereo);
+    let mut b = AudioBuffer::new(48_000.0);
+    b.push([0.25, 0.25]);
+    b.extend(vec![[1.0, 1.0]]);
+    a.append(&b);
+    assert_eq!(&a[..], &[[0.5, -0.5], [0.25, 0.25], [1.0, 1.0]]);
+    assert_eq!(a.layout(), Some(stereo));
+
+    a.resize(5);
+    assert_eq!(a[4], [0.0, 0.0]);
+    a.resize(1);
+    assert_eq!(a.into_frames(), vec![[0.5, -0.5]]);
+}
+
+#[test]
+#[should_panic]
+fn test_audio_buffer_append_sample_rate_mismatch() {
+    let mut a = AudioBuffer::<[f32; 2]>::new(44_100.0);
+    let b = AudioBuffer::<[f32; 2]>::silence(1, 48_000.0);
+    a.append(&b);
+}
+
+#[test]
+#[should_panic]
+fn test_audio_buffer_layout_channel_mismatch() {
+    AudioBuffer::<[f32; 2]>::new(48_000.0).with_layout(Standard::Mono.into());
+}
+
+#[test]
+fn test_audio_buffer_convert() {
+    let buffer = AudioBuffer::from_frames(vec![[0u8, 255], [128, 64]], 8_000.0)
+        .with_layout(Standard::Stereo.into());
+    let converted = buffer.convert::<[f32; 2]>();
+    assert_eq!(&converted[..], &[[-1.0, 127.0 / 128.0], [0.0, -0.5]]);
+    assert_eq!(converted.sample_rate(), 8_000.0);
+    assert_eq!(converted.layout(), buffer.layout());
+}
@@ -0,0 +1,46 @@
+//! Checks that `dasp_slice::convert` agrees with the per-sample conversions for each sample type's
+//! minimum, maximum and centre, using the same table as the `dasp_sample` conversion tests.
+
+use dasp_sample::{Sample, ToSample};
+use std::fmt::Debug;
+
+/// Asserts that converting a slice of `pre_conv` with `dasp_slice::convert` yields the same result
+/// as `conv` for every sample, including those handled by vectorised conversions.
+pub fn assert_bulk_conv_eq<S, T>(conv: fn(S) -> T, pre_conv: S)
+where
+    S: Sample + ToSample<T> + 'static,
+    T: Sample + Debug + 'static,
+{
+    let src = [pre_conv; 19];
+    let mut dst = [T::EQUILIBRIUM; 19];
+    dasp_slice::convert(&mut dst[..], &src[..]);
+    assert_eq!(dst, [conv(pre_conv); 19]);
+}
+
+/// Expands to an `assert_eq` for each pre-conversion and post-conversion pair, along with the
+/// same conversion of a slice via `dasp_slice::convert`.
+///
+/// Literals that must be wrapped by a custom sample type are wrapped using $T/$U::new_unchecked.
+macro_rules! conv_cmp {
+    ($fn_name:ident, $pre_conv:expr, $post_conv:expr) => {
+        assert_eq!($fn_name($pre_conv), $post_conv);
+        crate::assert_bulk_conv_eq($fn_name, $pre_conv);
+    };
+    ($fn_name:ident: $U:ident, $pre_conv:expr, $post_conv:expr) => {
+        assert_eq!($fn_name($pre_conv), $U::new_unchecked($post_conv));
+        crate::assert_bulk_conv_eq($fn_name, $pre_conv);
+    };
+    ($T:ident; $fn_name:ident, $pre_conv:expr, $post_conv:expr) => {
+        assert_eq!($fn_name($T::new_unchecked($pre_conv)), $post_conv);
+        crate::assert_bulk_conv_eq($fn_name, $T::new_unchecked($pre_conv));
+    };
+    ($T:ident; $fn_name:ident: $U:ident, $pre_conv:expr, $post_conv:expr) => {
+        assert_eq!(
+            $fn_name($T::new_unchecked($pre_conv)),
+            $U::new_unchecked($post_conv)
+        );
+        crate::assert_bulk_conv_eq($fn_name, $T::new_unchecked($pre_conv));
+    };
+}
+
+include!("../../dasp_sample/tests/common/conv.rs");
@@ -0,0 +1,117 @@
+use dasp_slice::planar::{self, Planar, PlanarMut};
+
+#[test]
+fn test_planar_new() {
+    let a = [0.0, 1.0, 2.0];
+    let b = [0.0, 1.0];
+    assert!(Planar::new(&[&a[..], &b[..]]).is_none());
+
+    let empty: [&[f32]; 0] = [];
+    let planar = Planar::new(&empty).unwrap();
+    assert_eq!(planar.num_channels(), 0);
+    assert!(planar.is_empty());
+    assert_eq!(planar.channel(0), None);
+}
+
+#[test]
+fn test_planar_frames() {
+    let samples = [0, 1, 2, 10, 11, 12, 20, 21, 22];
+    let planar = Planar::from_contiguous(&samples, 3).unwrap();
+    assert_eq!(planar.len(), 3);
+    let frames: Vec<[i32; 3]> = planar.frames().collect();
+    assert_eq!(frames, vec![[0, 10, 20], [1, 11, 21], [2, 12, 22]]);
+    assert_eq!(planar.frames::<[i32; 3]>().len(), 3);
+
+    // Frames with the wrong number of channels are never yielded.
+    assert_eq!(planar.frame::<[i32; 2]>(0), None);
+    assert_eq!(planar.frames::<[i32; 2]>().next(), None);
+    assert_eq!(planar.frame::<[i32; 3]>(3), None);
+
+    let channels: Vec<&[i32]> = planar.channels().collect();
+    assert_eq!(channels, vec![&samples[..3], &samples[3..6], &samples[6..]]);
+}
+
+#[test]
+fn test_planar_zero_frames() {
+    let samples: [f32; 0] = [];
+    let planar = Planar::from_contiguous(&samples, 2).unwrap();
+    assert_eq!(planar.num_channels(), 2);
+    assert_eq!(planar.channel(1), Some(&[][..]));
+    assert_eq!(planar.channel(2), None);
+}
+
+#[test]
+fn test_planar_mut_frames() {
+    let mut left = [0i16; 3];
+    let mut right = [0i16; 3];
+    {
+        let mut channels = [&mut left[..], &mut right[..]];
+        let mut planar = PlanarMut::new(&mut channels).unwrap();
+        assert!(planar.set_frame(0, [1, -1]));
+        assert!(planar.set_frame(2, [3, -3]));
+        assert!(!planar.set_frame(3, [4, -4]));
+        assert!(!planar.set_frame(1, [2, -2, 0]));
+        assert_eq!(planar.frame(2), Some([3, -3]));
+        planar.channel_mut(1).unwrap()[1] = 7;
+    }
+    assert_eq!(left, [1, 0, 3]);
+    assert_eq!(right, [-1, 7, -3]);
+}
+
+#[test]
+fn test_interleave_deinterleave() {
+    let frames = [[0.0f32, 0.5], [-0.5, -1.0], [0.25, -0.25]];
+    let mut samples = [0i16; 6];
+    planar::deinterleave(
+        &frames,
+        &mut PlanarMut::from_contiguous(&mut samples, 2).unwrap(),
+    );
+    assert_eq!(samples, [0, -16_384, 8_192, 16_384, -32_768, -8_192]);
+
+    let mut interleaved = [[0.0f32; 2]; 3];
+    let planar = Planar::from_contiguous(&samples, 2).unwrap();
+    planar::interleave(&planar, &mut interleaved);
+    assert_eq!(interleaved, frames);
+}
+
+#[test]
+#[should_panic]
+fn test_interleave_channel_mismatch() {
+    let samples = [0.0f32; 6];
+    let planar = Planar::from_contiguous(&samples, 3).unwrap();
+    let mut frames = [[0.0f32; 2]; 3];
+    planar::interleave(&planar, &mut frames);
+}
+
+#[test]
+#[should_panic]
+fn test_deinterleave_length_mismatch() {
+    let mut samples = [0.0f32; 6];
+    let mut planar = PlanarMut::from_contiguous(&mut samples, 2).unwrap();
+    let frames = [[0.0f32; 2]; 2];
+    planar::deinterleave(&frames, &mut planar);
+}
+
+#[cfg(feature = "boxed")]
+#[test]
+fn test_planar_buffer() {
+    use dasp_slice::planar::PlanarBuffer;
+
+    let mut buffer = PlanarBuffer::<f32>::new(2, 4);
+    assert_eq!(buffer.num_channels(), 2);
+    assert_eq!(buffer.len(), 4);
+    assert_eq!(buffer.channel(0), Some(&[0.0; 4][..]));
+
+    buffer.channel_mut(1).unwrap()[3] = 1.0;
+    assert!(buffer.as_planar_mut().set_frame(0, [0.5, -0.5]));
+    assert_eq!(buffer.as_planar().frame(3), Some([0.0, 1.0]));
+    assert_eq!(
+        &buffer.clone().into_boxed_slice()[..],
+        &[0.5, 0.0, 0.0, 0.0, -0.5, 0.0, 0.0, 1.0][..]
+    );
+
+    let frames = [[0u8, 255], [128, 64]];
+    let buffer = PlanarBuffer::<u8>::from_frames(&frames);
+    assert_eq!(buffer.channel(0), Some(&[0, 128][..]));
+    assert_eq!(buffer.channel(1), Some(&[255, 64][..]));
+}
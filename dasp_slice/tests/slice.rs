@@ -47,3 +47,277 @@ fn test_add_slice_with_amp_per_channel_panic() {
     let amp = [0.5];
     dasp_slice::add_in_place_with_amp_per_channel(&mut a, &b, amp);
 }
+
+#[test]
+fn test_scale_slice_amp() {
+    use dasp_sample::{Decibels, Gain};
+    let mut a = [[0.5, -0.25]; 8];
+    dasp_slice::scale_amp_in_place(&mut a, 0.5);
+    assert_eq!([[0.25, -0.125]; 8], a);
+    dasp_slice::scale_amp_in_place(&mut a, Gain(2.0));
+    assert_eq!([[0.5, -0.25]; 8], a);
+    let mut b = [1_000i16; 8];
+    dasp_slice::scale_amp_in_place(&mut b, Decibels(-20.0));
+    assert_eq!([100; 8], b);
+}
+
+#[test]
+fn test_add_slice_with_amp() {
+    use dasp_sample::{Decibels, Gain};
+    let mut a = [[0.5]; 32];
+    let b = [[1.0]; 32];
+    dasp_slice::add_in_place_with_amp(&mut a, &b, Gain(0.5));
+    assert_eq!([[1.0]; 32], a);
+    dasp_slice::add_in_place_with_amp(&mut a, &b, Decibels::SILENCE);
+    assert_eq!([[1.0]; 32], a);
+}
+
+#[test]
+fn test_sanitize_slice() {
+    use dasp_sample::sanitize::{Fault, NonFinite, Report, Sanitizer};
+    let mut a = [
+        [0.5, f64::NAN],
+        [f64::INFINITY, 1e-310],
+        [-0.5, f64::NEG_INFINITY],
+    ];
+    let counts = dasp_slice::count_faults(&a[..]);
+    assert_eq!((counts.nan, counts.infinite, counts.subnormal), (1, 2, 1));
+    let mut reports = vec![];
+    let sanitizer = Sanitizer::new(NonFinite::Clamp, false);
+    let sanitized = dasp_slice::sanitize_in_place_with(&mut a, sanitizer, |r| reports.push(r));
+    assert_eq!(sanitized, counts);
+    assert_eq!(a, [[0.5, 0.0], [1.0, 1e-310], [-0.5, -1.0]]);
+    assert_eq!(reports.len(), 4);
+    assert_eq!(
+        reports[3],
+        Report {
+            frame: 2,
+            channel: 1,
+            fault: Fault::Infinite,
+        }
+    );
+    assert_eq!(
+        dasp_slice::sanitize_in_place(&mut a, Sanitizer::default()).total(),
+        1
+    );
+    assert_eq!(dasp_slice::count_faults(&a[..]).total(), 0);
+}
+
+#[test]
+fn test_convert_matches_to_sample() {
+    use dasp_sample::{Sample, I24};
+
+    // Every `f32` bit pattern with a stride of 65_537, covering NaNs, infinities, subnormals and
+    // out of range values. The length of each slice leaves a tail for the scalar fallback.
+    let mut floats: Vec<f32> = (0..=u32::MAX / 65_537)
+        .map(|i| f32::from_bits(i * 65_537))
+        .collect();
+    floats.extend(&[
+        1.0,
+        -1.0,
+        0.999_99,
+        -1.000_01,
+        f32::NAN,
+        f32::INFINITY,
+        f32::NEG_INFINITY,
+        -0.0,
+        32_767.5 / 32_768.0,
+        8_388_607.5 / 8_388_608.0,
+        127.5 / 128.0,
+    ]);
+    let bits = |v: &[f32]| v.iter().map(|f| f.to_bits()).collect::<Vec<_>>();
+
+    let mut i16s = vec![0i16; floats.len()];
+    dasp_slice::convert(&mut i16s[..], &floats[..]);
+    let expected: Vec<i16> = floats.iter().map(|f| f.to_sample()).collect();
+    assert_eq!(i16s, expected);
+
+    let mut i24s = vec![I24::EQUILIBRIUM; floats.len()];
+    dasp_slice::convert(&mut i24s[..], &floats[..]);
+    let expected: Vec<I24> = floats.iter().map(|f| f.to_sample()).collect();
+    assert_eq!(i24s, expected);
+
+    let mut u8s = vec![0u8; floats.len()];
+    dasp_slice::convert(&mut u8s[..], &floats[..]);
+    let expected: Vec<u8> = floats.iter().map(|f| f.to_sample()).collect();
+    assert_eq!(u8s, expected);
+
+    let i16s: Vec<i16> = (i16::MIN..=i16::MAX).collect();
+    let mut f32s = vec![0.0f32; i16s.len()];
+    dasp_slice::convert(&mut f32s[..], &i16s[..]);
+    let expected: Vec<f32> = i16s.iter().map(|s| s.to_sample()).collect();
+    assert_eq!(bits(&f32s), bits(&expected));
+
+    let i24s: Vec<I24> = (-8_388_608..8_388_608)
+        .step_by(251)
+        .chain(Some(8_388_607))
+        .map(I24::new_unchecked)
+        .collect();
+    let mut f32s = vec![0.0f32; i24s.len()];
+    dasp_slice::convert(&mut f32s[..], &i24s[..]);
+    let expected: Vec<f32> = i24s.iter().map(|s| s.to_sample()).collect();
+    assert_eq!(bits(&f32s), bits(&expected));
+
+    let u8s: Vec<u8> = (0..=255).chain(0..3).collect();
+    let mut f32s = vec![0.0f32; u8s.len()];
+    dasp_slice::convert(&mut f32s[..], &u8s[..]);
+    let expected: Vec<f32> = u8s.iter().map(|s| s.to_sample()).collect();
+    assert_eq!(bits(&f32s), bits(&expected));
+}
+
+#[test]
+fn test_convert_frames() {
+    let samples = [0u8, 64, 128, 192, 255];
+    let mut frames = [[0i32; 1]; 5];
+    dasp_slice::convert(&mut frames[..], &samples[..]);
+    assert_eq!(
+        frames,
+        [
+            [-2_147_483_648],
+            [-1_073_741_824],
+            [0],
+            [1_073_741_824],
+            [2_130_706_432]
+        ]
+    );
+    let mut stereo = [[0.0f64; 2]; 2];
+    dasp_slice::convert(&mut stereo[..], &[0i16, 16_384, -16_384, -32_768][..]);
+    assert_eq!(stereo, [[0.0, 0.5], [-0.5, -1.0]]);
+}
+
+#[test]
+#[should_panic]
+fn test_convert_panic() {
+    let mut a = [0.0f32; 7];
+    dasp_slice::convert(&mut a[..], &[0i16; 8][..]);
+}
+
+#[test]
+fn test_companded_frames() {
+    use dasp_sample::{ALaw, MuLaw, Sample};
+    let mut a = [[MuLaw::EQUILIBRIUM; 2]; 4];
+    let b = [[0.25f32, -0.25]; 4];
+    dasp_slice::write_saturating(&mut a, &b);
+    let mut c = [[ALaw::EQUILIBRIUM; 2]; 4];
+    dasp_slice::write_saturating(&mut c, &a);
+    for frame in c.iter() {
+        let left = frame[0].to_sample::<f32>();
+        let right = frame[1].to_sample::<f32>();
+        assert!((left - 0.25).abs() < 1.0 / 64.0);
+        assert!((right + 0.25).abs() < 1.0 / 64.0);
+    }
+}
+
+#[test]
+fn test_erased_slice() {
+    use dasp_sample::{SampleFormat, I24, U48};
+    use dasp_slice::erased::ErasedSlice;
+    let samples = [
+        I24::new_unchecked(-8_388_608),
+        I24::new_unchecked(4_194_304),
+    ];
+    let erased = ErasedSlice::new(&samples[..]);
+    assert_eq!(erased.format(), SampleFormat::I24);
+    assert_eq!(erased.format().bits(), 24);
+    assert_eq!(erased.len(), 2);
+    assert_eq!(erased.downcast::<I24>(), Some(&samples[..]));
+    assert_eq!(erased.downcast::<i32>(), None);
+    let converted: Vec<U48> = erased.samples().collect();
+    assert_eq!(
+        converted,
+        vec![
+            U48::new_unchecked(0),
+            U48::new_unchecked(211_106_232_532_992)
+        ]
+    );
+    let mut frames = [[0i16; 2]];
+    erased.write_to(&mut frames[..]);
+    assert_eq!(frames, [[-32_768, 16_384]]);
+    assert!(ErasedSlice::from(&[0.0f64; 0][..]).is_empty());
+}
+
+#[cfg(feature = "half")]
+#[test]
+fn test_erased_half_slice() {
+    use dasp_sample::{f16, SampleFormat};
+    use dasp_slice::erased::ErasedSlice;
+    let samples = [f16::from_f32(-1.0), f16::from_f32(0.5)];
+    let erased = ErasedSlice::from(&samples[..]);
+    assert_eq!(erased.format(), SampleFormat::F16);
+    let converted: Vec<i16> = erased.samples().collect();
+    assert_eq!(converted, vec![-32_768, 16_384]);
+    let converted: Vec<f64> = erased.samples().collect();
+    assert_eq!(converted, vec![-1.0, 0.5]);
+}
+
+#[test]
+#[should_panic]
+fn test_erased_slice_write_panic() {
+    let erased = dasp_slice::erased::ErasedSlice::from(&[0u8; 3][..]);
+    let mut frames = [[0.0f32; 2]; 2];
+    erased.write_to(&mut frames[..]);
+}
+
+#[cfg(feature = "boxed")]
+#[test]
+fn test_erased_boxed_slice() {
+    use dasp_sample::{MuLaw, SampleFormat};
+    use dasp_slice::erased::{ErasedBoxedSlice, ErasedSlice};
+    let samples = [MuLaw::from_bits(0xFF), MuLaw::from_bits(0x80)];
+    let boxed = ErasedBoxedSlice::from_erased_slice(ErasedSlice::from(&samples[..]));
+    assert_eq!(boxed.format(), SampleFormat::MuLaw);
+    assert_eq!(
+        boxed.as_erased_slice().to_boxed_samples::<i16>()[..],
+        [0, 32_124]
+    );
+    let boxed = boxed.downcast::<f32>().unwrap_err();
+    assert_eq!(boxed.downcast::<MuLaw>().unwrap()[..], samples);
+    let boxed = ErasedBoxedSlice::from(vec![0.5f32, -0.5].into_boxed_slice());
+    assert_eq!(boxed.len(), 2);
+    assert_eq!(
+        boxed.as_erased_slice().to_boxed_samples::<u8>()[..],
+        [192, 64]
+    );
+}
+
+#[test]
+fn test_fade_slice() {
+    use dasp_sample::fade::Curve;
+
+    let mut a = [[1.0f32, -1.0]; 4];
+    dasp_slice::fade_out_in_place(&mut a, Curve::SCurve);
+    assert_eq!(a[0], [1.0, -1.0]);
+    assert_eq!(a[3], [0.0, 0.0]);
+
+    let mut b = [[1.0f32, -1.0]; 4];
+    dasp_slice::fade_in_in_place(&mut b, Curve::SCurve);
+    for (a, b) in a.iter().zip(b.iter().rev()) {
+        assert_eq!(a, b);
+    }
+
+    let mut empty: [[f32; 2]; 0] = [];
+    dasp_slice::fade_in_in_place(&mut empty, Curve::Exponential);
+}
+
+#[test]
+fn test_crossfade_slice() {
+    use dasp_sample::fade::Curve;
+
+    let mut a = [[0.5f32]; 5];
+    let b = [[0.5f32]; 5];
+    dasp_slice::crossfade_in_place(&mut a, &b, Curve::Linear);
+    assert_eq!(a, [[0.5]; 5]);
+
+    let mut a = [[128u8]; 3];
+    let b = [[0i8]; 3];
+    dasp_slice::crossfade_in_place(&mut a, &b, Curve::EqualPower);
+    assert_eq!(a, [[128]; 3]);
+}
+
+#[test]
+#[should_panic]
+fn test_crossfade_slice_panic() {
+    let mut a = [[0.0]; 4];
+    let b = [[0.0]; 3];
+    dasp_slice::crossfade_in_place(&mut a, &b, dasp_sample::fade::Curve::Linear);
+}